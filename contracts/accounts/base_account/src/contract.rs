@@ -7,7 +7,7 @@ use cosmwasm_std::{
 use cw2::set_contract_version;
 use valence_account_utils::{
     error::ContractError,
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ValenceCallback},
+    msg::{AccountCapability, ExecuteMsg, InstantiateMsg, QueryMsg, ValenceCallback},
 };
 
 use crate::state::APPROVED_LIBRARIES;
@@ -154,6 +154,12 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 .collect::<StdResult<Vec<_>>>()?;
             to_json_binary(&libraries)
         }
+        QueryMsg::GetCapabilities {} => to_json_binary(&vec![
+            AccountCapability::ExecuteMsgs,
+            AccountCapability::ExecuteSubmsgs,
+            AccountCapability::ApproveLibrary,
+            AccountCapability::RevokeLibrary,
+        ]),
     }
 }
 
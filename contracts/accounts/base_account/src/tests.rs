@@ -8,7 +8,7 @@ use itertools::sorted;
 use std::string::ToString;
 use valence_account_utils::{
     error::{ContractError, UnauthorizedReason},
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, VALENCE_PAYLOAD_KEY},
+    msg::{AccountCapability, ExecuteMsg, InstantiateMsg, QueryMsg, VALENCE_PAYLOAD_KEY},
     testing::{AccountTestSuite, AccountTestSuiteBase},
 };
 
@@ -244,6 +244,10 @@ impl BaseAccountTestSuite {
     fn query_owership(&mut self, addr: &Addr) -> Ownership<Addr> {
         self.query_wasm(addr, &QueryMsg::Ownership {})
     }
+
+    fn query_capabilities(&mut self, addr: &Addr) -> Vec<AccountCapability> {
+        self.query_wasm(addr, &QueryMsg::GetCapabilities {})
+    }
 }
 
 impl AccountTestSuite for BaseAccountTestSuite {
@@ -284,6 +288,25 @@ fn instantiate_with_no_approved_libraries() {
     assert_eq!(approved_libraries, Vec::<Addr>::new());
 }
 
+#[test]
+fn query_capabilities() {
+    let mut suite = BaseAccountTestSuite::default();
+
+    let acc = suite.account_init(vec![]);
+
+    // Base account doesn't support spending limits or sub accounts
+    let capabilities = suite.query_capabilities(&acc);
+    assert_eq!(
+        capabilities,
+        vec![
+            AccountCapability::ExecuteMsgs,
+            AccountCapability::ExecuteSubmsgs,
+            AccountCapability::ApproveLibrary,
+            AccountCapability::RevokeLibrary,
+        ]
+    );
+}
+
 #[test]
 fn instantiate_with_approved_libraries() {
     let mut suite = BaseAccountTestSuite::default();
@@ -1,9 +1,12 @@
-use cosmwasm_std::{BlockInfo, MessageInfo, QuerierWrapper, Storage, Uint128};
-use cw_utils::{must_pay, Expiration};
-use serde_json::Value;
+use cosmwasm_std::{
+    BlockInfo, Coin, MessageInfo, Order, QuerierWrapper, StdError, StdResult, Storage, Uint128,
+};
+use cw_utils::{must_pay, Duration, Expiration};
+use serde_json::{Map, Value};
 use valence_authorization_utils::{
     authorization::{
-        Authorization, AuthorizationMode, AuthorizationState, PermissionType, Priority, Subroutine,
+        Authorization, AuthorizationConstraint, AuthorizationMode, AuthorizationState,
+        PermissionType, Priority, Subroutine,
     },
     authorization_message::ParamRestriction,
     domain::{Domain, ExecutionEnvironment},
@@ -14,7 +17,7 @@ use valence_authorization_utils::{
 use crate::{
     contract::build_tokenfactory_denom,
     error::{AuthorizationErrorReason, ContractError, MessageErrorReason, UnauthorizedReason},
-    state::EXTERNAL_DOMAINS,
+    state::{AUTHORIZATION_CONSTRAINTS, EXTERNAL_DOMAINS, SPENDING_TRACKER},
 };
 
 pub trait Validate {
@@ -332,3 +335,130 @@ fn check_restriction(
     }
     Ok(())
 }
+
+// Checks the constraint of an authorization (if any) against the messages that are going to be sent and updates its tracking state
+pub fn enforce_constraint(
+    store: &mut dyn Storage,
+    block: &BlockInfo,
+    label: &str,
+    messages: &[ProcessorMessage],
+) -> Result<(), ContractError> {
+    if let Some(AuthorizationConstraint::SpendingLimit {
+        denom,
+        max_per_window,
+        window_duration,
+    }) = AUTHORIZATION_CONSTRAINTS.may_load(store, label.to_string())?
+    {
+        let requested = total_bank_send_amount(messages, &denom)?;
+        let window_start = get_window_start(block, &window_duration)?;
+
+        // Entries from previous windows are expired, so we remove them
+        let expired_windows = SPENDING_TRACKER
+            .prefix(label.to_string())
+            .keys(store, None, None, Order::Ascending)
+            .filter(|window| window.as_ref().map_or(true, |w| *w != window_start))
+            .collect::<StdResult<Vec<u64>>>()?;
+        for window in expired_windows {
+            SPENDING_TRACKER.remove(store, (label.to_string(), window));
+        }
+
+        let spent = SPENDING_TRACKER
+            .may_load(store, (label.to_string(), window_start))?
+            .unwrap_or_default();
+        let total = spent.checked_add(requested).map_err(StdError::from)?;
+        if total > max_per_window {
+            return Err(ContractError::Authorization(
+                AuthorizationErrorReason::SpendingLimitExceeded {
+                    denom,
+                    spent,
+                    requested,
+                    max: max_per_window,
+                },
+            ));
+        }
+
+        SPENDING_TRACKER.save(store, (label.to_string(), window_start), &total)?;
+    }
+
+    Ok(())
+}
+
+// Windows are aligned to multiples of their duration so that all executions within the same window share the same key
+pub fn get_window_start(
+    block: &BlockInfo,
+    window_duration: &Duration,
+) -> Result<u64, ContractError> {
+    let (current, duration) = match window_duration {
+        Duration::Height(blocks) => (block.height, *blocks),
+        Duration::Time(seconds) => (block.time.seconds(), *seconds),
+    };
+    if duration == 0 {
+        return Err(ContractError::Authorization(
+            AuthorizationErrorReason::InvalidSpendingWindow {},
+        ));
+    }
+
+    Ok(current - current % duration)
+}
+
+// Adds up the amounts of a denom in the BankMsg::Send messages found anywhere inside the messages.
+// Only literal BankMsg::Send messages are counted, funds moved in any other way (e.g. by a library function) are not visible here,
+// so every message sent with a spending limit must carry at least one BankMsg::Send.
+fn total_bank_send_amount(
+    messages: &[ProcessorMessage],
+    denom: &str,
+) -> Result<Uint128, ContractError> {
+    let mut total = Uint128::zero();
+    for message in messages {
+        let json: Value = serde_json::from_slice(message.get_msg().as_slice()).map_err(|e| {
+            ContractError::InvalidJson {
+                error: e.to_string(),
+            }
+        })?;
+        if !add_bank_send_amounts(&json, denom, &mut total)? {
+            return Err(ContractError::Authorization(
+                AuthorizationErrorReason::SpendingNotTracked {},
+            ));
+        }
+    }
+
+    Ok(total)
+}
+
+// Returns if a BankMsg::Send was found in the value
+fn add_bank_send_amounts(value: &Value, denom: &str, total: &mut Uint128) -> StdResult<bool> {
+    let mut found = false;
+    match value {
+        Value::Object(map) => {
+            if let Some(amount) = bank_send_amount(map) {
+                for coin in amount.iter().filter(|coin| coin.denom == denom) {
+                    *total = total.checked_add(coin.amount)?;
+                }
+                return Ok(true);
+            }
+            for nested in map.values() {
+                found |= add_bank_send_amounts(nested, denom, total)?;
+            }
+        }
+        Value::Array(values) => {
+            for nested in values {
+                found |= add_bank_send_amounts(nested, denom, total)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(found)
+}
+
+// The coins of a `{"send":{"to_address":...,"amount":[...]}}` object, only the amount is cloned and deserialized
+fn bank_send_amount(map: &Map<String, Value>) -> Option<Vec<Coin>> {
+    let Some(Value::Object(send)) = map.get("send") else {
+        return None;
+    };
+    if map.len() != 1 || send.len() != 2 || !send.get("to_address").is_some_and(Value::is_string) {
+        return None;
+    }
+
+    serde_json::from_value(send.get("amount")?.clone()).ok()
+}
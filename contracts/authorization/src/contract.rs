@@ -11,8 +11,8 @@ use cw_utils::Expiration;
 use neutron_sdk::proto_types::osmosis::tokenfactory::v1beta1::{MsgBurn, MsgCreateDenom, MsgMint};
 use valence_authorization_utils::{
     authorization::{
-        Authorization, AuthorizationInfo, AuthorizationMode, AuthorizationState, PermissionType,
-        Priority,
+        Authorization, AuthorizationConstraint, AuthorizationInfo, AuthorizationMode,
+        AuthorizationState, PermissionType, Priority, Role, Subroutine,
     },
     callback::{ExecutionResult, OperationInitiator, PolytoneCallbackMsg, ProcessorCallbackInfo},
     domain::{Connector, Domain, ExternalDomain, PolytoneProxyState},
@@ -27,12 +27,13 @@ use valence_polytone_utils::polytone::{
 use valence_processor_utils::msg::{AuthorizationMsg, ExecuteMsg as ProcessorExecuteMsg};
 
 use crate::{
-    authorization::Validate,
+    authorization::{enforce_constraint, get_window_start, Validate},
     domain::{add_domain, create_msg_for_processor_or_bridge, get_domain},
     error::{AuthorizationErrorReason, ContractError, MessageErrorReason, UnauthorizedReason},
     state::{
-        AUTHORIZATIONS, CURRENT_EXECUTIONS, EXECUTION_ID, EXTERNAL_DOMAINS, FIRST_OWNERSHIP,
-        PROCESSOR_CALLBACKS, PROCESSOR_ON_MAIN_DOMAIN, SUB_OWNERS,
+        AUTHORIZATIONS, AUTHORIZATION_CONSTRAINTS, CURRENT_EXECUTIONS, EXECUTION_ID,
+        EXTERNAL_DOMAINS, FIRST_OWNERSHIP, MIN_REQUIRED_ROLES, PROCESSOR_CALLBACKS,
+        PROCESSOR_ON_MAIN_DOMAIN, ROLE_ASSIGNMENTS, SPENDING_TRACKER, SUB_OWNERS,
     },
 };
 
@@ -89,6 +90,11 @@ pub fn execute(
             match owner_msg {
                 OwnerMsg::AddSubOwner { sub_owner } => add_sub_owner(deps, sub_owner),
                 OwnerMsg::RemoveSubOwner { sub_owner } => remove_sub_owner(deps, sub_owner),
+                OwnerMsg::AssignRole { addr, role } => assign_role(deps, addr, role),
+                OwnerMsg::RevokeRole { addr } => revoke_role(deps, addr),
+                OwnerMsg::SetMinRequiredRole { label, role } => {
+                    set_min_required_role(deps, label, role)
+                }
             }
         }
         ExecuteMsg::PermissionedAction(permissioned_msg) => {
@@ -134,6 +140,12 @@ pub fn execute(
                 } => insert_messages(deps, env, label, queue_position, priority, messages),
                 PermissionedMsg::PauseProcessor { domain } => pause_processor(deps, domain),
                 PermissionedMsg::ResumeProcessor { domain } => resume_processor(deps, domain),
+                PermissionedMsg::ResetCircuitBreaker { domain, library } => {
+                    reset_circuit_breaker(deps, domain, library)
+                }
+                PermissionedMsg::SetAuthorizationConstraint { label, constraint } => {
+                    set_authorization_constraint(deps, env, label, constraint)
+                }
             }
         }
         ExecuteMsg::PermissionlessAction(permissionless_msg) => match permissionless_msg {
@@ -196,6 +208,84 @@ fn remove_sub_owner(deps: DepsMut, sub_owner: String) -> Result<Response, Contra
         .add_attribute("sub_owner", sub_owner))
 }
 
+fn assign_role(deps: DepsMut, addr: String, role: Role) -> Result<Response, ContractError> {
+    ROLE_ASSIGNMENTS.save(deps.storage, deps.api.addr_validate(&addr)?, &role)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "assign_role")
+        .add_attribute("addr", addr)
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+fn revoke_role(deps: DepsMut, addr: String) -> Result<Response, ContractError> {
+    ROLE_ASSIGNMENTS.remove(deps.storage, deps.api.addr_validate(&addr)?);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_role")
+        .add_attribute("addr", addr))
+}
+
+fn set_min_required_role(
+    deps: DepsMut,
+    label: String,
+    role: Option<Role>,
+) -> Result<Response, ContractError> {
+    if !AUTHORIZATIONS.has(deps.storage, label.clone()) {
+        return Err(ContractError::Authorization(
+            AuthorizationErrorReason::DoesNotExist(label),
+        ));
+    }
+
+    match role {
+        Some(role) => MIN_REQUIRED_ROLES.save(deps.storage, label.clone(), &role)?,
+        None => MIN_REQUIRED_ROLES.remove(deps.storage, label.clone()),
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_min_required_role")
+        .add_attribute("label", label)
+        .add_attribute("role", format!("{:?}", role)))
+}
+
+fn set_authorization_constraint(
+    deps: DepsMut,
+    env: Env,
+    label: String,
+    constraint: Option<AuthorizationConstraint>,
+) -> Result<Response, ContractError> {
+    if !AUTHORIZATIONS.has(deps.storage, label.clone()) {
+        return Err(ContractError::Authorization(
+            AuthorizationErrorReason::DoesNotExist(label),
+        ));
+    }
+
+    match &constraint {
+        Some(constraint) => {
+            let AuthorizationConstraint::SpendingLimit {
+                window_duration, ..
+            } = constraint;
+            // Validate the window before saving it
+            get_window_start(&env.block, window_duration)?;
+            AUTHORIZATION_CONSTRAINTS.save(deps.storage, label.clone(), constraint)?
+        }
+        None => AUTHORIZATION_CONSTRAINTS.remove(deps.storage, label.clone()),
+    }
+
+    // Spending tracked for a previous constraint doesn't apply anymore
+    let windows = SPENDING_TRACKER
+        .prefix(label.clone())
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<u64>>>()?;
+    for window in windows {
+        SPENDING_TRACKER.remove(deps.storage, (label.clone(), window));
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "set_authorization_constraint")
+        .add_attribute("label", label)
+        .add_attribute("constraint", format!("{:?}", constraint)))
+}
+
 fn add_external_domains(
     mut deps: DepsMut,
     env: Env,
@@ -407,6 +497,25 @@ fn resume_processor(deps: DepsMut, domain: Domain) -> Result<Response, ContractE
         .add_attribute("action", "resume_processor"))
 }
 
+fn reset_circuit_breaker(
+    deps: DepsMut,
+    domain: Domain,
+    library: String,
+) -> Result<Response, ContractError> {
+    let execute_msg_binary = to_json_binary(&ProcessorExecuteMsg::AuthorizationModuleAction(
+        AuthorizationMsg::ResetCircuitBreaker {
+            library: library.clone(),
+        },
+    ))?;
+    let message =
+        create_msg_for_processor_or_bridge(deps.storage, execute_msg_binary, &domain, None)?;
+
+    Ok(Response::new()
+        .add_message(message)
+        .add_attribute("action", "reset_circuit_breaker")
+        .add_attribute("library", library))
+}
+
 fn insert_messages(
     deps: DepsMut,
     env: Env,
@@ -435,6 +544,7 @@ fn insert_messages(
 
     let domain = get_domain(&authorization)?;
     let id = get_and_increase_execution_id(deps.storage)?;
+    let expiration_time = get_expiration_time(&env, &authorization.subroutine);
     let execute_msg_binary = to_json_binary(&ProcessorExecuteMsg::AuthorizationModuleAction(
         AuthorizationMsg::InsertMsgs {
             id,
@@ -442,6 +552,7 @@ fn insert_messages(
             msgs: messages.clone(),
             subroutine: authorization.subroutine,
             priority,
+            expiration_time,
         },
     ))?;
 
@@ -516,6 +627,21 @@ fn send_msgs(
         &messages,
     )?;
 
+    // If the authorization requires a role, the sender must have that role or a higher one
+    if let Some(min_required_role) = MIN_REQUIRED_ROLES.may_load(deps.storage, label.clone())? {
+        match ROLE_ASSIGNMENTS.may_load(deps.storage, info.sender.clone())? {
+            Some(role) if role >= min_required_role => {}
+            _ => {
+                return Err(ContractError::Unauthorized(
+                    UnauthorizedReason::InsufficientRole {},
+                ))
+            }
+        }
+    }
+
+    // Check that the messages don't go over the constraint of the authorization (e.g. spending limit)
+    enforce_constraint(deps.storage, &env.block, &label, &messages)?;
+
     // We need to check that we haven't reached the max concurrent executions and if not, increase it by 1
     let current_executions = CURRENT_EXECUTIONS
         .load(deps.storage, label.clone())
@@ -535,6 +661,8 @@ fn send_msgs(
     let domain = get_domain(&authorization)?;
     // Get the ID we are going to use for the execution (used to process callbacks)
     let id = get_and_increase_execution_id(deps.storage)?;
+    // Time after which the processor won't execute the messages anymore (if the subroutine has an execution time limit)
+    let expiration_time = get_expiration_time(&env, &authorization.subroutine);
     // Message for the processor
     let execute_msg_binary = to_json_binary(&ProcessorExecuteMsg::AuthorizationModuleAction(
         AuthorizationMsg::EnqueueMsgs {
@@ -542,6 +670,7 @@ fn send_msgs(
             msgs: messages.clone(),
             subroutine: authorization.subroutine,
             priority: authorization.priority,
+            expiration_time,
         },
     ))?;
 
@@ -603,12 +732,14 @@ fn retry_msgs(deps: DepsMut, env: Env, execution_id: u64) -> Result<Response, Co
                 callback_info.label.clone(),
                 &current_executions.checked_add(1).expect("Overflow"),
             )?;
+            let expiration_time = get_expiration_time(&env, &authorization.subroutine);
             let execute_msg_binary = to_json_binary(
                 &ProcessorExecuteMsg::AuthorizationModuleAction(AuthorizationMsg::EnqueueMsgs {
                     id: execution_id,
                     msgs: callback_info.messages.clone(),
                     subroutine: authorization.subroutine,
                     priority: authorization.priority,
+                    expiration_time,
                 }),
             )?;
             // Update the state
@@ -757,6 +888,10 @@ fn process_processor_callback(
                     // If the operation was executed, partially executed or removed by the owner the token will be burned
                     burn_msg(env.contract.address.to_string(), 1, denom)
                 }
+                ExecutionResult::Expired(executed_functions) if executed_functions > 0 => {
+                    // If some functions were executed before it expired, it's the same as partially executed
+                    burn_msg(env.contract.address.to_string(), 1, denom)
+                }
                 _ => {
                     // Otherwise, the tokens will be sent back
                     CosmosMsg::Bank(BankMsg::Send {
@@ -972,6 +1107,15 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProcessorCallback { execution_id } => {
             to_json_binary(&get_processor_callback(deps, execution_id)?)
         }
+        QueryMsg::Role { addr } => to_json_binary(
+            &ROLE_ASSIGNMENTS.may_load(deps.storage, deps.api.addr_validate(&addr)?)?,
+        ),
+        QueryMsg::MinRequiredRole { label } => {
+            to_json_binary(&MIN_REQUIRED_ROLES.may_load(deps.storage, label)?)
+        }
+        QueryMsg::AuthorizationConstraint { label } => {
+            to_json_binary(&AUTHORIZATION_CONSTRAINTS.may_load(deps.storage, label)?)
+        }
     }
 }
 
@@ -1070,6 +1214,13 @@ pub fn get_and_increase_execution_id(storage: &mut dyn Storage) -> StdResult<u64
     Ok(id)
 }
 
+/// Absolute time (in seconds) until which the processor can execute the subroutine, if it has an execution time limit
+pub fn get_expiration_time(env: &Env, subroutine: &Subroutine) -> Option<u64> {
+    subroutine
+        .get_execution_time_limit()
+        .map(|limit| env.block.time.plus_seconds(limit).seconds())
+}
+
 /// Store the pending callback
 pub fn store_inprocess_callback(
     storage: &mut dyn Storage,
@@ -2,7 +2,7 @@ use cw_ownable::OwnershipError;
 use cw_utils::PaymentError;
 use thiserror::Error;
 
-use cosmwasm_std::StdError;
+use cosmwasm_std::{StdError, Uint128};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
@@ -65,6 +65,20 @@ pub enum AuthorizationErrorReason {
 
     #[error("The authorization has reached its max concurrent executions")]
     MaxConcurrentExecutionsReached {},
+
+    #[error("Spending limit window duration can't be 0")]
+    InvalidSpendingWindow {},
+
+    #[error("Messages of an authorization with a spending limit must contain a BankMsg::Send")]
+    SpendingNotTracked {},
+
+    #[error("Spending limit exceeded for {denom}: {spent} already spent in this window, {requested} requested, {max} allowed")]
+    SpendingLimitExceeded {
+        denom: String,
+        spent: Uint128,
+        requested: Uint128,
+        max: Uint128,
+    },
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -95,6 +109,9 @@ pub enum UnauthorizedReason {
 
     #[error("Creation of bridge was not timed out")]
     BridgeCreationNotTimedOut {},
+
+    #[error("This address does not have the role required to execute this authorization")]
+    InsufficientRole {},
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -1,7 +1,9 @@
-use cosmwasm_std::{Addr, Empty};
+use cosmwasm_std::{Addr, Empty, Uint128};
 use cw_storage_plus::{Item, Map};
 use valence_authorization_utils::{
-    authorization::Authorization, callback::ProcessorCallbackInfo, domain::ExternalDomain,
+    authorization::{Authorization, AuthorizationConstraint, Role},
+    callback::ProcessorCallbackInfo,
+    domain::ExternalDomain,
 };
 
 pub const FIRST_OWNERSHIP: Item<bool> = Item::new("first_ownership");
@@ -14,3 +16,12 @@ pub const EXECUTION_ID: Item<u64> = Item::new("execution_id");
 pub const CURRENT_EXECUTIONS: Map<String, u64> = Map::new("current_executions");
 // Track all the callbacks for the processor, if they haven't been processed yet they will be in ExecutionResult::InProcess
 pub const PROCESSOR_CALLBACKS: Map<u64, ProcessorCallbackInfo> = Map::new("processor_callbacks");
+// Roles assigned to addresses by the owner
+pub const ROLE_ASSIGNMENTS: Map<Addr, Role> = Map::new("role_assignments");
+// Minimum role required to execute an authorization, by label
+pub const MIN_REQUIRED_ROLES: Map<String, Role> = Map::new("min_required_roles");
+// Constraint applied to an authorization, by label
+pub const AUTHORIZATION_CONSTRAINTS: Map<String, AuthorizationConstraint> =
+    Map::new("authorization_constraints");
+// Amount spent for an authorization with a spending limit, by label and start of the current window
+pub const SPENDING_TRACKER: Map<(String, u64), Uint128> = Map::new("spending_tracker");
@@ -11,7 +11,9 @@ use neutron_test_tube::{
 };
 use serde::Serialize;
 use valence_authorization_utils::msg::InstantiateMsg;
-use valence_processor_utils::msg::InstantiateMsg as ProcessorInstantiateMsg;
+use valence_processor_utils::{
+    msg::InstantiateMsg as ProcessorInstantiateMsg, processor::CircuitBreaker,
+};
 use valence_test_library::msg::InstantiateMsg as TestLibraryInstantiateMsg;
 
 pub const ARTIFACTS_DIR: &str = "../../artifacts";
@@ -93,6 +95,18 @@ pub fn store_and_instantiate_authorization_with_processor_contract(
     signer: &SigningAccount,
     owner: String,
     sub_owners: Vec<String>,
+) -> (String, String) {
+    store_and_instantiate_authorization_with_processor_contract_and_circuit_breaker(
+        app, signer, owner, sub_owners, None,
+    )
+}
+
+pub fn store_and_instantiate_authorization_with_processor_contract_and_circuit_breaker(
+    app: &NeutronTestApp,
+    signer: &SigningAccount,
+    owner: String,
+    sub_owners: Vec<String>,
+    circuit_breaker: Option<CircuitBreaker>,
 ) -> (String, String) {
     let wasm = Wasm::new(app);
     let extended_wasm = ExtendedWasm::new(app);
@@ -130,6 +144,7 @@ pub fn store_and_instantiate_authorization_with_processor_contract(
             &ProcessorInstantiateMsg {
                 authorization_contract: predicted_address.clone(),
                 polytone_contracts: None,
+                circuit_breaker,
             },
             None,
             "processor".into(),
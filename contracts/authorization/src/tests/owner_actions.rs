@@ -1016,7 +1016,9 @@ fn pausing_and_resuming_processor() {
     let error = wasm
         .execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1043,7 +1045,9 @@ fn pausing_and_resuming_processor() {
     let error = wasm
         .execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -17,7 +17,11 @@ use valence_authorization_utils::{
     msg::{ExecuteMsg, PermissionedMsg, PermissionlessMsg, ProcessorMessage, QueryMsg},
 };
 use valence_library_utils::LibraryAccountType;
-use valence_processor_utils::{msg::InternalProcessorMsg, processor::MessageBatch};
+use valence_processor_utils::{
+    callback::ExecutionRecord,
+    msg::InternalProcessorMsg,
+    processor::{CircuitBreaker, MessageBatch},
+};
 
 use crate::{
     contract::build_tokenfactory_denom,
@@ -25,8 +29,8 @@ use crate::{
     tests::helpers::{wait_for_height, ARTIFACTS_DIR},
 };
 use valence_processor_utils::msg::{
-    ExecuteMsg as ProcessorExecuteMsg, PermissionlessMsg as ProcessorPermissionlessMsg,
-    QueryMsg as ProcessorQueryMsg,
+    ExecuteMsg as ProcessorExecuteMsg, InstantiateMsg as ProcessorInstantiateMsg,
+    PermissionlessMsg as ProcessorPermissionlessMsg, QueryMsg as ProcessorQueryMsg,
 };
 
 use valence_processor::error::{ContractError as ProcessorContractError, UnauthorizedReason};
@@ -39,6 +43,7 @@ use super::{
     builders::NeutronTestAppBuilder,
     helpers::{
         store_and_instantiate_authorization_with_processor_contract,
+        store_and_instantiate_authorization_with_processor_contract_and_circuit_breaker,
         store_and_instantiate_test_library,
     },
 };
@@ -950,7 +955,9 @@ fn invalid_msg_rejected() {
     // If we tick the processor, the message will fail, the callback will be sent to the authorization contract with the right error, and will be removed from queue
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1129,7 +1136,9 @@ fn queue_shifting_when_not_retriable() {
     // Ticking the processor will make the first message fail and be put back at the end of the queue
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1174,7 +1183,9 @@ fn queue_shifting_when_not_retriable() {
     // Ticking the processor again will make the first message fail and be put back at the end of the queue
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1185,7 +1196,9 @@ fn queue_shifting_when_not_retriable() {
     let response = wasm
         .execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1200,7 +1213,9 @@ fn queue_shifting_when_not_retriable() {
     let response = wasm
         .execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1219,7 +1234,9 @@ fn queue_shifting_when_not_retriable() {
     let response = wasm
         .execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1234,7 +1251,9 @@ fn queue_shifting_when_not_retriable() {
     let response = wasm
         .execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1359,7 +1378,9 @@ fn higher_priority_queue_is_processed_first() {
     // Let's check that the high priority queue is processed first
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1397,7 +1418,9 @@ fn higher_priority_queue_is_processed_first() {
     // Now let's tick again to process the other message in the high priority queue
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1450,7 +1473,9 @@ fn higher_priority_queue_is_processed_first() {
     for _ in 0..2 {
         wasm.execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1611,7 +1636,9 @@ fn retry_multi_function_atomic_batch_until_success() {
     for _ in 0..5 {
         wasm.execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1645,7 +1672,9 @@ fn retry_multi_function_atomic_batch_until_success() {
     // Ticking now will make it succeed
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1796,7 +1825,9 @@ fn retry_multi_function_non_atomic_batch_until_success() {
     // Ticking the first time will make the first message succeed and re-add to the queue to move to the second message
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1820,7 +1851,9 @@ fn retry_multi_function_non_atomic_batch_until_success() {
     for i in 0..5 {
         wasm.execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -1859,7 +1892,9 @@ fn retry_multi_function_non_atomic_batch_until_success() {
     // Tick again will move now to the 3rd function but not process it, just re-add it to the queue
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -1884,7 +1919,9 @@ fn retry_multi_function_non_atomic_batch_until_success() {
     // Last tick will process the last message and send the callback
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
@@ -2020,9 +2057,11 @@ fn failed_atomic_batch_after_retries() {
                     subroutine: Subroutine::Atomic(AtomicSubroutine {
                         functions: vec![],
                         retry_logic: None,
+                        execution_time_limit: None,
                     }),
                     priority: Priority::Medium,
                     retry: None,
+                    expiration_time: None,
                 },
             }),
             &[],
@@ -2040,7 +2079,9 @@ fn failed_atomic_batch_after_retries() {
     for _ in 0..6 {
         wasm.execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -2180,7 +2221,9 @@ fn failed_non_atomic_batch_after_retries() {
     for _ in 0..7 {
         wasm.execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
             &[],
             &setup.owner_accounts[0],
         )
@@ -2243,7 +2286,7 @@ fn failed_non_atomic_batch_after_retries() {
 }
 
 #[test]
-fn successful_non_atomic_and_atomic_batches_together() {
+fn expired_atomic_batch_is_not_executed() {
     let setup = NeutronTestAppBuilder::new().build().unwrap();
 
     let wasm = Wasm::new(&setup.app);
@@ -2258,77 +2301,36 @@ fn successful_non_atomic_and_atomic_batches_together() {
     let test_library_contract =
         store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
 
-    // We'll create two authorizations, one atomic and one non-atomic, with 2 functions each where both of them will succeed
-    let authorizations = vec![
-        AuthorizationBuilder::new()
-            .with_label("permissionless-atomic")
-            .with_subroutine(
-                AtomicSubroutineBuilder::new()
-                    .with_function(
-                        AtomicFunctionBuilder::new()
-                            .with_contract_address(LibraryAccountType::Addr(
-                                test_library_contract.clone(),
-                            ))
-                            .with_message_details(MessageDetails {
-                                message_type: MessageType::CosmwasmExecuteMsg,
-                                message: Message {
-                                    name: "will_succeed".to_string(),
-                                    params_restrictions: None,
-                                },
-                            })
-                            .build(),
-                    )
-                    .with_function(
-                        AtomicFunctionBuilder::new()
-                            .with_contract_address(LibraryAccountType::Addr(
-                                test_library_contract.clone(),
-                            ))
-                            .with_message_details(MessageDetails {
-                                message_type: MessageType::CosmwasmExecuteMsg,
-                                message: Message {
-                                    name: "will_succeed".to_string(),
-                                    params_restrictions: None,
-                                },
-                            })
-                            .build(),
-                    )
-                    .build(),
-            )
-            .build(),
-        AuthorizationBuilder::new()
-            .with_label("permissionless-non-atomic")
-            .with_subroutine(
-                NonAtomicSubroutineBuilder::new()
-                    .with_function(
-                        NonAtomicFunctionBuilder::new()
-                            .with_contract_address(&test_library_contract.clone())
-                            .with_message_details(MessageDetails {
-                                message_type: MessageType::CosmwasmExecuteMsg,
-                                message: Message {
-                                    name: "will_succeed".to_string(),
-                                    params_restrictions: None,
-                                },
-                            })
-                            .build(),
-                    )
-                    .with_function(
-                        NonAtomicFunctionBuilder::new()
-                            .with_contract_address(&test_library_contract)
-                            .with_message_details(MessageDetails {
-                                message_type: MessageType::CosmwasmExecuteMsg,
-                                message: Message {
-                                    name: "will_succeed".to_string(),
-                                    params_restrictions: None,
-                                },
-                            })
-                            .build(),
-                    )
-                    .build(),
-            )
-            .build(),
-    ];
+    // We'll create an authorization with an atomic subroutine that has to be executed within 100 seconds
+    let authorizations = vec![AuthorizationBuilder::new()
+        .with_label("permissioned")
+        .with_mode(AuthorizationModeInfo::Permissioned(
+            PermissionTypeInfo::WithCallLimit(vec![(
+                setup.user_accounts[0].address(),
+                Uint128::new(1),
+            )]),
+        ))
+        .with_subroutine(
+            AtomicSubroutineBuilder::new()
+                .with_function(
+                    AtomicFunctionBuilder::new()
+                        .with_contract_address(LibraryAccountType::Addr(
+                            test_library_contract.clone(),
+                        ))
+                        .with_message_details(MessageDetails {
+                            message_type: MessageType::CosmwasmExecuteMsg,
+                            message: Message {
+                                name: "will_succeed".to_string(),
+                                params_restrictions: None,
+                            },
+                        })
+                        .build(),
+                )
+                .with_execution_time_limit(100)
+                .build(),
+        )
+        .build()];
 
-    // Create them and send the messages
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
@@ -2340,42 +2342,36 @@ fn successful_non_atomic_and_atomic_batches_together() {
     let binary = Binary::from(
         serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
     );
-    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+    let message = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
 
-    wasm.execute::<ExecuteMsg>(
-        &authorization_contract,
-        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-            label: "permissionless-atomic".to_string(),
-            messages: vec![message1.clone(), message1.clone()],
-            ttl: None,
-        }),
-        &[],
-        &setup.user_accounts[0],
-    )
-    .unwrap();
+    let permission_token = build_tokenfactory_denom(&authorization_contract, "permissioned");
 
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-            label: "permissionless-non-atomic".to_string(),
-            messages: vec![message1.clone(), message1.clone()],
+            label: "permissioned".to_string(),
+            messages: vec![message],
             ttl: None,
         }),
-        &[],
+        &[Coin::new(Uint128::one(), permission_token.to_string())],
         &setup.user_accounts[0],
     )
     .unwrap();
 
-    // Ticking the first time will make the atomic batch succeed
+    // Let the execution time limit pass before ticking the processor
+    setup.app.increase_time(200);
+
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // Confirm that we have only 1 batch left in the queue
+    // The batch must have been removed from the queue
     let query_med_prio_queue = wasm
         .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
             &processor_contract,
@@ -2387,9 +2383,8 @@ fn successful_non_atomic_and_atomic_batches_together() {
         )
         .unwrap();
 
-    assert_eq!(query_med_prio_queue.len(), 1);
+    assert!(query_med_prio_queue.is_empty());
 
-    // Confirm we got callback for the atomic batch
     let query_callbacks = wasm
         .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
             &authorization_contract,
@@ -2400,63 +2395,26 @@ fn successful_non_atomic_and_atomic_batches_together() {
         )
         .unwrap();
 
-    assert_eq!(query_callbacks.len(), 2);
-    assert_eq!(query_callbacks[0].messages.len(), 2);
+    assert_eq!(query_callbacks.len(), 1);
     assert_eq!(
         query_callbacks[0].execution_result,
-        ExecutionResult::Success
+        ExecutionResult::Expired(0)
     );
 
-    // For the non-atomic batch we need to tick 2 times to process it
-    for _ in 0..2 {
-        wasm.execute::<ProcessorExecuteMsg>(
-            &processor_contract,
-            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
-            &[],
-            &setup.owner_accounts[0],
-        )
-        .unwrap();
-
-        setup.app.increase_time(5);
-    }
-
-    // Check that we have no more batches in the queue
-    let query_med_prio_queue = wasm
-        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
-            &processor_contract,
-            &ProcessorQueryMsg::GetQueue {
-                from: None,
-                to: None,
-                priority: Priority::Medium,
-            },
-        )
-        .unwrap();
-
-    assert_eq!(query_med_prio_queue.len(), 0);
-
-    // Confirm we got callback for the non-atomic batch
-    let query_callbacks = wasm
-        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
-            &authorization_contract,
-            &QueryMsg::ProcessorCallbacks {
-                start_after: None,
-                limit: None,
-            },
-        )
+    // Nothing was executed so the token must have been sent back to the user
+    let bank = Bank::new(&setup.app);
+    let balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: setup.user_accounts[0].address(),
+            denom: permission_token,
+        })
         .unwrap();
 
-    assert_eq!(query_callbacks.len(), 2);
-    for confirmed_callback in query_callbacks.iter() {
-        assert_eq!(confirmed_callback.messages.len(), 2);
-        assert_eq!(
-            confirmed_callback.execution_result,
-            ExecutionResult::Success
-        );
-    }
+    assert_eq!(balance.balance.unwrap().amount, "1");
 }
 
 #[test]
-fn reject_and_confirm_non_atomic_function_with_callback() {
+fn expired_non_atomic_batch_after_partial_execution() {
     let setup = NeutronTestAppBuilder::new().build().unwrap();
 
     let wasm = Wasm::new(&setup.app);
@@ -2471,9 +2429,15 @@ fn reject_and_confirm_non_atomic_function_with_callback() {
     let test_library_contract =
         store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
 
-    // We'll create an authorization with 2 functions, where both will succeed but second one needs to confirmed with a callback
+    // We'll create an authorization with 2 functions, where the first one will succeed and the second one will keep failing until the batch expires
     let authorizations = vec![AuthorizationBuilder::new()
-        .with_label("permissionless")
+        .with_label("permissioned")
+        .with_mode(AuthorizationModeInfo::Permissioned(
+            PermissionTypeInfo::WithCallLimit(vec![(
+                setup.user_accounts[0].address(),
+                Uint128::new(1),
+            )]),
+        ))
         .with_subroutine(
             NonAtomicSubroutineBuilder::new()
                 .with_function(
@@ -2498,21 +2462,17 @@ fn reject_and_confirm_non_atomic_function_with_callback() {
                         .with_message_details(MessageDetails {
                             message_type: MessageType::CosmwasmExecuteMsg,
                             message: Message {
-                                name: "will_succeed".to_string(),
+                                name: "will_error".to_string(),
                                 params_restrictions: None,
                             },
                         })
-                        .with_callback_confirmation(FunctionCallback {
-                            contract_address: Addr::unchecked(test_library_contract.to_string()),
-                            callback_message: Binary::from("Confirmed".as_bytes()),
-                        })
                         .build(),
                 )
+                .with_execution_time_limit(100)
                 .build(),
         )
         .build()];
 
-    // Create the authorization and send the messages
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
@@ -2525,52 +2485,54 @@ fn reject_and_confirm_non_atomic_function_with_callback() {
         serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
     );
     let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+    let binary = Binary::from(
+        serde_json::to_vec(&TestLibraryExecuteMsg::WillError {
+            error: "failed".to_string(),
+        })
+        .unwrap(),
+    );
+    let message2 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+
+    let permission_token = build_tokenfactory_denom(&authorization_contract, "permissioned");
 
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-            label: "permissionless".to_string(),
-            messages: vec![message1.clone(), message1],
+            label: "permissioned".to_string(),
+            messages: vec![message1, message2],
             ttl: None,
         }),
-        &[],
+        &[Coin::new(Uint128::one(), permission_token.to_string())],
         &setup.user_accounts[0],
     )
     .unwrap();
 
-    // Ticking the first time will make the first function succeed and re-add the batch to the queue
-    wasm.execute::<ProcessorExecuteMsg>(
-        &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
-        &[],
-        &setup.owner_accounts[0],
-    )
-    .unwrap();
-
-    // Confirm that we have it in the queue
-    let query_med_prio_queue = wasm
-        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+    // Ticking twice will execute the first function and fail the second one, which will be put back in the queue for retrying
+    for _ in 0..2 {
+        wasm.execute::<ProcessorExecuteMsg>(
             &processor_contract,
-            &ProcessorQueryMsg::GetQueue {
-                from: None,
-                to: None,
-                priority: Priority::Medium,
-            },
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
+            &[],
+            &setup.owner_accounts[0],
         )
         .unwrap();
+    }
 
-    assert_eq!(query_med_prio_queue.len(), 1);
+    // Let the execution time limit pass, next tick should expire the batch instead of retrying
+    setup.app.increase_time(200);
 
-    // Ticking a second time will put the function in a pending callback confirmation state, removing it from the queue
     wasm.execute::<ProcessorExecuteMsg>(
         &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // Confirm that we have no more batches in the queue
     let query_med_prio_queue = wasm
         .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
             &processor_contract,
@@ -2582,23 +2544,182 @@ fn reject_and_confirm_non_atomic_function_with_callback() {
         )
         .unwrap();
 
-    assert_eq!(query_med_prio_queue.len(), 0);
+    assert!(query_med_prio_queue.is_empty());
 
-    // Sending the wrong callback will re-add the batch to the queue to retry the function
-    let callback = Binary::from("Wrong".as_bytes());
+    let query_callbacks = wasm
+        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
 
-    wasm.execute::<TestLibraryExecuteMsg>(
-        &test_library_contract,
-        &TestLibraryExecuteMsg::SendCallback {
-            to: processor_contract.to_string(),
-            callback,
-        },
+    assert_eq!(query_callbacks.len(), 1);
+    // The first function was executed before the batch expired
+    assert_eq!(
+        query_callbacks[0].execution_result,
+        ExecutionResult::Expired(1)
+    );
+
+    // Since part of the batch was executed, the token must have been burned
+    let bank = Bank::new(&setup.app);
+    let balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: setup.user_accounts[0].address(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(balance.balance.unwrap().amount, "0");
+
+    let balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: authorization_contract.clone(),
+            denom: permission_token,
+        })
+        .unwrap();
+
+    assert_eq!(balance.balance.unwrap().amount, "0");
+}
+
+#[test]
+fn successful_non_atomic_and_atomic_batches_together() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+
+    let (authorization_contract, processor_contract) =
+        store_and_instantiate_authorization_with_processor_contract(
+            &setup.app,
+            &setup.owner_accounts[0],
+            setup.owner_addr.to_string(),
+            vec![setup.subowner_addr.to_string()],
+        );
+    let test_library_contract =
+        store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
+
+    // We'll create two authorizations, one atomic and one non-atomic, with 2 functions each where both of them will succeed
+    let authorizations = vec![
+        AuthorizationBuilder::new()
+            .with_label("permissionless-atomic")
+            .with_subroutine(
+                AtomicSubroutineBuilder::new()
+                    .with_function(
+                        AtomicFunctionBuilder::new()
+                            .with_contract_address(LibraryAccountType::Addr(
+                                test_library_contract.clone(),
+                            ))
+                            .with_message_details(MessageDetails {
+                                message_type: MessageType::CosmwasmExecuteMsg,
+                                message: Message {
+                                    name: "will_succeed".to_string(),
+                                    params_restrictions: None,
+                                },
+                            })
+                            .build(),
+                    )
+                    .with_function(
+                        AtomicFunctionBuilder::new()
+                            .with_contract_address(LibraryAccountType::Addr(
+                                test_library_contract.clone(),
+                            ))
+                            .with_message_details(MessageDetails {
+                                message_type: MessageType::CosmwasmExecuteMsg,
+                                message: Message {
+                                    name: "will_succeed".to_string(),
+                                    params_restrictions: None,
+                                },
+                            })
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build(),
+        AuthorizationBuilder::new()
+            .with_label("permissionless-non-atomic")
+            .with_subroutine(
+                NonAtomicSubroutineBuilder::new()
+                    .with_function(
+                        NonAtomicFunctionBuilder::new()
+                            .with_contract_address(&test_library_contract.clone())
+                            .with_message_details(MessageDetails {
+                                message_type: MessageType::CosmwasmExecuteMsg,
+                                message: Message {
+                                    name: "will_succeed".to_string(),
+                                    params_restrictions: None,
+                                },
+                            })
+                            .build(),
+                    )
+                    .with_function(
+                        NonAtomicFunctionBuilder::new()
+                            .with_contract_address(&test_library_contract)
+                            .with_message_details(MessageDetails {
+                                message_type: MessageType::CosmwasmExecuteMsg,
+                                message: Message {
+                                    name: "will_succeed".to_string(),
+                                    params_restrictions: None,
+                                },
+                            })
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build(),
+    ];
+
+    // Create them and send the messages
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // Confirm that we have it in the queue again
+    let binary = Binary::from(
+        serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
+    );
+    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+            label: "permissionless-atomic".to_string(),
+            messages: vec![message1.clone(), message1.clone()],
+            ttl: None,
+        }),
+        &[],
+        &setup.user_accounts[0],
+    )
+    .unwrap();
+
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+            label: "permissionless-non-atomic".to_string(),
+            messages: vec![message1.clone(), message1.clone()],
+            ttl: None,
+        }),
+        &[],
+        &setup.user_accounts[0],
+    )
+    .unwrap();
+
+    // Ticking the first time will make the atomic batch succeed
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Confirm that we have only 1 batch left in the queue
     let query_med_prio_queue = wasm
         .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
             &processor_contract,
@@ -2612,46 +2733,732 @@ fn reject_and_confirm_non_atomic_function_with_callback() {
 
     assert_eq!(query_med_prio_queue.len(), 1);
 
-    setup.app.increase_time(5);
+    // Confirm we got callback for the atomic batch
+    let query_callbacks = wasm
+        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
 
-    // Tick again to retry the message
-    wasm.execute::<ProcessorExecuteMsg>(
-        &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+    assert_eq!(query_callbacks.len(), 2);
+    assert_eq!(query_callbacks[0].messages.len(), 2);
+    assert_eq!(
+        query_callbacks[0].execution_result,
+        ExecutionResult::Success
+    );
+
+    // For the non-atomic batch we need to tick 2 times to process it
+    for _ in 0..2 {
+        wasm.execute::<ProcessorExecuteMsg>(
+            &processor_contract,
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
+            &[],
+            &setup.owner_accounts[0],
+        )
+        .unwrap();
+
+        setup.app.increase_time(5);
+    }
+
+    // Check that we have no more batches in the queue
+    let query_med_prio_queue = wasm
+        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority: Priority::Medium,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_med_prio_queue.len(), 0);
+
+    // Confirm we got callback for the non-atomic batch
+    let query_callbacks = wasm
+        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_callbacks.len(), 2);
+    for confirmed_callback in query_callbacks.iter() {
+        assert_eq!(confirmed_callback.messages.len(), 2);
+        assert_eq!(
+            confirmed_callback.execution_result,
+            ExecutionResult::Success
+        );
+    }
+}
+
+#[test]
+fn reject_and_confirm_non_atomic_function_with_callback() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+
+    let (authorization_contract, processor_contract) =
+        store_and_instantiate_authorization_with_processor_contract(
+            &setup.app,
+            &setup.owner_accounts[0],
+            setup.owner_addr.to_string(),
+            vec![setup.subowner_addr.to_string()],
+        );
+    let test_library_contract =
+        store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
+
+    // We'll create an authorization with 2 functions, where both will succeed but second one needs to confirmed with a callback
+    let authorizations = vec![AuthorizationBuilder::new()
+        .with_label("permissionless")
+        .with_subroutine(
+            NonAtomicSubroutineBuilder::new()
+                .with_function(
+                    NonAtomicFunctionBuilder::new()
+                        .with_contract_address(&test_library_contract)
+                        .with_message_details(MessageDetails {
+                            message_type: MessageType::CosmwasmExecuteMsg,
+                            message: Message {
+                                name: "will_succeed".to_string(),
+                                params_restrictions: None,
+                            },
+                        })
+                        .build(),
+                )
+                .with_function(
+                    NonAtomicFunctionBuilder::new()
+                        .with_contract_address(&test_library_contract)
+                        .with_retry_logic(RetryLogic {
+                            times: RetryTimes::Indefinitely,
+                            interval: Duration::Time(2),
+                        })
+                        .with_message_details(MessageDetails {
+                            message_type: MessageType::CosmwasmExecuteMsg,
+                            message: Message {
+                                name: "will_succeed".to_string(),
+                                params_restrictions: None,
+                            },
+                        })
+                        .with_callback_confirmation(FunctionCallback {
+                            contract_address: Addr::unchecked(test_library_contract.to_string()),
+                            callback_message: Binary::from("Confirmed".as_bytes()),
+                        })
+                        .build(),
+                )
+                .build(),
+        )
+        .build()];
+
+    // Create the authorization and send the messages
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    let binary = Binary::from(
+        serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
+    );
+    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+            label: "permissionless".to_string(),
+            messages: vec![message1.clone(), message1],
+            ttl: None,
+        }),
+        &[],
+        &setup.user_accounts[0],
+    )
+    .unwrap();
+
+    // Ticking the first time will make the first function succeed and re-add the batch to the queue
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Confirm that we have it in the queue
+    let query_med_prio_queue = wasm
+        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority: Priority::Medium,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_med_prio_queue.len(), 1);
+
+    // Ticking a second time will put the function in a pending callback confirmation state, removing it from the queue
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Confirm that we have no more batches in the queue
+    let query_med_prio_queue = wasm
+        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority: Priority::Medium,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_med_prio_queue.len(), 0);
+
+    // Sending the wrong callback will re-add the batch to the queue to retry the function
+    let callback = Binary::from("Wrong".as_bytes());
+
+    wasm.execute::<TestLibraryExecuteMsg>(
+        &test_library_contract,
+        &TestLibraryExecuteMsg::SendCallback {
+            to: processor_contract.to_string(),
+            callback,
+        },
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Confirm that we have it in the queue again
+    let query_med_prio_queue = wasm
+        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority: Priority::Medium,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_med_prio_queue.len(), 1);
+
+    setup.app.increase_time(5);
+
+    // Tick again to retry the message
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Check that it's not in the queue
+    let query_med_prio_queue = wasm
+        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority: Priority::Medium,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_med_prio_queue.len(), 0);
+
+    // Send the right callback to confirm
+    let callback = Binary::from("Confirmed".as_bytes());
+
+    wasm.execute::<TestLibraryExecuteMsg>(
+        &test_library_contract,
+        &TestLibraryExecuteMsg::SendCallback {
+            to: processor_contract.to_string(),
+            callback,
+        },
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // It shouldn't be in the queue now and we should have the confirmed callback
+    let query_med_prio_queue = wasm
+        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority: Priority::Medium,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_med_prio_queue.len(), 0);
+
+    let query_callbacks = wasm
+        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_callbacks.len(), 1);
+    assert_eq!(query_callbacks[0].messages.len(), 2);
+    assert_eq!(
+        query_callbacks[0].execution_result,
+        ExecutionResult::Success
+    );
+}
+
+#[test]
+fn refund_and_burn_tokens_after_callback() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+    let bank = Bank::new(&setup.app);
+
+    let (authorization_contract, processor_contract) =
+        store_and_instantiate_authorization_with_processor_contract(
+            &setup.app,
+            &setup.owner_accounts[0],
+            setup.owner_addr.to_string(),
+            vec![setup.subowner_addr.to_string()],
+        );
+    let test_library_contract =
+        store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
+
+    // We'll create an authorization that we'll force to fail and succeed once to check that refund and burning works
+    let authorizations = vec![AuthorizationBuilder::new()
+        .with_label("permissioned-with-limit")
+        .with_mode(AuthorizationModeInfo::Permissioned(
+            PermissionTypeInfo::WithCallLimit(vec![(
+                setup.user_accounts[0].address(),
+                // Mint two tokens to also check concurrent executions
+                Uint128::new(2),
+            )]),
+        ))
+        .with_subroutine(
+            NonAtomicSubroutineBuilder::new()
+                .with_function(
+                    NonAtomicFunctionBuilder::new()
+                        .with_contract_address(&test_library_contract)
+                        .with_message_details(MessageDetails {
+                            message_type: MessageType::CosmwasmExecuteMsg,
+                            message: Message {
+                                name: "will_succeed_if_true".to_string(),
+                                params_restrictions: None,
+                            },
+                        })
+                        .build(),
+                )
+                .build(),
+        )
+        .build()];
+
+    // Create the authorization and messages that will be sent
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    let binary =
+        Binary::from(serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceedIfTrue {}).unwrap());
+
+    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+
+    // The token that was minted to the user
+    let permission_token =
+        build_tokenfactory_denom(&authorization_contract, "permissioned-with-limit");
+
+    // Sending the message will enqueue into the processor
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+            label: "permissioned-with-limit".to_string(),
+            messages: vec![message1.clone()],
+            ttl: None,
+        }),
+        &[Coin::new(Uint128::one(), permission_token.to_string())],
+        &setup.user_accounts[0],
+    )
+    .unwrap();
+
+    // Trying to send again will fail because there's only 1 concurrent execution allowed
+    let error = wasm
+        .execute::<ExecuteMsg>(
+            &authorization_contract,
+            &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+                label: "permissioned-with-limit".to_string(),
+                messages: vec![message1.clone()],
+                ttl: None,
+            }),
+            &[Coin::new(Uint128::one(), permission_token.to_string())],
+            &setup.user_accounts[0],
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().contains(
+        ContractError::Authorization(AuthorizationErrorReason::MaxConcurrentExecutionsReached {})
+            .to_string()
+            .as_str()
+    ));
+
+    // Check the balance of the user to verify that they don't have any tokens left
+    let balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: setup.user_accounts[0].address(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(balance.balance.unwrap().amount, "1");
+
+    // Ticking the processor will make it fail and send a Rejected callback, which should refund the token to the user
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Check that the user has been refunded
+    let balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: setup.user_accounts[0].address(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(balance.balance.unwrap().amount, "2");
+
+    // Now we should be able to enqueue again
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+            label: "permissioned-with-limit".to_string(),
+            messages: vec![message1],
+            ttl: None,
+        }),
+        &[Coin::new(Uint128::one(), permission_token.to_string())],
+        &setup.user_accounts[0],
+    )
+    .unwrap();
+
+    // Modify the test library to make the message succeed when it eventually executes
+    wasm.execute::<TestLibraryExecuteMsg>(
+        &test_library_contract,
+        &TestLibraryExecuteMsg::SetCondition { condition: true },
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Ticking the processor will make it succeed and send a Success callback, which should burn the token instead of refunding it
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // Verify that user still has 1 token and contract doesn't have any
+    let user_balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: setup.user_accounts[0].address(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(user_balance.balance.unwrap().amount, "1");
+
+    let contract_balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: authorization_contract.clone(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(contract_balance.balance.unwrap().amount, "0");
+}
+
+#[test]
+fn burn_tokens_after_removed_by_owner() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+    let bank = Bank::new(&setup.app);
+
+    let (authorization_contract, _) = store_and_instantiate_authorization_with_processor_contract(
+        &setup.app,
+        &setup.owner_accounts[0],
+        setup.owner_addr.to_string(),
+        vec![setup.subowner_addr.to_string()],
+    );
+    let test_library_contract =
+        store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
+
+    // We'll create an authorization that we'll remove by the owner to check that the token is correctly burned
+    let authorizations = vec![AuthorizationBuilder::new()
+        .with_label("permissioned-with-limit")
+        .with_mode(AuthorizationModeInfo::Permissioned(
+            PermissionTypeInfo::WithCallLimit(vec![(
+                setup.user_accounts[0].address(),
+                // Mint one token to allow 1 execution
+                Uint128::one(),
+            )]),
+        ))
+        .with_subroutine(
+            NonAtomicSubroutineBuilder::new()
+                .with_function(
+                    NonAtomicFunctionBuilder::new()
+                        .with_contract_address(&test_library_contract)
+                        .with_message_details(MessageDetails {
+                            message_type: MessageType::CosmwasmExecuteMsg,
+                            message: Message {
+                                name: "will_succeed".to_string(),
+                                params_restrictions: None,
+                            },
+                        })
+                        .build(),
+                )
+                .build(),
+        )
+        .build()];
+
+    // Create the authorization and messages that will be sent
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    let binary = Binary::from(
+        serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
+    );
+
+    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+
+    // The token that was minted to the user
+    let permission_token =
+        build_tokenfactory_denom(&authorization_contract, "permissioned-with-limit");
+
+    // Sending the message will enqueue into the processor
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+            label: "permissioned-with-limit".to_string(),
+            messages: vec![message1.clone()],
+            ttl: None,
+        }),
+        &[Coin::new(Uint128::one(), permission_token.to_string())],
+        &setup.user_accounts[0],
+    )
+    .unwrap();
+
+    // Let's balance of the user to verify that he doesn't have any tokens left
+    let balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: setup.user_accounts[0].address(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(balance.balance.unwrap().amount, "0");
+
+    // Verify that the contract has the token in escrow
+    let contract_balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: authorization_contract.clone(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(contract_balance.balance.unwrap().amount, "1");
+
+    // Remove the message by owner
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::EvictMsgs {
+            domain: Domain::Main,
+            queue_position: 0,
+            priority: Priority::Medium,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    // We should have a confirmed callback  with RemovedByOwner result
+    let query_callbacks = wasm
+        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(query_callbacks.len(), 1);
+    assert_eq!(
+        query_callbacks[0].execution_result,
+        ExecutionResult::RemovedByOwner
+    );
+
+    // Token should have been burnt, not refunded
+    let contract_balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: authorization_contract.clone(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(contract_balance.balance.unwrap().amount, "0");
+
+    // User should still have 0 tokens
+    let balance = bank
+        .query_balance(&QueryBalanceRequest {
+            address: setup.user_accounts[0].address(),
+            denom: permission_token.clone(),
+        })
+        .unwrap();
+
+    assert_eq!(balance.balance.unwrap().amount, "0");
+}
+
+#[test]
+fn migration() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+
+    let (authorization_contract, processor_contract) =
+        store_and_instantiate_authorization_with_processor_contract(
+            &setup.app,
+            &setup.owner_accounts[0],
+            setup.owner_addr.to_string(),
+            vec![setup.subowner_addr.to_string()],
+        );
+    let test_library_contract = store_and_instantiate_test_library(
+        &wasm,
+        &setup.owner_accounts[0],
+        Some(&processor_contract),
+    );
+
+    // Store it again to get a new code id
+    let wasm_byte_code =
+        std::fs::read(format!("{}/valence_test_library.wasm", ARTIFACTS_DIR)).unwrap();
+
+    let code_id = wasm
+        .store_code(&wasm_byte_code, None, &setup.owner_accounts[0])
+        .unwrap()
+        .data
+        .code_id;
+
+    // Create an authorization with 1 function to migrate
+    let authorizations = vec![AuthorizationBuilder::new()
+        .with_label("permissionless")
+        .with_subroutine(
+            AtomicSubroutineBuilder::new()
+                .with_function(
+                    AtomicFunctionBuilder::new()
+                        .with_contract_address(LibraryAccountType::Addr(
+                            test_library_contract.clone(),
+                        ))
+                        .with_message_details(MessageDetails {
+                            message_type: MessageType::CosmwasmMigrateMsg,
+                            message: Message {
+                                name: "migrate".to_string(),
+                                params_restrictions: None,
+                            },
+                        })
+                        .build(),
+                )
+                .build(),
+        )
+        .build()];
+
+    // Create the authorization and send the message
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // Check that it's not in the queue
-    let query_med_prio_queue = wasm
-        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
-            &processor_contract,
-            &ProcessorQueryMsg::GetQueue {
-                from: None,
-                to: None,
-                priority: Priority::Medium,
-            },
-        )
-        .unwrap();
-
-    assert_eq!(query_med_prio_queue.len(), 0);
+    let binary = Binary::from(
+        serde_json::to_vec(&valence_test_library::msg::MigrateMsg::Migrate {
+            new_condition: true,
+        })
+        .unwrap(),
+    );
+    let message = ProcessorMessage::CosmwasmMigrateMsg {
+        code_id,
+        msg: binary,
+    };
 
-    // Send the right callback to confirm
-    let callback = Binary::from("Confirmed".as_bytes());
+    // Send the message
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+            label: "permissionless".to_string(),
+            messages: vec![message],
+            ttl: None,
+        }),
+        &[],
+        &setup.user_accounts[0],
+    )
+    .unwrap();
 
-    wasm.execute::<TestLibraryExecuteMsg>(
-        &test_library_contract,
-        &TestLibraryExecuteMsg::SendCallback {
-            to: processor_contract.to_string(),
-            callback,
-        },
+    // Ticking the first time will make the migration succeed
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // It shouldn't be in the queue now and we should have the confirmed callback
+    // Check that it was removed from the queue and we got the callback
     let query_med_prio_queue = wasm
         .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
             &processor_contract,
@@ -2676,45 +3483,55 @@ fn reject_and_confirm_non_atomic_function_with_callback() {
         .unwrap();
 
     assert_eq!(query_callbacks.len(), 1);
-    assert_eq!(query_callbacks[0].messages.len(), 2);
+    assert_eq!(query_callbacks[0].messages.len(), 1);
     assert_eq!(
         query_callbacks[0].execution_result,
         ExecutionResult::Success
     );
+
+    // Check that indeed it was migrated by querying the contract
+    let query_condition = wasm
+        .query::<TestLibraryQueryMsg, bool>(
+            &test_library_contract,
+            &TestLibraryQueryMsg::Condition {},
+        )
+        .unwrap();
+
+    assert!(query_condition);
 }
 
 #[test]
-fn refund_and_burn_tokens_after_callback() {
+fn circuit_breaker_trips_and_resets() {
     let setup = NeutronTestAppBuilder::new().build().unwrap();
 
     let wasm = Wasm::new(&setup.app);
-    let bank = Bank::new(&setup.app);
 
     let (authorization_contract, processor_contract) =
-        store_and_instantiate_authorization_with_processor_contract(
+        store_and_instantiate_authorization_with_processor_contract_and_circuit_breaker(
             &setup.app,
             &setup.owner_accounts[0],
             setup.owner_addr.to_string(),
             vec![setup.subowner_addr.to_string()],
+            Some(CircuitBreaker {
+                failure_threshold: 2,
+                reset_after_blocks: 1000,
+            }),
         );
     let test_library_contract =
         store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
 
-    // We'll create an authorization that we'll force to fail and succeed once to check that refund and burning works
+    // Authorization with a single function that fails until we modify the test library, retried indefinitely
     let authorizations = vec![AuthorizationBuilder::new()
-        .with_label("permissioned-with-limit")
-        .with_mode(AuthorizationModeInfo::Permissioned(
-            PermissionTypeInfo::WithCallLimit(vec![(
-                setup.user_accounts[0].address(),
-                // Mint two tokens to also check concurrent executions
-                Uint128::new(2),
-            )]),
-        ))
+        .with_label("permissionless")
         .with_subroutine(
             NonAtomicSubroutineBuilder::new()
                 .with_function(
                     NonAtomicFunctionBuilder::new()
                         .with_contract_address(&test_library_contract)
+                        .with_retry_logic(RetryLogic {
+                            times: RetryTimes::Indefinitely,
+                            interval: Duration::Time(2),
+                        })
                         .with_message_details(MessageDetails {
                             message_type: MessageType::CosmwasmExecuteMsg,
                             message: Message {
@@ -2728,7 +3545,6 @@ fn refund_and_burn_tokens_after_callback() {
         )
         .build()];
 
-    // Create the authorization and messages that will be sent
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
@@ -2740,151 +3556,194 @@ fn refund_and_burn_tokens_after_callback() {
     let binary =
         Binary::from(serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceedIfTrue {}).unwrap());
 
-    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
-
-    // The token that was minted to the user
-    let permission_token =
-        build_tokenfactory_denom(&authorization_contract, "permissioned-with-limit");
-
-    // Sending the message will enqueue into the processor
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-            label: "permissioned-with-limit".to_string(),
-            messages: vec![message1.clone()],
+            label: "permissionless".to_string(),
+            messages: vec![ProcessorMessage::CosmwasmExecuteMsg { msg: binary }],
             ttl: None,
         }),
-        &[Coin::new(Uint128::one(), permission_token.to_string())],
+        &[],
         &setup.user_accounts[0],
     )
     .unwrap();
 
-    // Trying to send again will fail because there's only 1 concurrent execution allowed
-    let error = wasm
-        .execute::<ExecuteMsg>(
-            &authorization_contract,
-            &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-                label: "permissioned-with-limit".to_string(),
-                messages: vec![message1.clone()],
-                ttl: None,
-            }),
-            &[Coin::new(Uint128::one(), permission_token.to_string())],
-            &setup.user_accounts[0],
-        )
-        .unwrap_err();
+    let tick = || {
+        let response = wasm
+            .execute::<ProcessorExecuteMsg>(
+                &processor_contract,
+                &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                    simulate: false,
+                }),
+                &[],
+                &setup.owner_accounts[0],
+            )
+            .unwrap();
+        setup.app.increase_time(5);
+        response
+    };
 
-    assert!(error.to_string().contains(
-        ContractError::Authorization(AuthorizationErrorReason::MaxConcurrentExecutionsReached {})
-            .to_string()
-            .as_str()
-    ));
+    // First failure doesn't trip the circuit breaker
+    let response = tick();
+    assert!(!response
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-circuit_breaker_tripped"));
 
-    // Check the balance of the user to verify that they don't have any tokens left
-    let balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: setup.user_accounts[0].address(),
-            denom: permission_token.clone(),
-        })
-        .unwrap();
+    // Second consecutive failure trips it
+    let response = tick();
+    assert!(response
+        .events
+        .iter()
+        .any(|event| event.ty == "wasm-circuit_breaker_tripped"));
 
-    assert_eq!(balance.balance.unwrap().amount, "1");
+    // From now on the batch is not executed, it's just pushed back to the queue
+    let response = tick();
+    assert!(response.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(
+            |attribute| attribute.key == "action" && attribute.value == "circuit_breaker_tripped"
+        )));
 
-    // Ticking the processor will make it fail and send a Rejected callback, which should refund the token to the user
-    wasm.execute::<ProcessorExecuteMsg>(
-        &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+    // Make the library succeed, the batch is still not executed until we reset the circuit breaker
+    wasm.execute::<TestLibraryExecuteMsg>(
+        &test_library_contract,
+        &TestLibraryExecuteMsg::SetCondition { condition: true },
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // Check that the user has been refunded
-    let balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: setup.user_accounts[0].address(),
-            denom: permission_token.clone(),
-        })
+    tick();
+
+    let query_callbacks = wasm
+        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
+    assert_eq!(
+        query_callbacks[0].execution_result,
+        ExecutionResult::InProcess
+    );
 
-    assert_eq!(balance.balance.unwrap().amount, "2");
+    // Only the owner or subowners of the authorization contract can reset it
+    assert!(wasm
+        .execute::<ExecuteMsg>(
+            &authorization_contract,
+            &ExecuteMsg::PermissionedAction(PermissionedMsg::ResetCircuitBreaker {
+                domain: Domain::Main,
+                library: test_library_contract.clone(),
+            }),
+            &[],
+            &setup.user_accounts[0],
+        )
+        .is_err());
 
-    // Now we should be able to enqueue again
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
-        &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-            label: "permissioned-with-limit".to_string(),
-            messages: vec![message1],
-            ttl: None,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::ResetCircuitBreaker {
+            domain: Domain::Main,
+            library: test_library_contract.clone(),
         }),
-        &[Coin::new(Uint128::one(), permission_token.to_string())],
-        &setup.user_accounts[0],
-    )
-    .unwrap();
-
-    // Modify the test library to make the message succeed when it eventually executes
-    wasm.execute::<TestLibraryExecuteMsg>(
-        &test_library_contract,
-        &TestLibraryExecuteMsg::SetCondition { condition: true },
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // Ticking the processor will make it succeed and send a Success callback, which should burn the token instead of refunding it
-    wasm.execute::<ProcessorExecuteMsg>(
-        &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
-        &[],
-        &setup.owner_accounts[0],
-    )
-    .unwrap();
+    // After the reset the batch is executed and succeeds
+    tick();
 
-    // Verify that user still has 1 token and contract doesn't have any
-    let user_balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: setup.user_accounts[0].address(),
-            denom: permission_token.clone(),
-        })
+    let query_callbacks = wasm
+        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
         .unwrap();
+    assert_eq!(
+        query_callbacks[0].execution_result,
+        ExecutionResult::Success
+    );
+}
 
-    assert_eq!(user_balance.balance.unwrap().amount, "1");
+#[test]
+fn instantiate_fails_for_invalid_circuit_breaker() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
 
-    let contract_balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: authorization_contract.clone(),
-            denom: permission_token.clone(),
-        })
-        .unwrap();
+    let wasm = Wasm::new(&setup.app);
 
-    assert_eq!(contract_balance.balance.unwrap().amount, "0");
+    let wasm_byte_code_processor =
+        std::fs::read(format!("{}/valence_processor.wasm", ARTIFACTS_DIR)).unwrap();
+    let code_id_processor = wasm
+        .store_code(&wasm_byte_code_processor, None, &setup.owner_accounts[0])
+        .unwrap()
+        .data
+        .code_id;
+
+    for (circuit_breaker, reason) in [
+        (
+            CircuitBreaker {
+                failure_threshold: 0,
+                reset_after_blocks: 1000,
+            },
+            "failure_threshold must be greater than 0",
+        ),
+        (
+            CircuitBreaker {
+                failure_threshold: 2,
+                reset_after_blocks: 0,
+            },
+            "reset_after_blocks must be greater than 0",
+        ),
+    ] {
+        let error = wasm
+            .instantiate(
+                code_id_processor,
+                &ProcessorInstantiateMsg {
+                    authorization_contract: setup.owner_addr.to_string(),
+                    polytone_contracts: None,
+                    circuit_breaker: Some(circuit_breaker),
+                },
+                None,
+                "processor".into(),
+                &[],
+                &setup.owner_accounts[0],
+            )
+            .unwrap_err();
+
+        assert!(error.to_string().contains(
+            ProcessorContractError::InvalidCircuitBreaker(reason.to_string())
+                .to_string()
+                .as_str()
+        ));
+    }
 }
 
 #[test]
-fn burn_tokens_after_removed_by_owner() {
+fn simulated_tick_does_not_modify_state() {
     let setup = NeutronTestAppBuilder::new().build().unwrap();
 
     let wasm = Wasm::new(&setup.app);
-    let bank = Bank::new(&setup.app);
 
-    let (authorization_contract, _) = store_and_instantiate_authorization_with_processor_contract(
-        &setup.app,
-        &setup.owner_accounts[0],
-        setup.owner_addr.to_string(),
-        vec![setup.subowner_addr.to_string()],
-    );
+    let (authorization_contract, processor_contract) =
+        store_and_instantiate_authorization_with_processor_contract(
+            &setup.app,
+            &setup.owner_accounts[0],
+            setup.owner_addr.to_string(),
+            vec![setup.subowner_addr.to_string()],
+        );
     let test_library_contract =
         store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
 
-    // We'll create an authorization that we'll remove by the owner to check that the token is correctly burned
     let authorizations = vec![AuthorizationBuilder::new()
-        .with_label("permissioned-with-limit")
-        .with_mode(AuthorizationModeInfo::Permissioned(
-            PermissionTypeInfo::WithCallLimit(vec![(
-                setup.user_accounts[0].address(),
-                // Mint one token to allow 1 execution
-                Uint128::one(),
-            )]),
-        ))
+        .with_label("permissionless")
         .with_subroutine(
             NonAtomicSubroutineBuilder::new()
                 .with_function(
@@ -2903,7 +3762,6 @@ fn burn_tokens_after_removed_by_owner() {
         )
         .build()];
 
-    // Create the authorization and messages that will be sent
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
@@ -2913,101 +3771,214 @@ fn burn_tokens_after_removed_by_owner() {
     .unwrap();
 
     let binary = Binary::from(
-        serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
-    );
-
-    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
-
-    // The token that was minted to the user
-    let permission_token =
-        build_tokenfactory_denom(&authorization_contract, "permissioned-with-limit");
+        serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
+    );
 
-    // Sending the message will enqueue into the processor
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-            label: "permissioned-with-limit".to_string(),
-            messages: vec![message1.clone()],
+            label: "permissionless".to_string(),
+            messages: vec![ProcessorMessage::CosmwasmExecuteMsg { msg: binary }],
             ttl: None,
         }),
-        &[Coin::new(Uint128::one(), permission_token.to_string())],
+        &[],
         &setup.user_accounts[0],
     )
     .unwrap();
 
-    // Let's balance of the user to verify that he doesn't have any tokens left
-    let balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: setup.user_accounts[0].address(),
-            denom: permission_token.clone(),
-        })
+    let query_queue = || {
+        wasm.query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority: Priority::Medium,
+            },
+        )
+        .unwrap()
+    };
+    let query_callbacks = || {
+        wasm.query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
+            &authorization_contract,
+            &QueryMsg::ProcessorCallbacks {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+    };
+    let queue_before = query_queue();
+    assert_eq!(queue_before.len(), 1);
+
+    // Simulating returns the message that would be sent to the library
+    let response = wasm
+        .execute::<ProcessorExecuteMsg>(
+            &processor_contract,
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: true,
+            }),
+            &[],
+            &setup.owner_accounts[0],
+        )
         .unwrap();
 
-    assert_eq!(balance.balance.unwrap().amount, "0");
+    let attributes: Vec<_> = response
+        .events
+        .iter()
+        .filter(|event| event.ty == "wasm")
+        .flat_map(|event| event.attributes.clone())
+        .collect();
+    assert!(attributes
+        .iter()
+        .any(|attribute| attribute.key == "action" && attribute.value == "execute_batch"));
+    assert!(attributes
+        .iter()
+        .any(|attribute| attribute.key == "messages"
+            && attribute.value.contains(test_library_contract.as_str())));
 
-    // Verify that the contract has the token in escrow
-    let contract_balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: authorization_contract.clone(),
-            denom: permission_token.clone(),
-        })
-        .unwrap();
+    // Nothing was executed and the batch is still in the queue
+    assert_eq!(query_queue(), queue_before);
+    assert_eq!(
+        query_callbacks()[0].execution_result,
+        ExecutionResult::InProcess
+    );
 
-    assert_eq!(contract_balance.balance.unwrap().amount, "1");
+    // A real tick executes the batch
+    wasm.execute::<ProcessorExecuteMsg>(
+        &processor_contract,
+        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+            simulate: false,
+        }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    assert!(query_queue().is_empty());
+    assert_eq!(
+        query_callbacks()[0].execution_result,
+        ExecutionResult::Success
+    );
+}
+
+#[test]
+fn execution_history() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+
+    let (authorization_contract, processor_contract) =
+        store_and_instantiate_authorization_with_processor_contract(
+            &setup.app,
+            &setup.owner_accounts[0],
+            setup.owner_addr.to_string(),
+            vec![setup.subowner_addr.to_string()],
+        );
+    let test_library_contract =
+        store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
+
+    // One authorization that always fails and one that always succeeds
+    let authorizations = [("will_error", "failing"), ("will_succeed", "succeeding")]
+        .into_iter()
+        .map(|(message, label)| {
+            AuthorizationBuilder::new()
+                .with_label(label)
+                .with_subroutine(
+                    AtomicSubroutineBuilder::new()
+                        .with_function(
+                            AtomicFunctionBuilder::new()
+                                .with_contract_address(LibraryAccountType::Addr(
+                                    test_library_contract.clone(),
+                                ))
+                                .with_message_details(MessageDetails {
+                                    message_type: MessageType::CosmwasmExecuteMsg,
+                                    message: Message {
+                                        name: message.to_string(),
+                                        params_restrictions: None,
+                                    },
+                                })
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build()
+        })
+        .collect();
 
-    // Remove the message by owner
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
-        &ExecuteMsg::PermissionedAction(PermissionedMsg::EvictMsgs {
-            domain: Domain::Main,
-            queue_position: 0,
-            priority: Priority::Medium,
-        }),
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // We should have a confirmed callback  with RemovedByOwner result
-    let query_callbacks = wasm
-        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
-            &authorization_contract,
-            &QueryMsg::ProcessorCallbacks {
-                start_after: None,
-                limit: None,
+    let messages = [
+        (
+            "failing",
+            TestLibraryExecuteMsg::WillError {
+                error: "failed".to_string(),
             },
+        ),
+        (
+            "succeeding",
+            TestLibraryExecuteMsg::WillSucceed { execution_id: None },
+        ),
+    ];
+    for (label, msg) in messages {
+        wasm.execute::<ExecuteMsg>(
+            &authorization_contract,
+            &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+                label: label.to_string(),
+                messages: vec![ProcessorMessage::CosmwasmExecuteMsg {
+                    msg: Binary::from(serde_json::to_vec(&msg).unwrap()),
+                }],
+                ttl: None,
+            }),
+            &[],
+            &setup.user_accounts[0],
         )
         .unwrap();
 
-    assert_eq!(query_callbacks.len(), 1);
-    assert_eq!(
-        query_callbacks[0].execution_result,
-        ExecutionResult::RemovedByOwner
-    );
-
-    // Token should have been burnt, not refunded
-    let contract_balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: authorization_contract.clone(),
-            denom: permission_token.clone(),
-        })
+        wasm.execute::<ProcessorExecuteMsg>(
+            &processor_contract,
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
+            &[],
+            &setup.owner_accounts[0],
+        )
         .unwrap();
+    }
 
-    assert_eq!(contract_balance.balance.unwrap().amount, "0");
+    let query_history = |start: Option<u64>, limit: Option<u32>| {
+        wasm.query::<ProcessorQueryMsg, Vec<ExecutionRecord>>(
+            &processor_contract,
+            &ProcessorQueryMsg::GetExecutionHistory { start, limit },
+        )
+        .unwrap()
+    };
 
-    // User should still have 0 tokens
-    let balance = bank
-        .query_balance(&QueryBalanceRequest {
-            address: setup.user_accounts[0].address(),
-            denom: permission_token.clone(),
-        })
-        .unwrap();
+    let history = query_history(None, None);
+    assert_eq!(history.len(), 2);
 
-    assert_eq!(balance.balance.unwrap().amount, "0");
+    assert_eq!(history[0].batch_id, 0);
+    assert!(matches!(history[0].result, ExecutionResult::Rejected(_)));
+    assert!(history[0].error.as_ref().unwrap().contains("failed"));
+    assert_eq!(history[0].library, Addr::unchecked(&test_library_contract));
+
+    assert_eq!(history[1].batch_id, 1);
+    assert_eq!(history[1].result, ExecutionResult::Success);
+    assert_eq!(history[1].error, None);
+    assert!(history[1].timestamp >= history[0].timestamp);
+
+    // Pagination
+    assert_eq!(query_history(Some(1), None), vec![history[1].clone()]);
+    assert_eq!(query_history(None, Some(1)), vec![history[0].clone()]);
 }
 
 #[test]
-fn migration() {
+fn execution_history_of_evicted_non_atomic_batch() {
     let setup = NeutronTestAppBuilder::new().build().unwrap();
 
     let wasm = Wasm::new(&setup.app);
@@ -3019,36 +3990,39 @@ fn migration() {
             setup.owner_addr.to_string(),
             vec![setup.subowner_addr.to_string()],
         );
-    let test_library_contract = store_and_instantiate_test_library(
-        &wasm,
-        &setup.owner_accounts[0],
-        Some(&processor_contract),
-    );
-
-    // Store it again to get a new code id
-    let wasm_byte_code =
-        std::fs::read(format!("{}/valence_test_library.wasm", ARTIFACTS_DIR)).unwrap();
-
-    let code_id = wasm
-        .store_code(&wasm_byte_code, None, &setup.owner_accounts[0])
-        .unwrap()
-        .data
-        .code_id;
+    let first_library_contract =
+        store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
+    let second_library_contract =
+        store_and_instantiate_test_library(&wasm, &setup.owner_accounts[0], None);
 
-    // Create an authorization with 1 function to migrate
+    // The first function succeeds and the second one keeps failing, so the batch stays in the queue on the second function
     let authorizations = vec![AuthorizationBuilder::new()
-        .with_label("permissionless")
+        .with_label("non_atomic")
         .with_subroutine(
-            AtomicSubroutineBuilder::new()
+            NonAtomicSubroutineBuilder::new()
                 .with_function(
-                    AtomicFunctionBuilder::new()
-                        .with_contract_address(LibraryAccountType::Addr(
-                            test_library_contract.clone(),
-                        ))
+                    NonAtomicFunctionBuilder::new()
+                        .with_contract_address(&first_library_contract)
                         .with_message_details(MessageDetails {
-                            message_type: MessageType::CosmwasmMigrateMsg,
+                            message_type: MessageType::CosmwasmExecuteMsg,
                             message: Message {
-                                name: "migrate".to_string(),
+                                name: "will_succeed".to_string(),
+                                params_restrictions: None,
+                            },
+                        })
+                        .build(),
+                )
+                .with_function(
+                    NonAtomicFunctionBuilder::new()
+                        .with_contract_address(&second_library_contract)
+                        .with_retry_logic(RetryLogic {
+                            times: RetryTimes::Indefinitely,
+                            interval: Duration::Time(2),
+                        })
+                        .with_message_details(MessageDetails {
+                            message_type: MessageType::CosmwasmExecuteMsg,
+                            message: Message {
+                                name: "will_error".to_string(),
                                 params_restrictions: None,
                             },
                         })
@@ -3058,7 +4032,6 @@ fn migration() {
         )
         .build()];
 
-    // Create the authorization and send the message
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
@@ -3068,22 +4041,22 @@ fn migration() {
     .unwrap();
 
     let binary = Binary::from(
-        serde_json::to_vec(&valence_test_library::msg::MigrateMsg::Migrate {
-            new_condition: true,
+        serde_json::to_vec(&TestLibraryExecuteMsg::WillSucceed { execution_id: None }).unwrap(),
+    );
+    let message1 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
+    let binary = Binary::from(
+        serde_json::to_vec(&TestLibraryExecuteMsg::WillError {
+            error: "failed".to_string(),
         })
         .unwrap(),
     );
-    let message = ProcessorMessage::CosmwasmMigrateMsg {
-        code_id,
-        msg: binary,
-    };
+    let message2 = ProcessorMessage::CosmwasmExecuteMsg { msg: binary };
 
-    // Send the message
     wasm.execute::<ExecuteMsg>(
         &authorization_contract,
         &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
-            label: "permissionless".to_string(),
-            messages: vec![message],
+            label: "non_atomic".to_string(),
+            messages: vec![message1, message2],
             ttl: None,
         }),
         &[],
@@ -3091,53 +4064,46 @@ fn migration() {
     )
     .unwrap();
 
-    // Ticking the first time will make the migration succeed
-    wasm.execute::<ProcessorExecuteMsg>(
-        &processor_contract,
-        &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {}),
+    // Ticking twice will execute the first function and fail the second one, which will be put back in the queue for retrying
+    for _ in 0..2 {
+        wasm.execute::<ProcessorExecuteMsg>(
+            &processor_contract,
+            &ProcessorExecuteMsg::PermissionlessAction(ProcessorPermissionlessMsg::Tick {
+                simulate: false,
+            }),
+            &[],
+            &setup.owner_accounts[0],
+        )
+        .unwrap();
+    }
+
+    wasm.execute::<ExecuteMsg>(
+        &authorization_contract,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::EvictMsgs {
+            domain: Domain::Main,
+            queue_position: 0,
+            priority: Priority::Medium,
+        }),
         &[],
         &setup.owner_accounts[0],
     )
     .unwrap();
 
-    // Check that it was removed from the queue and we got the callback
-    let query_med_prio_queue = wasm
-        .query::<ProcessorQueryMsg, Vec<MessageBatch>>(
+    let history = wasm
+        .query::<ProcessorQueryMsg, Vec<ExecutionRecord>>(
             &processor_contract,
-            &ProcessorQueryMsg::GetQueue {
-                from: None,
-                to: None,
-                priority: Priority::Medium,
-            },
-        )
-        .unwrap();
-
-    assert_eq!(query_med_prio_queue.len(), 0);
-
-    let query_callbacks = wasm
-        .query::<QueryMsg, Vec<ProcessorCallbackInfo>>(
-            &authorization_contract,
-            &QueryMsg::ProcessorCallbacks {
-                start_after: None,
+            &ProcessorQueryMsg::GetExecutionHistory {
+                start: None,
                 limit: None,
             },
         )
         .unwrap();
 
-    assert_eq!(query_callbacks.len(), 1);
-    assert_eq!(query_callbacks[0].messages.len(), 1);
+    // The batch was evicted while it was on the second function
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].result, ExecutionResult::RemovedByOwner);
     assert_eq!(
-        query_callbacks[0].execution_result,
-        ExecutionResult::Success
+        history[0].library,
+        Addr::unchecked(&second_library_contract)
     );
-
-    // Check that indeed it was migrated by querying the contract
-    let query_condition = wasm
-        .query::<TestLibraryQueryMsg, bool>(
-            &test_library_contract,
-            &TestLibraryQueryMsg::Condition {},
-        )
-        .unwrap();
-
-    assert!(query_condition);
 }
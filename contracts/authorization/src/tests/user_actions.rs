@@ -1,12 +1,15 @@
 use cosmwasm_std::{Binary, Coin, Timestamp, Uint128};
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
 use neutron_test_tube::{Account, Module, Wasm};
 use serde_json::json;
 use valence_authorization_utils::{
-    authorization::{AuthorizationDuration, AuthorizationModeInfo, PermissionTypeInfo},
+    authorization::{
+        AuthorizationConstraint, AuthorizationDuration, AuthorizationModeInfo, PermissionTypeInfo,
+        Role,
+    },
     authorization_message::{Message, MessageDetails, MessageType, ParamRestriction},
     builders::{AtomicFunctionBuilder, AtomicSubroutineBuilder, AuthorizationBuilder, JsonBuilder},
-    msg::{ExecuteMsg, PermissionedMsg, PermissionlessMsg, ProcessorMessage},
+    msg::{ExecuteMsg, OwnerMsg, PermissionedMsg, PermissionlessMsg, ProcessorMessage, QueryMsg},
 };
 
 use crate::{
@@ -747,3 +750,314 @@ fn invalid_messages() {
             .as_str()
     ));
 }
+
+#[test]
+fn roles() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+
+    let (contract_addr, _) = store_and_instantiate_authorization_with_processor_contract(
+        &setup.app,
+        &setup.owner_accounts[0],
+        setup.owner_addr.to_string(),
+        vec![setup.subowner_addr.to_string()],
+    );
+
+    let authorizations = vec![AuthorizationBuilder::new()
+        .with_label("permissionless")
+        .with_max_concurrent_executions(10)
+        .with_subroutine(
+            AtomicSubroutineBuilder::new()
+                .with_function(AtomicFunctionBuilder::new().build())
+                .build(),
+        )
+        .build()];
+
+    wasm.execute::<ExecuteMsg>(
+        &contract_addr,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    let user = setup.user_accounts[0].address();
+    let owner_action = |msg: OwnerMsg| {
+        wasm.execute::<ExecuteMsg>(
+            &contract_addr,
+            &ExecuteMsg::OwnerAction(msg),
+            &[],
+            &setup.owner_accounts[0],
+        )
+    };
+    let send_msgs = || {
+        let binary =
+            Binary::from(serde_json::to_vec(&JsonBuilder::new().main("method").build()).unwrap());
+        wasm.execute::<ExecuteMsg>(
+            &contract_addr,
+            &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+                label: "permissionless".to_string(),
+                messages: vec![ProcessorMessage::CosmwasmExecuteMsg { msg: binary }],
+                ttl: None,
+            }),
+            &[],
+            &setup.user_accounts[0],
+        )
+    };
+    let query_role = || {
+        wasm.query::<QueryMsg, Option<Role>>(&contract_addr, &QueryMsg::Role { addr: user.clone() })
+            .unwrap()
+    };
+    let insufficient_role =
+        ContractError::Unauthorized(UnauthorizedReason::InsufficientRole {}).to_string();
+
+    // Only the owner can assign roles
+    let error = wasm
+        .execute::<ExecuteMsg>(
+            &contract_addr,
+            &ExecuteMsg::OwnerAction(OwnerMsg::AssignRole {
+                addr: user.clone(),
+                role: Role::Admin,
+            }),
+            &[],
+            &setup.user_accounts[0],
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().contains(
+        ContractError::Ownership(cw_ownable::OwnershipError::NotOwner)
+            .to_string()
+            .as_str()
+    ));
+
+    // The authorization must exist to require a role
+    let error = owner_action(OwnerMsg::SetMinRequiredRole {
+        label: "non_existent".to_string(),
+        role: Some(Role::Operator),
+    })
+    .unwrap_err();
+
+    assert!(error.to_string().contains(
+        ContractError::Authorization(AuthorizationErrorReason::DoesNotExist(
+            "non_existent".to_string()
+        ))
+        .to_string()
+        .as_str()
+    ));
+
+    owner_action(OwnerMsg::SetMinRequiredRole {
+        label: "permissionless".to_string(),
+        role: Some(Role::Operator),
+    })
+    .unwrap();
+
+    // Without a role the user can't execute the authorization anymore
+    assert_eq!(query_role(), None);
+    let error = send_msgs().unwrap_err();
+    assert!(error.to_string().contains(insufficient_role.as_str()));
+
+    // A lower role is not enough
+    owner_action(OwnerMsg::AssignRole {
+        addr: user.clone(),
+        role: Role::Observer,
+    })
+    .unwrap();
+    assert_eq!(query_role(), Some(Role::Observer));
+    let error = send_msgs().unwrap_err();
+    assert!(error.to_string().contains(insufficient_role.as_str()));
+
+    // The required role and higher roles can execute
+    owner_action(OwnerMsg::AssignRole {
+        addr: user.clone(),
+        role: Role::Operator,
+    })
+    .unwrap();
+    assert_eq!(query_role(), Some(Role::Operator));
+    send_msgs().unwrap();
+
+    owner_action(OwnerMsg::AssignRole {
+        addr: user.clone(),
+        role: Role::Admin,
+    })
+    .unwrap();
+    assert_eq!(query_role(), Some(Role::Admin));
+    send_msgs().unwrap();
+
+    // Once revoked, the user can't execute it again
+    owner_action(OwnerMsg::RevokeRole { addr: user.clone() }).unwrap();
+    assert_eq!(query_role(), None);
+    let error = send_msgs().unwrap_err();
+    assert!(error.to_string().contains(insufficient_role.as_str()));
+
+    // Without a required role the authorization is permissionless again
+    owner_action(OwnerMsg::SetMinRequiredRole {
+        label: "permissionless".to_string(),
+        role: None,
+    })
+    .unwrap();
+    assert_eq!(
+        wasm.query::<QueryMsg, Option<Role>>(
+            &contract_addr,
+            &QueryMsg::MinRequiredRole {
+                label: "permissionless".to_string(),
+            },
+        )
+        .unwrap(),
+        None
+    );
+    send_msgs().unwrap();
+}
+
+#[test]
+fn spending_limit() {
+    let setup = NeutronTestAppBuilder::new().build().unwrap();
+
+    let wasm = Wasm::new(&setup.app);
+
+    let (contract_addr, _) = store_and_instantiate_authorization_with_processor_contract(
+        &setup.app,
+        &setup.owner_accounts[0],
+        setup.owner_addr.to_string(),
+        vec![setup.subowner_addr.to_string()],
+    );
+
+    let authorizations = vec![AuthorizationBuilder::new()
+        .with_label("permissionless")
+        .with_max_concurrent_executions(10)
+        .with_subroutine(
+            AtomicSubroutineBuilder::new()
+                .with_function(AtomicFunctionBuilder::new().build())
+                .build(),
+        )
+        .build()];
+
+    wasm.execute::<ExecuteMsg>(
+        &contract_addr,
+        &ExecuteMsg::PermissionedAction(PermissionedMsg::CreateAuthorizations { authorizations }),
+        &[],
+        &setup.owner_accounts[0],
+    )
+    .unwrap();
+
+    let set_constraint = |constraint: Option<AuthorizationConstraint>| {
+        wasm.execute::<ExecuteMsg>(
+            &contract_addr,
+            &ExecuteMsg::PermissionedAction(PermissionedMsg::SetAuthorizationConstraint {
+                label: "permissionless".to_string(),
+                constraint,
+            }),
+            &[],
+            &setup.owner_accounts[1],
+        )
+    };
+    // Message that contains a bank send of the amount passed
+    let send_msgs = |denom: &str, amount: u128| {
+        let msg = JsonBuilder::new()
+            .main("method")
+            .add(
+                "msgs",
+                json!([{ "bank": { "send": {
+                    "to_address": setup.owner_addr.to_string(),
+                    "amount": [{ "denom": denom, "amount": amount.to_string() }],
+                }}}]),
+            )
+            .build();
+        wasm.execute::<ExecuteMsg>(
+            &contract_addr,
+            &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+                label: "permissionless".to_string(),
+                messages: vec![ProcessorMessage::CosmwasmExecuteMsg {
+                    msg: Binary::from(serde_json::to_vec(&msg).unwrap()),
+                }],
+                ttl: None,
+            }),
+            &[],
+            &setup.user_accounts[0],
+        )
+    };
+
+    // Window duration can't be 0
+    let error = set_constraint(Some(AuthorizationConstraint::SpendingLimit {
+        denom: "untrn".to_string(),
+        max_per_window: Uint128::new(100),
+        window_duration: Duration::Time(0),
+    }))
+    .unwrap_err();
+
+    assert!(error.to_string().contains(
+        ContractError::Authorization(AuthorizationErrorReason::InvalidSpendingWindow {})
+            .to_string()
+            .as_str()
+    ));
+
+    let constraint = AuthorizationConstraint::SpendingLimit {
+        denom: "untrn".to_string(),
+        max_per_window: Uint128::new(100),
+        window_duration: Duration::Time(10000),
+    };
+    set_constraint(Some(constraint.clone())).unwrap();
+
+    assert_eq!(
+        wasm.query::<QueryMsg, Option<AuthorizationConstraint>>(
+            &contract_addr,
+            &QueryMsg::AuthorizationConstraint {
+                label: "permissionless".to_string(),
+            },
+        )
+        .unwrap(),
+        Some(constraint)
+    );
+
+    // A message without a bank send can't be tracked, so it is rejected
+    let error = wasm
+        .execute::<ExecuteMsg>(
+            &contract_addr,
+            &ExecuteMsg::PermissionlessAction(PermissionlessMsg::SendMsgs {
+                label: "permissionless".to_string(),
+                messages: vec![ProcessorMessage::CosmwasmExecuteMsg {
+                    msg: Binary::from(
+                        serde_json::to_vec(&JsonBuilder::new().main("method").build()).unwrap(),
+                    ),
+                }],
+                ttl: None,
+            }),
+            &[],
+            &setup.user_accounts[0],
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains(
+        ContractError::Authorization(AuthorizationErrorReason::SpendingNotTracked {})
+            .to_string()
+            .as_str()
+    ));
+
+    send_msgs("untrn", 60).unwrap();
+
+    // Going over the limit in the same window fails
+    let error = send_msgs("untrn", 60).unwrap_err();
+    assert!(error.to_string().contains(
+        ContractError::Authorization(AuthorizationErrorReason::SpendingLimitExceeded {
+            denom: "untrn".to_string(),
+            spent: Uint128::new(60),
+            requested: Uint128::new(60),
+            max: Uint128::new(100),
+        })
+        .to_string()
+        .as_str()
+    ));
+
+    // Other denoms are not restricted and we can spend up to the limit
+    send_msgs("uatom", 1000).unwrap();
+    send_msgs("untrn", 40).unwrap();
+    assert!(send_msgs("untrn", 1).is_err());
+
+    // Once the window is over, the tracker is reset
+    setup.app.increase_time(10000);
+    send_msgs("untrn", 100).unwrap();
+    assert!(send_msgs("untrn", 1).is_err());
+
+    // Removing the constraint removes the limit
+    set_constraint(None).unwrap();
+    send_msgs("untrn", 1000).unwrap();
+}
@@ -6,7 +6,7 @@ use valence_astroport_utils::astroport_cw20_lp_token::{
     Asset, AssetInfo, ExecuteMsg, PairType, PoolQueryMsg, SimulationResponse,
 };
 use valence_astroport_utils::PoolType;
-use valence_library_utils::error::LibraryError;
+use valence_library_utils::error::{LibraryError, ERR_INVALID_POOL};
 
 pub fn create_provide_liquidity_msg(
     cfg: &Config,
@@ -56,9 +56,10 @@ pub fn create_single_sided_liquidity_msg(
                 create_stable_or_custom_liquidity_msg(cfg, asset_balance, other_asset)
             }
         },
-        _ => Err(LibraryError::ExecutionError(
-            "Invalid pool type for astroport_cw20".to_string(),
-        )),
+        _ => Err(LibraryError::ExecutionError {
+            code: ERR_INVALID_POOL,
+            msg: "Invalid pool type for astroport_cw20".to_string(),
+        }),
     }
 }
 
@@ -61,7 +61,9 @@ mod functions {
         decimal_checked_ops::DecimalCheckedOps, get_pool_asset_amounts, query_pool, PoolType,
     };
     use valence_library_utils::{
-        error::LibraryError, execute_on_behalf_of, liquidity_utils::DecimalRange,
+        error::{LibraryError, ERR_ARITHMETIC, ERR_INVALID_DENOM},
+        execute_on_behalf_of,
+        liquidity_utils::DecimalRange,
     };
 
     use crate::{
@@ -107,8 +109,12 @@ mod functions {
 
         // Get the pool asset ratios
         let pool_asset_ratios =
-            Decimal::checked_from_ratio(pool_asset1_balance, pool_asset2_balance)
-                .map_err(|e| LibraryError::ExecutionError(e.to_string()))?;
+            Decimal::checked_from_ratio(pool_asset1_balance, pool_asset2_balance).map_err(|e| {
+                LibraryError::ExecutionError {
+                    code: ERR_ARITHMETIC,
+                    msg: e.to_string(),
+                }
+            })?;
 
         // If we have an expected pool ratio range, we need to check if the pool is within that range
         if let Some(range) = expected_pool_ratio_range {
@@ -155,7 +161,10 @@ mod functions {
         // Let's get the maximum amount of assets that we can provide liquidity
         let required_asset1_amount = pool_asset_ratio
             .checked_mul_uint128(balance2.into())
-            .map_err(|error| LibraryError::ExecutionError(error.to_string()))?;
+            .map_err(|error| LibraryError::ExecutionError {
+                code: ERR_ARITHMETIC,
+                msg: error.to_string(),
+            })?;
 
         // We can provide all asset2 tokens along with the corresponding maximum of asset1 tokens
         if balance1 >= required_asset1_amount.u128() {
@@ -163,13 +172,19 @@ mod functions {
         } else {
             // We can't provide all asset2 tokens so we need to determine how many we can provide according to our available asset1
             let ratio = Decimal::checked_from_ratio(pool_asset2_balance, pool_asset1_balance)
-                .map_err(|e| LibraryError::ExecutionError(e.to_string()))?;
+                .map_err(|e| LibraryError::ExecutionError {
+                    code: ERR_ARITHMETIC,
+                    msg: e.to_string(),
+                })?;
 
             Ok((
                 balance1,
                 ratio
                     .checked_mul_uint128(balance1.into())
-                    .map_err(|error| LibraryError::ExecutionError(error.to_string()))?
+                    .map_err(|error| LibraryError::ExecutionError {
+                        code: ERR_ARITHMETIC,
+                        msg: error.to_string(),
+                    })?
                     .u128(),
             ))
         }
@@ -214,16 +229,21 @@ mod functions {
         } else if asset == cfg.lp_config.asset_data.asset2 {
             (balance_asset2.clone(), balance_asset1.clone())
         } else {
-            return Err(LibraryError::ExecutionError(
-                "Asset to provide liquidity for is not part of the pool".to_string(),
-            ));
+            return Err(LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: "Asset to provide liquidity for is not part of the pool".to_string(),
+            });
         };
 
         // Check pool ratio if range is provided
         if let Some(range) = expected_pool_ratio_range {
             let pool_asset_ratios =
-                Decimal::checked_from_ratio(pool_asset1_balance, pool_asset2_balance)
-                    .map_err(|e| LibraryError::ExecutionError(e.to_string()))?;
+                Decimal::checked_from_ratio(pool_asset1_balance, pool_asset2_balance).map_err(
+                    |e| LibraryError::ExecutionError {
+                        code: ERR_ARITHMETIC,
+                        msg: e.to_string(),
+                    },
+                )?;
             range.contains(pool_asset_ratios)?;
         }
 
@@ -268,6 +288,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetProcessor {} => {
             to_json_binary(&valence_library_base::get_processor(deps.storage)?)
         }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
         QueryMsg::GetLibraryConfig {} => {
             let config: Config = valence_library_base::load_config(deps.storage)?;
             to_json_binary(&config)
@@ -277,5 +300,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
             to_json_binary(&raw_config)
         }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
     }
 }
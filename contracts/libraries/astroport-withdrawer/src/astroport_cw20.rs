@@ -1,7 +1,7 @@
 use cosmwasm_std::{to_json_binary, Addr, Coin, CosmosMsg, DepsMut};
 use cw20::{BalanceResponse, Cw20ExecuteMsg};
 use valence_astroport_utils::astroport_cw20_lp_token::{Asset, PairInfo};
-use valence_library_utils::error::LibraryError;
+use valence_library_utils::error::{LibraryError, ERR_NOTHING_TO_PROCESS};
 
 use crate::msg::Config;
 
@@ -29,9 +29,10 @@ pub fn create_withdraw_liquidity_msgs(
         },
     )?;
     if balance_response.balance.is_zero() {
-        return Err(LibraryError::ExecutionError(
-            "Nothing to withdraw".to_string(),
-        ));
+        return Err(LibraryError::ExecutionError {
+            code: ERR_NOTHING_TO_PROCESS,
+            msg: "Nothing to withdraw".to_string(),
+        });
     }
 
     // Calculate how much we are going to get when we withdraw
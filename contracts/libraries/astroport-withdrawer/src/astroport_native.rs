@@ -1,7 +1,7 @@
 use crate::msg::Config;
 use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, DepsMut};
 use valence_astroport_utils::astroport_native_lp_token::{Asset, PairInfo};
-use valence_library_utils::error::LibraryError;
+use valence_library_utils::error::{LibraryError, ERR_NOTHING_TO_PROCESS};
 
 pub fn query_liquidity_token(deps: &DepsMut, cfg: &Config) -> Result<String, LibraryError> {
     let pair_info: PairInfo = deps.querier.query_wasm_smart(
@@ -22,9 +22,10 @@ pub fn create_withdraw_liquidity_msgs(
     // Query the balance of the account that is going to withdraw
     let balance = deps.querier.query_balance(&cfg.input_addr, token)?;
     if balance.amount.is_zero() {
-        return Err(LibraryError::ExecutionError(
-            "Nothing to withdraw".to_string(),
-        ));
+        return Err(LibraryError::ExecutionError {
+            code: ERR_NOTHING_TO_PROCESS,
+            msg: "Nothing to withdraw".to_string(),
+        });
     }
 
     // Calculate how much we are going to get when we withdraw
@@ -59,7 +59,9 @@ mod functions {
     use cosmwasm_std::{CosmosMsg, Decimal, DepsMut, Env, MessageInfo, Response};
     use valence_astroport_utils::{get_pool_asset_amounts, query_pool, PoolType};
     use valence_library_utils::{
-        error::LibraryError, execute_on_behalf_of, liquidity_utils::DecimalRange,
+        error::{LibraryError, ERR_ARITHMETIC},
+        execute_on_behalf_of,
+        liquidity_utils::DecimalRange,
     };
 
     use crate::{
@@ -104,8 +106,12 @@ mod functions {
 
             // Get the pool asset ratios
             let pool_asset_ratios =
-                Decimal::checked_from_ratio(pool_asset1_balance, pool_asset2_balance)
-                    .map_err(|e| LibraryError::ExecutionError(e.to_string()))?;
+                Decimal::checked_from_ratio(pool_asset1_balance, pool_asset2_balance).map_err(
+                    |e| LibraryError::ExecutionError {
+                        code: ERR_ARITHMETIC,
+                        msg: e.to_string(),
+                    },
+                )?;
 
             range.contains(pool_asset_ratios)?;
         }
@@ -141,6 +147,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetProcessor {} => {
             to_json_binary(&valence_library_base::get_processor(deps.storage)?)
         }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
         QueryMsg::GetLibraryConfig {} => {
             let config: Config = valence_library_base::load_config(deps.storage)?;
             to_json_binary(&config)
@@ -150,5 +159,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
             to_json_binary(&raw_config)
         }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
     }
 }
@@ -0,0 +1,206 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use valence_library_utils::{
+    error::LibraryError,
+    msg::{ExecuteMsg, InstantiateMsg},
+};
+
+use crate::msg::{Config, FunctionMsgs, LibraryConfig, LibraryConfigUpdate, QueryMsg};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg<LibraryConfig>,
+) -> Result<Response, LibraryError> {
+    valence_library_base::instantiate(deps, CONTRACT_NAME, CONTRACT_VERSION, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg<FunctionMsgs, LibraryConfigUpdate>,
+) -> Result<Response, LibraryError> {
+    valence_library_base::execute(
+        deps,
+        env,
+        info,
+        msg,
+        functions::process_function,
+        execute::update_config,
+    )
+}
+
+mod functions {
+    use cosmwasm_std::{ensure, to_json_string, DepsMut, Env, MessageInfo, Response};
+    use valence_library_utils::{
+        error::{
+            LibraryError, ERR_ARITHMETIC, ERR_EXECUTION_FAILED, ERR_INSUFFICIENT_BALANCE,
+            ERR_INVALID_INPUT,
+        },
+        execute_on_behalf_of,
+    };
+
+    use crate::msg::{
+        AxelarFee, AxelarGmpMessage, Config, FunctionMsgs, AXELAR_GMP_TYPE_MESSAGE,
+        AXELAR_GMP_TYPE_MESSAGE_WITH_TOKEN,
+    };
+
+    pub fn process_function(
+        deps: DepsMut,
+        env: Env,
+        _info: MessageInfo,
+        msg: FunctionMsgs,
+        cfg: Config,
+    ) -> Result<Response, LibraryError> {
+        match msg {
+            FunctionMsgs::SendCrossChain {
+                destination_chain,
+                destination_address,
+                payload,
+                token,
+                gas_fee,
+            } => {
+                ensure!(
+                    !destination_chain.is_empty() && !destination_address.is_empty(),
+                    LibraryError::ExecutionError {
+                        code: ERR_INVALID_INPUT,
+                        msg: "Destination chain and address must be set".to_string(),
+                    }
+                );
+                ensure!(
+                    !gas_fee.amount.is_zero(),
+                    LibraryError::ExecutionError {
+                        code: ERR_INVALID_INPUT,
+                        msg: "Gas fee must be positive".to_string(),
+                    }
+                );
+                // Axelar takes the gas fee out of the transferred tokens
+                ensure!(
+                    gas_fee.denom == token.denom,
+                    LibraryError::ExecutionError {
+                        code: ERR_INVALID_INPUT,
+                        msg: format!(
+                            "Gas fee must be paid in the denom of the token: {}",
+                            token.denom
+                        ),
+                    }
+                );
+
+                let amount = token.amount.checked_add(gas_fee.amount).map_err(|err| {
+                    LibraryError::ExecutionError {
+                        code: ERR_ARITHMETIC,
+                        msg: err.to_string(),
+                    }
+                })?;
+                let balance = deps
+                    .querier
+                    .query_balance(&cfg.input_addr, &token.denom)?
+                    .amount;
+                ensure!(
+                    balance >= amount,
+                    LibraryError::ExecutionError {
+                        code: ERR_INSUFFICIENT_BALANCE,
+                        msg: format!(
+                            "Insufficient balance for denom '{}' (required: {}, available: {}).",
+                            token.denom, amount, balance,
+                        ),
+                    }
+                );
+
+                let memo = AxelarGmpMessage {
+                    destination_chain: destination_chain.clone(),
+                    destination_address: destination_address.clone(),
+                    payload: payload.to_vec(),
+                    type_: if token.amount.is_zero() {
+                        AXELAR_GMP_TYPE_MESSAGE
+                    } else {
+                        AXELAR_GMP_TYPE_MESSAGE_WITH_TOKEN
+                    },
+                    fee: Some(AxelarFee {
+                        amount: gas_fee.amount.to_string(),
+                        recipient: cfg.gas_service.clone(),
+                    }),
+                };
+
+                // The message is carried by an ICS-20 transfer to the Axelar GMP account
+                let ibc_send_msg = valence_ibc_utils::generic::ibc_send_message(
+                    env.clone(),
+                    cfg.channel_id.clone(),
+                    cfg.axelar_gateway.clone(),
+                    token.denom.clone(),
+                    amount.u128(),
+                    to_json_string(&memo)?,
+                    cfg.ibc_transfer_timeout
+                        .as_ref()
+                        .map(|policy| policy.to_ibc_timeout(&env.block)),
+                    Default::default(),
+                )
+                .map_err(|err| LibraryError::ExecutionError {
+                    code: ERR_EXECUTION_FAILED,
+                    msg: err.to_string(),
+                })?;
+
+                let input_account_msgs = execute_on_behalf_of(vec![ibc_send_msg], &cfg.input_addr)?;
+
+                Ok(Response::new()
+                    .add_message(input_account_msgs)
+                    .add_attribute("method", "send_cross_chain")
+                    .add_attribute("destination_chain", destination_chain)
+                    .add_attribute("destination_address", destination_address)
+                    .add_attribute("token", token.to_string())
+                    .add_attribute("gas_fee", gas_fee.to_string()))
+            }
+        }
+    }
+}
+
+mod execute {
+    use cosmwasm_std::{DepsMut, Env, MessageInfo};
+    use valence_library_utils::error::LibraryError;
+
+    use crate::msg::LibraryConfigUpdate;
+
+    pub fn update_config(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        new_config: LibraryConfigUpdate,
+    ) -> Result<(), LibraryError> {
+        new_config.update_config(deps)
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Ownership {} => {
+            to_json_binary(&valence_library_base::get_ownership(deps.storage)?)
+        }
+        QueryMsg::GetProcessor {} => {
+            to_json_binary(&valence_library_base::get_processor(deps.storage)?)
+        }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
+        QueryMsg::GetLibraryConfig {} => {
+            let config: Config = valence_library_base::load_config(deps.storage)?;
+            to_json_binary(&config)
+        }
+        QueryMsg::GetRawLibraryConfig {} => {
+            let raw_config: LibraryConfig = valence_library_base::load_raw_config(deps.storage)?;
+            to_json_binary(&raw_config)
+        }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
+    }
+}
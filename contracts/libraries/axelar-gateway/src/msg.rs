@@ -0,0 +1,197 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary, Coin, Deps, DepsMut};
+use cw_ownable::cw_ownable_query;
+use valence_generic_ibc_transfer_library::msg::TimeoutPolicy;
+use valence_library_utils::{
+    error::LibraryError, msg::LibraryConfigValidation, validation::HasAddressFields,
+    LibraryAccountType,
+};
+use valence_macros::{valence_library_query, ValenceAddressFields, ValenceLibraryInterface};
+
+/// Axelar GMP message type for a contract call without tokens.
+pub const AXELAR_GMP_TYPE_MESSAGE: i64 = 1;
+/// Axelar GMP message type for a contract call with the tokens of the transfer (`CallContractWithToken`).
+pub const AXELAR_GMP_TYPE_MESSAGE_WITH_TOKEN: i64 = 2;
+
+#[cw_serde]
+/// Enum representing the different function messages that can be sent.
+pub enum FunctionMsgs {
+    /// Message to call a contract on another chain through Axelar with the payload.
+    /// `token` is bridged along with the call (`CallContractWithToken`), if its amount is zero only the message is sent.
+    /// `gas_fee` is paid to the Axelar gas service out of the transfer, so it must be in the same denom as `token`.
+    SendCrossChain {
+        destination_chain: String,
+        destination_address: String,
+        payload: Binary,
+        token: Coin,
+        gas_fee: Coin,
+    },
+}
+
+#[valence_library_query]
+#[cw_ownable_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+/// Enum representing the different query messages that can be sent.
+/// There is no gas estimate query, the gas service lives on the Axelar chain and can't be queried from here.
+pub enum QueryMsg {}
+
+#[cw_serde]
+#[derive(ValenceLibraryInterface, ValenceAddressFields)]
+/// Struct representing the library configuration.
+pub struct LibraryConfig {
+    /// The account that sends the tokens and pays the gas fee.
+    pub input_addr: LibraryAccountType,
+    /// The IBC channel from this chain to the Axelar chain.
+    pub channel_id: String,
+    /// The Axelar GMP account on the Axelar chain, it receives the ICS-20 transfer that carries the message.
+    pub axelar_gateway: String,
+    /// The Axelar gas service account on the Axelar chain, it receives the gas fee.
+    pub gas_service: String,
+    /// Timeout of the ICS-20 transfer, defaults to 600 seconds.
+    pub ibc_transfer_timeout: Option<TimeoutPolicy>,
+}
+
+impl LibraryConfig {
+    pub fn new(
+        input_addr: impl Into<LibraryAccountType>,
+        channel_id: String,
+        axelar_gateway: String,
+        gas_service: String,
+        ibc_transfer_timeout: Option<TimeoutPolicy>,
+    ) -> Self {
+        LibraryConfig {
+            input_addr: input_addr.into(),
+            channel_id,
+            axelar_gateway,
+            gas_service,
+            ibc_transfer_timeout,
+        }
+    }
+
+    fn do_validate(&self, api: &dyn cosmwasm_std::Api) -> Result<Config, LibraryError> {
+        // The Axelar accounts live on the Axelar chain, so we can't validate them with our api
+        for (field, value) in [
+            ("channel_id", &self.channel_id),
+            ("axelar_gateway", &self.axelar_gateway),
+            ("gas_service", &self.gas_service),
+        ] {
+            if value.is_empty() {
+                return Err(LibraryError::ConfigurationError(format!(
+                    "{} can't be empty",
+                    field
+                )));
+            }
+        }
+
+        if let Some(timeout) = &self.ibc_transfer_timeout {
+            if timeout.is_zero() {
+                return Err(LibraryError::ConfigurationError(
+                    "Invalid IBC transfer timeout: 0".to_string(),
+                ));
+            }
+        }
+
+        Ok(Config {
+            input_addr: self.input_addr.to_addr(api)?,
+            channel_id: self.channel_id.clone(),
+            axelar_gateway: self.axelar_gateway.clone(),
+            gas_service: self.gas_service.clone(),
+            ibc_transfer_timeout: self.ibc_transfer_timeout.clone(),
+        })
+    }
+}
+
+impl LibraryConfigValidation<Config> for LibraryConfig {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn pre_validate(&self, api: &dyn cosmwasm_std::Api) -> Result<(), LibraryError> {
+        // Report all the invalid addresses at once
+        self.validate_addresses(api).map_err(|errors| {
+            LibraryError::ConfigurationError(
+                errors
+                    .iter()
+                    .map(|(field, err)| format!("{}: {}", field, err))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+        })?;
+        self.do_validate(api)?;
+        Ok(())
+    }
+
+    fn validate(&self, deps: Deps) -> Result<Config, LibraryError> {
+        self.do_validate(deps.api)
+    }
+}
+
+impl LibraryConfigUpdate {
+    pub fn update_config(self, deps: DepsMut) -> Result<(), LibraryError> {
+        let config: Config = valence_library_base::load_config(deps.storage)?;
+
+        // Validate the updated config as a whole, so empty fields are rejected as well
+        let mut new_config = LibraryConfig::new(
+            config.input_addr,
+            config.channel_id,
+            config.axelar_gateway,
+            config.gas_service,
+            config.ibc_transfer_timeout,
+        );
+
+        if let Some(input_addr) = self.input_addr {
+            new_config.input_addr = input_addr;
+        }
+
+        if let Some(channel_id) = self.channel_id {
+            new_config.channel_id = channel_id;
+        }
+
+        if let Some(axelar_gateway) = self.axelar_gateway {
+            new_config.axelar_gateway = axelar_gateway;
+        }
+
+        if let Some(gas_service) = self.gas_service {
+            new_config.gas_service = gas_service;
+        }
+
+        match self.ibc_transfer_timeout {
+            OptionUpdate::Set(ibc_transfer_timeout) => {
+                new_config.ibc_transfer_timeout = ibc_transfer_timeout
+            }
+            OptionUpdate::Clear => new_config.ibc_transfer_timeout = None,
+            OptionUpdate::None => {}
+        }
+
+        valence_library_base::save_config(deps.storage, &new_config.do_validate(deps.api)?)?;
+
+        Ok(())
+    }
+}
+
+#[cw_serde]
+/// Struct representing the validated library configuration.
+pub struct Config {
+    pub input_addr: Addr,
+    pub channel_id: String,
+    pub axelar_gateway: String,
+    pub gas_service: String,
+    pub ibc_transfer_timeout: Option<TimeoutPolicy>,
+}
+
+/// The memo of the ICS-20 transfer to the Axelar GMP account, it tells Axelar which contract to call on the destination chain.
+#[cw_serde]
+pub struct AxelarGmpMessage {
+    pub destination_chain: String,
+    pub destination_address: String,
+    /// Axelar expects the payload as an array of bytes
+    pub payload: Vec<u8>,
+    #[serde(rename = "type")]
+    pub type_: i64,
+    pub fee: Option<AxelarFee>,
+}
+
+/// The part of the transferred amount that pays for the execution on the destination chain.
+#[cw_serde]
+pub struct AxelarFee {
+    pub amount: String,
+    pub recipient: String,
+}
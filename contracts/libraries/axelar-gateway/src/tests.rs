@@ -0,0 +1,249 @@
+use crate::msg::{
+    AxelarFee, AxelarGmpMessage, Config, FunctionMsgs, LibraryConfig, QueryMsg,
+    AXELAR_GMP_TYPE_MESSAGE_WITH_TOKEN,
+};
+use cosmwasm_std::{coin, to_json_string, Addr, Binary, Coin, Empty};
+use cw_multi_test::{error::AnyResult, App, AppResponse, ContractWrapper};
+use cw_ownable::Ownership;
+use getset::{Getters, Setters};
+use valence_generic_ibc_transfer_library::msg::TimeoutPolicy;
+use valence_library_utils::{
+    msg::{ExecuteMsg, InstantiateMsg, LibraryConfigValidation},
+    testing::{LibraryTestSuite, LibraryTestSuiteBase},
+};
+
+const NTRN: &str = "untrn";
+const CHANNEL_ID: &str = "channel-0";
+const AXELAR_GMP_ACCOUNT: &str = "axelar_gmp_account";
+const AXELAR_GAS_SERVICE: &str = "axelar_gas_service";
+const GAS_FEE: u128 = 1_000;
+
+#[derive(Getters, Setters)]
+struct AxelarGatewayTestSuite {
+    #[getset(get)]
+    inner: LibraryTestSuiteBase,
+    #[getset(get)]
+    axelar_gateway_code_id: u64,
+    #[getset(get)]
+    input_addr: Addr,
+}
+
+impl Default for AxelarGatewayTestSuite {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AxelarGatewayTestSuite {
+    pub fn new() -> Self {
+        let mut inner = LibraryTestSuiteBase::new();
+
+        let input_addr = inner.get_contract_addr(inner.account_code_id(), "input_account");
+
+        let axelar_gateway_code = ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        );
+        let axelar_gateway_code_id = inner.app_mut().store_code(Box::new(axelar_gateway_code));
+
+        Self {
+            inner,
+            axelar_gateway_code_id,
+            input_addr,
+        }
+    }
+
+    pub fn axelar_gateway_init(&mut self, input_balance: u128) -> Addr {
+        let init_msg = InstantiateMsg {
+            owner: self.owner().to_string(),
+            processor: self.processor().to_string(),
+            config: LibraryConfig::new(
+                self.input_addr().clone(),
+                CHANNEL_ID.to_string(),
+                AXELAR_GMP_ACCOUNT.to_string(),
+                AXELAR_GAS_SERVICE.to_string(),
+                None,
+            ),
+        };
+        let addr = self.contract_init(
+            self.axelar_gateway_code_id,
+            "axelar_gateway_library",
+            &init_msg,
+            &[],
+        );
+
+        let input_addr = self.input_addr().clone();
+        let account_addr = self.account_init("input_account", vec![addr.to_string()]);
+        assert_eq!(account_addr, input_addr);
+        if input_balance > 0 {
+            self.init_balance(&input_addr, vec![coin(input_balance, NTRN)]);
+        }
+
+        addr
+    }
+
+    fn execute_send_cross_chain(
+        &mut self,
+        addr: Addr,
+        token: Coin,
+        gas_fee: Coin,
+    ) -> AnyResult<AppResponse> {
+        self.contract_execute(
+            addr,
+            &ExecuteMsg::<_, LibraryConfig>::ProcessFunction(FunctionMsgs::SendCrossChain {
+                destination_chain: "Ethereum".to_string(),
+                destination_address: "0x0000000000000000000000000000000000000001".to_string(),
+                payload: Binary::from(b"payload".to_vec()),
+                token,
+                gas_fee,
+            }),
+        )
+    }
+}
+
+impl LibraryTestSuite<Empty, Empty> for AxelarGatewayTestSuite {
+    fn app(&self) -> &App {
+        self.inner.app()
+    }
+
+    fn app_mut(&mut self) -> &mut App {
+        self.inner.app_mut()
+    }
+
+    fn owner(&self) -> &Addr {
+        self.inner.owner()
+    }
+
+    fn processor(&self) -> &Addr {
+        self.inner.processor()
+    }
+
+    fn account_code_id(&self) -> u64 {
+        self.inner.account_code_id()
+    }
+
+    fn cw20_code_id(&self) -> u64 {
+        self.inner.cw20_code_id()
+    }
+}
+
+#[test]
+fn instantiate_with_valid_config() {
+    let mut suite = AxelarGatewayTestSuite::default();
+    let lib = suite.axelar_gateway_init(0);
+
+    let owner_res: Ownership<Addr> = suite.query_wasm(&lib, &QueryMsg::Ownership {});
+    assert_eq!(owner_res.owner, Some(suite.owner().clone()));
+
+    let lib_cfg: Config = suite.query_wasm(&lib, &QueryMsg::GetLibraryConfig {});
+    assert_eq!(
+        lib_cfg,
+        Config {
+            input_addr: suite.input_addr().clone(),
+            channel_id: CHANNEL_ID.to_string(),
+            axelar_gateway: AXELAR_GMP_ACCOUNT.to_string(),
+            gas_service: AXELAR_GAS_SERVICE.to_string(),
+            ibc_transfer_timeout: None,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Gas fee must be positive")]
+fn send_cross_chain_fails_without_gas_fee() {
+    let mut suite = AxelarGatewayTestSuite::default();
+    let lib = suite.axelar_gateway_init(10_000);
+
+    suite
+        .execute_send_cross_chain(lib, coin(5_000, NTRN), coin(0, NTRN))
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Gas fee must be paid in the denom of the token: untrn")]
+fn send_cross_chain_fails_with_gas_fee_in_other_denom() {
+    let mut suite = AxelarGatewayTestSuite::default();
+    let lib = suite.axelar_gateway_init(10_000);
+
+    suite
+        .execute_send_cross_chain(lib, coin(5_000, NTRN), coin(GAS_FEE, "uatom"))
+        .unwrap();
+}
+
+#[test]
+#[should_panic(
+    expected = "Insufficient balance for denom 'untrn' (required: 10001, available: 10000)."
+)]
+fn send_cross_chain_fails_with_insufficient_balance() {
+    let mut suite = AxelarGatewayTestSuite::default();
+    let lib = suite.axelar_gateway_init(10_000);
+
+    // The gas fee is transferred on top of the token
+    suite
+        .execute_send_cross_chain(lib, coin(10_000 - GAS_FEE + 1, NTRN), coin(GAS_FEE, NTRN))
+        .unwrap();
+}
+
+#[test]
+fn gmp_message_matches_axelar_memo_format() {
+    let memo = AxelarGmpMessage {
+        destination_chain: "Ethereum".to_string(),
+        destination_address: "0x0000000000000000000000000000000000000001".to_string(),
+        payload: b"hi".to_vec(),
+        type_: AXELAR_GMP_TYPE_MESSAGE_WITH_TOKEN,
+        fee: Some(AxelarFee {
+            amount: GAS_FEE.to_string(),
+            recipient: AXELAR_GAS_SERVICE.to_string(),
+        }),
+    };
+
+    assert_eq!(
+        to_json_string(&memo).unwrap(),
+        r#"{"destination_chain":"Ethereum","destination_address":"0x0000000000000000000000000000000000000001","payload":[104,105],"type":2,"fee":{"amount":"1000","recipient":"axelar_gas_service"}}"#
+    );
+}
+
+#[test]
+fn pre_validate_reports_invalid_config() {
+    let suite = AxelarGatewayTestSuite::default();
+
+    let cfg = LibraryConfig::new(
+        "invalid_input_addr",
+        CHANNEL_ID.to_string(),
+        AXELAR_GMP_ACCOUNT.to_string(),
+        AXELAR_GAS_SERVICE.to_string(),
+        None,
+    );
+    let err = cfg.pre_validate(suite.app().api()).unwrap_err().to_string();
+    assert!(err.contains("input_addr"));
+
+    let cfg = LibraryConfig::new(
+        suite.input_addr().clone(),
+        CHANNEL_ID.to_string(),
+        AXELAR_GMP_ACCOUNT.to_string(),
+        String::new(),
+        None,
+    );
+    let err = cfg.pre_validate(suite.app().api()).unwrap_err().to_string();
+    assert!(err.contains("gas_service can't be empty"));
+
+    let cfg = LibraryConfig::new(
+        suite.input_addr().clone(),
+        CHANNEL_ID.to_string(),
+        AXELAR_GMP_ACCOUNT.to_string(),
+        AXELAR_GAS_SERVICE.to_string(),
+        Some(TimeoutPolicy::RelativeSeconds(0)),
+    );
+    let err = cfg.pre_validate(suite.app().api()).unwrap_err().to_string();
+    assert!(err.contains("Invalid IBC transfer timeout: 0"));
+
+    let cfg = LibraryConfig::new(
+        suite.input_addr().clone(),
+        CHANNEL_ID.to_string(),
+        AXELAR_GMP_ACCOUNT.to_string(),
+        AXELAR_GAS_SERVICE.to_string(),
+        Some(TimeoutPolicy::RelativeSeconds(600)),
+    );
+    cfg.pre_validate(suite.app().api()).unwrap();
+}
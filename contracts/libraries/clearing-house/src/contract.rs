@@ -0,0 +1,170 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use valence_library_utils::{
+    error::LibraryError,
+    msg::{ExecuteMsg, InstantiateMsg},
+};
+
+use crate::{
+    msg::{Config, FunctionMsgs, LibraryConfig, LibraryConfigUpdate, QueryMsg},
+    state::OBLIGATIONS,
+};
+
+// version info for migration info
+const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
+const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg<LibraryConfig>,
+) -> Result<Response, LibraryError> {
+    valence_library_base::instantiate(deps, CONTRACT_NAME, CONTRACT_VERSION, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg<FunctionMsgs, LibraryConfigUpdate>,
+) -> Result<Response, LibraryError> {
+    valence_library_base::execute(
+        deps,
+        env,
+        info,
+        msg,
+        functions::process_function,
+        execute::update_config,
+    )
+}
+
+mod functions {
+    use cosmwasm_std::{BankMsg, CosmosMsg, DepsMut, Env, MessageInfo, Response};
+    use valence_library_utils::{
+        error::{LibraryError, ERR_INVALID_INPUT, ERR_NOTHING_TO_PROCESS},
+        execute_on_behalf_of,
+    };
+
+    use crate::{
+        msg::{Config, FunctionMsgs, WithdrawalObligation},
+        state::OBLIGATIONS,
+    };
+
+    pub fn process_function(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        msg: FunctionMsgs,
+        cfg: Config,
+    ) -> Result<Response, LibraryError> {
+        match msg {
+            FunctionMsgs::RegisterObligation(obligation) => {
+                ensure_valid_obligation(&deps, &obligation)?;
+
+                // New obligations are always settled after the ones already registered
+                OBLIGATIONS.push_back(deps.storage, &obligation)?;
+
+                Ok(Response::new()
+                    .add_attribute("method", "register_obligation")
+                    .add_attribute("obligation_id", obligation.id.to_string())
+                    .add_attribute("recipient", obligation.recipient))
+            }
+            FunctionMsgs::SettleNextObligation {} => {
+                let obligation = OBLIGATIONS.pop_front(deps.storage)?.ok_or_else(|| {
+                    LibraryError::ExecutionError {
+                        code: ERR_NOTHING_TO_PROCESS,
+                        msg: "No obligations to settle.".to_string(),
+                    }
+                })?;
+
+                // Pay out the obligation from the settlement account
+                let transfer_msg: CosmosMsg = BankMsg::Send {
+                    to_address: obligation.recipient.clone(),
+                    amount: obligation.payout_coins,
+                }
+                .into();
+                let settlement_acc_msg =
+                    execute_on_behalf_of(vec![transfer_msg], cfg.settlement_acc_addr())?;
+
+                Ok(Response::new()
+                    .add_attribute("method", "settle_next_obligation")
+                    .add_attribute("obligation_id", obligation.id.to_string())
+                    .add_attribute("recipient", obligation.recipient)
+                    .add_message(settlement_acc_msg))
+            }
+        }
+    }
+
+    // Ensure the obligation can be paid out once it reaches the front of the queue
+    fn ensure_valid_obligation(
+        deps: &DepsMut<'_>,
+        obligation: &WithdrawalObligation,
+    ) -> Result<(), LibraryError> {
+        deps.api.addr_validate(&obligation.recipient)?;
+
+        if obligation.payout_coins.is_empty() {
+            return Err(LibraryError::ExecutionError {
+                code: ERR_INVALID_INPUT,
+                msg: "Obligation must have at least one payout coin.".to_string(),
+            });
+        }
+
+        if obligation.payout_coins.iter().any(|c| c.amount.is_zero()) {
+            return Err(LibraryError::ExecutionError {
+                code: ERR_INVALID_INPUT,
+                msg: "Obligation payout coins must be non-zero.".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+mod execute {
+    use cosmwasm_std::{DepsMut, Env, MessageInfo};
+    use valence_library_utils::error::LibraryError;
+
+    use crate::msg::LibraryConfigUpdate;
+
+    pub fn update_config(
+        deps: DepsMut,
+        _env: Env,
+        _info: MessageInfo,
+        new_config: LibraryConfigUpdate,
+    ) -> Result<(), LibraryError> {
+        new_config.update_config(deps)
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Ownership {} => {
+            to_json_binary(&valence_library_base::get_ownership(deps.storage)?)
+        }
+        QueryMsg::GetProcessor {} => {
+            to_json_binary(&valence_library_base::get_processor(deps.storage)?)
+        }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
+        QueryMsg::GetLibraryConfig {} => {
+            let config: Config = valence_library_base::load_config(deps.storage)?;
+            to_json_binary(&config)
+        }
+        QueryMsg::GetRawLibraryConfig {} => {
+            let raw_config: LibraryConfig =
+                valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
+            to_json_binary(&raw_config)
+        }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
+        QueryMsg::GetQueueLength {} => to_json_binary(&OBLIGATIONS.len(deps.storage)?),
+        QueryMsg::GetNextObligation {} => to_json_binary(&OBLIGATIONS.front(deps.storage)?),
+    }
+}
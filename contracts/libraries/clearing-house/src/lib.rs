@@ -0,0 +1,6 @@
+pub mod contract;
+pub mod msg;
+pub mod state;
+
+#[cfg(test)]
+mod tests;
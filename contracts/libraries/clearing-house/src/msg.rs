@@ -0,0 +1,107 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Coin, Deps, DepsMut, Uint64};
+use cw_ownable::cw_ownable_query;
+use getset::{Getters, Setters};
+use valence_library_utils::{
+    error::LibraryError, msg::LibraryConfigValidation, LibraryAccountType,
+};
+use valence_macros::{valence_library_query, ValenceLibraryInterface};
+
+#[cw_serde]
+/// Enum representing the different function messages that can be sent.
+pub enum FunctionMsgs {
+    /// Message to register a new withdrawal obligation at the back of the queue.
+    RegisterObligation(WithdrawalObligation),
+    /// Message to settle the obligation at the front of the queue.
+    SettleNextObligation {},
+}
+
+#[cw_serde]
+/// Struct representing a withdrawal that must be paid out from the settlement account.
+pub struct WithdrawalObligation {
+    /// Identifier of the obligation, assigned by whoever registers it.
+    pub id: Uint64,
+    /// The address that will receive the payout.
+    pub recipient: String,
+    /// The coins to be paid out to the recipient.
+    pub payout_coins: Vec<Coin>,
+}
+
+#[valence_library_query]
+#[cw_ownable_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+/// Enum representing the different query messages that can be sent.
+pub enum QueryMsg {
+    /// Returns the amount of obligations waiting to be settled.
+    #[returns(u32)]
+    GetQueueLength {},
+    /// Returns the obligation that will be settled next, if any.
+    #[returns(Option<WithdrawalObligation>)]
+    GetNextObligation {},
+}
+
+#[cw_serde]
+#[derive(ValenceLibraryInterface)]
+/// Struct representing the library configuration.
+pub struct LibraryConfig {
+    /// The account from which the obligations are paid out.
+    pub settlement_acc_addr: LibraryAccountType,
+}
+
+impl LibraryConfig {
+    pub fn new(settlement_acc_addr: impl Into<LibraryAccountType>) -> Self {
+        LibraryConfig {
+            settlement_acc_addr: settlement_acc_addr.into(),
+        }
+    }
+
+    fn do_validate(&self, api: &dyn cosmwasm_std::Api) -> Result<Addr, LibraryError> {
+        let settlement_acc_addr = self.settlement_acc_addr.to_addr(api)?;
+        Ok(settlement_acc_addr)
+    }
+}
+
+impl LibraryConfigValidation<Config> for LibraryConfig {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn pre_validate(&self, api: &dyn cosmwasm_std::Api) -> Result<(), LibraryError> {
+        self.do_validate(api)?;
+        Ok(())
+    }
+
+    fn validate(&self, deps: Deps) -> Result<Config, LibraryError> {
+        let settlement_acc_addr = self.do_validate(deps.api)?;
+        Ok(Config::new(settlement_acc_addr))
+    }
+}
+
+impl LibraryConfigUpdate {
+    pub fn update_config(self, deps: DepsMut) -> Result<(), LibraryError> {
+        let mut config: Config = valence_library_base::load_config(deps.storage)?;
+
+        if let Some(settlement_acc_addr) = self.settlement_acc_addr {
+            config.settlement_acc_addr = settlement_acc_addr.to_addr(deps.api)?;
+        }
+
+        valence_library_base::save_config(deps.storage, &config)?;
+
+        Ok(())
+    }
+}
+
+#[cw_serde]
+#[derive(Getters, Setters)]
+/// Struct representing the validated library configuration.
+pub struct Config {
+    /// The account from which the obligations are paid out.
+    #[getset(get = "pub", set)]
+    settlement_acc_addr: Addr,
+}
+
+impl Config {
+    pub fn new(settlement_acc_addr: Addr) -> Self {
+        Config {
+            settlement_acc_addr,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use cw_storage_plus::Deque;
+
+use crate::msg::WithdrawalObligation;
+
+/// Obligations waiting to be settled, in the order they were registered
+pub const OBLIGATIONS: Deque<WithdrawalObligation> = Deque::new("obligations");
@@ -0,0 +1,373 @@
+use crate::msg::{Config, FunctionMsgs, LibraryConfig, QueryMsg, WithdrawalObligation};
+use cosmwasm_std::{coin, Addr, Coin, Empty, Uint64};
+use cw_multi_test::{error::AnyResult, App, AppResponse, ContractWrapper, Executor};
+use cw_ownable::Ownership;
+use getset::{Getters, Setters};
+use valence_library_utils::{
+    msg::{ExecuteMsg, InstantiateMsg},
+    testing::{LibraryTestSuite, LibraryTestSuiteBase},
+};
+
+const NTRN: &str = "untrn";
+
+#[derive(Getters, Setters)]
+struct ClearingHouseTestSuite {
+    #[getset(get)]
+    inner: LibraryTestSuiteBase,
+    #[getset(get)]
+    clearing_house_code_id: u64,
+    #[getset(get)]
+    settlement_addr: Addr,
+    #[getset(get)]
+    settlement_balances: Option<Vec<(u128, String)>>,
+}
+
+impl Default for ClearingHouseTestSuite {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+#[allow(dead_code)]
+impl ClearingHouseTestSuite {
+    pub fn new(settlement_balances: Option<Vec<(u128, String)>>) -> Self {
+        let mut inner = LibraryTestSuiteBase::new();
+
+        let settlement_addr =
+            inner.get_contract_addr(inner.account_code_id(), "settlement_account");
+
+        // Clearing house contract
+        let clearing_house_code = ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        );
+
+        let clearing_house_code_id = inner.app_mut().store_code(Box::new(clearing_house_code));
+
+        Self {
+            inner,
+            clearing_house_code_id,
+            settlement_addr,
+            settlement_balances,
+        }
+    }
+
+    pub fn clearing_house_init(&mut self, cfg: &LibraryConfig) -> Addr {
+        let init_msg = InstantiateMsg {
+            owner: self.owner().to_string(),
+            processor: self.processor().to_string(),
+            config: cfg.clone(),
+        };
+        let addr = self.contract_init(
+            self.clearing_house_code_id,
+            "clearing_house",
+            &init_msg,
+            &[],
+        );
+
+        let settlement_addr = self.settlement_addr().clone();
+        if self.app_mut().contract_data(&settlement_addr).is_err() {
+            let account_addr = self.account_init("settlement_account", vec![addr.to_string()]);
+            assert_eq!(account_addr, settlement_addr);
+
+            if let Some(balances) = self.settlement_balances.as_ref().cloned() {
+                let amounts = balances
+                    .iter()
+                    .map(|(amount, denom)| coin(*amount, denom.to_string()))
+                    .collect::<Vec<Coin>>();
+                self.init_balance(&settlement_addr, amounts);
+            }
+        }
+
+        addr
+    }
+
+    fn clearing_house_config(&self) -> LibraryConfig {
+        LibraryConfig::new(self.settlement_addr())
+    }
+
+    fn obligation(&self, id: u64, recipient: &Addr, amount: u128) -> WithdrawalObligation {
+        WithdrawalObligation {
+            id: Uint64::new(id),
+            recipient: recipient.to_string(),
+            payout_coins: vec![coin(amount, NTRN)],
+        }
+    }
+
+    fn execute_register_obligation(
+        &mut self,
+        addr: Addr,
+        obligation: WithdrawalObligation,
+    ) -> AnyResult<AppResponse> {
+        self.contract_execute(
+            addr,
+            &ExecuteMsg::<_, LibraryConfig>::ProcessFunction(FunctionMsgs::RegisterObligation(
+                obligation,
+            )),
+        )
+    }
+
+    fn execute_settle_next_obligation(&mut self, addr: Addr) -> AnyResult<AppResponse> {
+        self.contract_execute(
+            addr,
+            &ExecuteMsg::<_, LibraryConfig>::ProcessFunction(FunctionMsgs::SettleNextObligation {}),
+        )
+    }
+
+    fn query_queue_length(&self, addr: &Addr) -> u32 {
+        self.query_wasm(addr, &QueryMsg::GetQueueLength {})
+    }
+
+    fn query_next_obligation(&self, addr: &Addr) -> Option<WithdrawalObligation> {
+        self.query_wasm(addr, &QueryMsg::GetNextObligation {})
+    }
+}
+
+impl LibraryTestSuite<Empty, Empty> for ClearingHouseTestSuite {
+    fn app(&self) -> &App {
+        self.inner.app()
+    }
+
+    fn app_mut(&mut self) -> &mut App {
+        self.inner.app_mut()
+    }
+
+    fn owner(&self) -> &Addr {
+        self.inner.owner()
+    }
+
+    fn processor(&self) -> &Addr {
+        self.inner.processor()
+    }
+
+    fn account_code_id(&self) -> u64 {
+        self.inner.account_code_id()
+    }
+
+    fn cw20_code_id(&self) -> u64 {
+        self.inner.cw20_code_id()
+    }
+}
+
+#[test]
+fn instantiate_with_valid_config() {
+    let mut suite = ClearingHouseTestSuite::default();
+
+    let cfg = suite.clearing_house_config();
+
+    // Instantiate Clearing house contract
+    let lib = suite.clearing_house_init(&cfg);
+
+    // Verify owner
+    let owner_res: Ownership<Addr> = suite.query_wasm(&lib, &QueryMsg::Ownership {});
+    assert_eq!(owner_res.owner, Some(suite.owner().clone()));
+
+    // Verify processor
+    let processor_addr: Addr = suite.query_wasm(&lib, &QueryMsg::GetProcessor {});
+    assert_eq!(processor_addr, suite.processor().clone());
+
+    // Verify library config
+    let lib_cfg: Config = suite.query_wasm(&lib, &QueryMsg::GetLibraryConfig {});
+    assert_eq!(lib_cfg, Config::new(suite.settlement_addr().clone()));
+
+    // Queue starts empty
+    assert_eq!(suite.query_queue_length(&lib), 0);
+    assert_eq!(suite.query_next_obligation(&lib), None);
+}
+
+#[test]
+fn obligations_are_settled_in_registration_order() {
+    let mut suite = ClearingHouseTestSuite::new(Some(vec![(1_000_000_u128, NTRN.into())]));
+
+    let cfg = suite.clearing_house_config();
+    let lib = suite.clearing_house_init(&cfg);
+
+    let recipient1 = suite.api().addr_make("recipient_1");
+    let recipient2 = suite.api().addr_make("recipient_2");
+    let recipient3 = suite.api().addr_make("recipient_3");
+
+    // Register three obligations
+    let obligation1 = suite.obligation(1, &recipient1, 100_000);
+    let obligation2 = suite.obligation(2, &recipient2, 200_000);
+    let obligation3 = suite.obligation(3, &recipient3, 300_000);
+    for obligation in [&obligation1, &obligation2, &obligation3] {
+        suite
+            .execute_register_obligation(lib.clone(), obligation.clone())
+            .unwrap();
+    }
+
+    assert_eq!(suite.query_queue_length(&lib), 3);
+    assert_eq!(suite.query_next_obligation(&lib), Some(obligation1));
+
+    // Settle the first obligation: only the first recipient gets paid
+    suite.execute_settle_next_obligation(lib.clone()).unwrap();
+
+    suite.assert_balance(&recipient1, 100_000, NTRN);
+    suite.assert_balance(&recipient2, 0, NTRN);
+    suite.assert_balance(&recipient3, 0, NTRN);
+    assert_eq!(suite.query_queue_length(&lib), 2);
+    assert_eq!(suite.query_next_obligation(&lib), Some(obligation2));
+
+    // Settle the remaining obligations
+    suite.execute_settle_next_obligation(lib.clone()).unwrap();
+    suite.execute_settle_next_obligation(lib.clone()).unwrap();
+
+    suite.assert_balance(&recipient2, 200_000, NTRN);
+    suite.assert_balance(&recipient3, 300_000, NTRN);
+    suite.assert_balance(suite.settlement_addr(), 400_000, NTRN);
+    assert_eq!(suite.query_queue_length(&lib), 0);
+    assert_eq!(suite.query_next_obligation(&lib), None);
+}
+
+#[test]
+fn settle_fails_when_queue_is_empty() {
+    let mut suite = ClearingHouseTestSuite::default();
+
+    let cfg = suite.clearing_house_config();
+    let lib = suite.clearing_house_init(&cfg);
+
+    let res = suite.execute_settle_next_obligation(lib);
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Execution error 1006: No obligations to settle."
+    );
+}
+
+#[test]
+fn settle_fails_and_keeps_obligation_when_settlement_account_is_underfunded() {
+    let mut suite = ClearingHouseTestSuite::new(Some(vec![(100_u128, NTRN.into())]));
+
+    let cfg = suite.clearing_house_config();
+    let lib = suite.clearing_house_init(&cfg);
+
+    let recipient = suite.api().addr_make("recipient");
+    let obligation = suite.obligation(1, &recipient, 1_000);
+    suite
+        .execute_register_obligation(lib.clone(), obligation.clone())
+        .unwrap();
+
+    // Not enough funds, the whole transaction is reverted
+    let res = suite.execute_settle_next_obligation(lib.clone());
+    assert!(res.is_err());
+
+    // Obligation is still at the front of the queue
+    assert_eq!(suite.query_queue_length(&lib), 1);
+    assert_eq!(suite.query_next_obligation(&lib), Some(obligation));
+    suite.assert_balance(&recipient, 0, NTRN);
+}
+
+#[test]
+fn register_fails_for_invalid_recipient() {
+    let mut suite = ClearingHouseTestSuite::default();
+
+    let cfg = suite.clearing_house_config();
+    let lib = suite.clearing_house_init(&cfg);
+
+    let obligation = WithdrawalObligation {
+        id: Uint64::one(),
+        recipient: "invalid_address".to_string(),
+        payout_coins: vec![coin(1_000, NTRN)],
+    };
+
+    let res = suite.execute_register_obligation(lib.clone(), obligation);
+    assert!(res.is_err());
+    assert_eq!(suite.query_queue_length(&lib), 0);
+}
+
+#[test]
+fn register_fails_for_empty_or_zero_payout() {
+    let mut suite = ClearingHouseTestSuite::default();
+
+    let cfg = suite.clearing_house_config();
+    let lib = suite.clearing_house_init(&cfg);
+
+    let recipient = suite.api().addr_make("recipient");
+
+    let mut obligation = suite.obligation(1, &recipient, 1_000);
+    obligation.payout_coins = vec![];
+    let res = suite.execute_register_obligation(lib.clone(), obligation);
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Execution error 1008: Obligation must have at least one payout coin."
+    );
+
+    let obligation = suite.obligation(2, &recipient, 0);
+    let res = suite.execute_register_obligation(lib.clone(), obligation);
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Execution error 1008: Obligation payout coins must be non-zero."
+    );
+
+    assert_eq!(suite.query_queue_length(&lib), 0);
+}
+
+#[test]
+fn only_processor_can_register_and_settle() {
+    let mut suite = ClearingHouseTestSuite::new(Some(vec![(1_000_u128, NTRN.into())]));
+
+    let cfg = suite.clearing_house_config();
+    let lib = suite.clearing_house_init(&cfg);
+
+    let recipient = suite.api().addr_make("recipient");
+    let obligation = suite.obligation(1, &recipient, 1_000);
+    let unauthorized = suite.api().addr_make("unauthorized");
+
+    // Registering from an address other than the processor fails
+    let res = suite.app_mut().execute_contract(
+        unauthorized.clone(),
+        lib.clone(),
+        &ExecuteMsg::<_, LibraryConfig>::ProcessFunction(FunctionMsgs::RegisterObligation(
+            obligation.clone(),
+        )),
+        &[],
+    );
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Unauthorized: This address is not allowed to execute this action"
+    );
+    assert_eq!(suite.query_queue_length(&lib), 0);
+
+    suite
+        .execute_register_obligation(lib.clone(), obligation)
+        .unwrap();
+
+    // Settling from an address other than the processor fails
+    let res = suite.app_mut().execute_contract(
+        unauthorized,
+        lib.clone(),
+        &ExecuteMsg::<_, LibraryConfig>::ProcessFunction(FunctionMsgs::SettleNextObligation {}),
+        &[],
+    );
+    assert_eq!(
+        res.unwrap_err().root_cause().to_string(),
+        "Unauthorized: This address is not allowed to execute this action"
+    );
+    assert_eq!(suite.query_queue_length(&lib), 1);
+    suite.assert_balance(&recipient, 0, NTRN);
+}
+
+#[test]
+fn update_config_changes_settlement_account() {
+    let mut suite = ClearingHouseTestSuite::default();
+
+    let cfg = suite.clearing_house_config();
+    let lib = suite.clearing_house_init(&cfg);
+
+    let new_settlement_addr = suite.api().addr_make("new_settlement_account");
+    let new_config = LibraryConfig::new(&new_settlement_addr);
+
+    let owner = suite.owner().clone();
+    suite
+        .app_mut()
+        .execute_contract(
+            owner,
+            lib.clone(),
+            &ExecuteMsg::<FunctionMsgs, LibraryConfig>::UpdateConfig { new_config },
+            &[],
+        )
+        .unwrap();
+
+    let lib_cfg: Config = suite.query_wasm(&lib, &QueryMsg::GetLibraryConfig {});
+    assert_eq!(lib_cfg, Config::new(new_settlement_addr));
+}
@@ -43,7 +43,10 @@ mod functions {
     use cosmwasm_std::{
         Addr, CosmosMsg, DepsMut, Env, MessageInfo, QuerierWrapper, Response, StdResult,
     };
-    use valence_library_utils::{error::LibraryError, execute_on_behalf_of};
+    use valence_library_utils::{
+        error::{LibraryError, ERR_CONSTRAINT_NOT_MET},
+        execute_on_behalf_of,
+    };
 
     use crate::{
         msg::{Config, FunctionMsgs},
@@ -141,9 +144,10 @@ mod functions {
                     .after(&last_successful_forward)
                     .is_expired(&env.block)
                 {
-                    return Err(LibraryError::ExecutionError(
-                        "Forwarding constraint not met.".to_string(),
-                    ));
+                    return Err(LibraryError::ExecutionError {
+                        code: ERR_CONSTRAINT_NOT_MET,
+                        msg: "Forwarding constraint not met.".to_string(),
+                    });
                 }
             }
         };
@@ -176,6 +180,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetProcessor {} => {
             to_json_binary(&valence_library_base::get_processor(deps.storage)?)
         }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
         QueryMsg::GetLibraryConfig {} => {
             let config: Config = valence_library_base::load_config(deps.storage)?;
             to_json_binary(&config)
@@ -185,5 +192,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
             to_json_binary(&raw_config)
         }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
     }
 }
@@ -416,7 +416,7 @@ fn forward_with_height_interval_constraint() {
 
     assert_eq!(
         res.unwrap_err().root_cause().to_string(),
-        "Execution error: Forwarding constraint not met."
+        "Execution error 1007: Forwarding constraint not met."
     );
 
     // BLOCK N+2
@@ -41,7 +41,10 @@ pub fn execute(
 
 mod functions {
     use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
-    use valence_library_utils::{error::LibraryError, execute_on_behalf_of};
+    use valence_library_utils::{
+        error::{LibraryError, ERR_EXECUTION_FAILED, ERR_INSUFFICIENT_BALANCE},
+        execute_on_behalf_of,
+    };
 
     use crate::msg::{Config, FunctionMsgs, IbcTransferAmount};
 
@@ -60,15 +63,24 @@ mod functions {
                     IbcTransferAmount::FullAmount => balance,
                     IbcTransferAmount::FixedAmount(amount) => {
                         if balance < *amount {
-                            return Err(LibraryError::ExecutionError(format!(
-                                "Insufficient balance for denom '{}' in config (required: {}, available: {}).",
-                                cfg.denom(), amount, balance,
-                            )));
+                            return Err(LibraryError::ExecutionError {
+                                code: ERR_INSUFFICIENT_BALANCE,
+                                msg: format!(
+                                    "Insufficient balance for denom '{}' in config (required: {}, available: {}).",
+                                    cfg.denom(), amount, balance,
+                                ),
+                            });
                         }
                         *amount
                     }
                 };
 
+                let timeout = cfg
+                    .remote_chain_info()
+                    .ibc_transfer_timeout
+                    .as_ref()
+                    .map(|policy| policy.to_ibc_timeout(&env.block));
+
                 let ibc_send_msg = valence_ibc_utils::generic::ibc_send_message(
                     env,
                     cfg.remote_chain_info().channel_id.clone(),
@@ -76,10 +88,13 @@ mod functions {
                     cfg.denom().to_string(),
                     amount.u128(),
                     cfg.memo().clone(),
-                    cfg.remote_chain_info().ibc_transfer_timeout.map(Into::into),
+                    timeout,
                     cfg.denom_to_pfm_map().clone(),
                 )
-                .map_err(|err| LibraryError::ExecutionError(err.to_string()))?;
+                .map_err(|err| LibraryError::ExecutionError {
+                    code: ERR_EXECUTION_FAILED,
+                    msg: err.to_string(),
+                })?;
 
                 let input_account_msgs =
                     execute_on_behalf_of(vec![ibc_send_msg], cfg.input_addr())?;
@@ -117,6 +132,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetProcessor {} => {
             to_json_binary(&valence_library_base::get_processor(deps.storage)?)
         }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
         QueryMsg::GetLibraryConfig {} => {
             let config: Config = valence_library_base::load_config(deps.storage)?;
             to_json_binary(&config)
@@ -126,5 +144,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
             to_json_binary(&raw_config)
         }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
     }
 }
@@ -1,7 +1,14 @@
 use std::collections::BTreeMap;
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, CustomQuery, Deps, DepsMut, Uint128, Uint64};
+use cosmwasm_schema::{
+    schemars::JsonSchema,
+    serde::{Deserialize, Deserializer},
+};
+use cosmwasm_std::{
+    Addr, BlockInfo, CustomQuery, Deps, DepsMut, IbcTimeout, IbcTimeoutBlock, Timestamp, Uint128,
+    Uint64,
+};
 use cw_ownable::cw_ownable_query;
 use getset::{Getters, Setters};
 use valence_ibc_utils::types::PacketForwardMiddlewareConfig;
@@ -46,11 +53,13 @@ pub enum IbcTransferAmount {
 #[cw_serde]
 pub struct RemoteChainInfo {
     pub channel_id: String,
-    pub ibc_transfer_timeout: Option<Uint64>,
+    #[serde(default, deserialize_with = "deserialize_timeout_policy")]
+    #[schemars(with = "Option<TimeoutPolicyOrSeconds>")]
+    pub ibc_transfer_timeout: Option<TimeoutPolicy>,
 }
 
 impl RemoteChainInfo {
-    pub fn new(channel_id: String, ibc_transfer_timeout: Option<Uint64>) -> Self {
+    pub fn new(channel_id: String, ibc_transfer_timeout: Option<TimeoutPolicy>) -> Self {
         Self {
             channel_id,
             ibc_transfer_timeout,
@@ -58,6 +67,88 @@ impl RemoteChainInfo {
     }
 }
 
+#[cw_serde]
+/// How the timeout of an IBC transfer is computed when the transfer is executed.
+pub enum TimeoutPolicy {
+    /// Absolute timestamp (in seconds) after which the transfer times out.
+    #[deprecated(note = "Use RelativeSeconds or RelativeBlocks instead")]
+    Fixed(u64),
+    /// Amount of blocks, added to the current block height at execution time.
+    RelativeBlocks(u64),
+    /// Amount of seconds, added to the current block time at execution time.
+    RelativeSeconds(u64),
+}
+
+impl TimeoutPolicy {
+    pub fn is_zero(&self) -> bool {
+        match self {
+            #[allow(deprecated)]
+            TimeoutPolicy::Fixed(value) => *value == 0,
+            TimeoutPolicy::RelativeBlocks(value) | TimeoutPolicy::RelativeSeconds(value) => {
+                *value == 0
+            }
+        }
+    }
+
+    /// Resolves the policy into the timeout of a transfer executed in the given block.
+    pub fn to_ibc_timeout(&self, block: &BlockInfo) -> IbcTimeout {
+        match self {
+            #[allow(deprecated)]
+            TimeoutPolicy::Fixed(timestamp) => {
+                IbcTimeout::with_timestamp(Timestamp::from_seconds(*timestamp))
+            }
+            TimeoutPolicy::RelativeBlocks(blocks) => IbcTimeout::with_block(IbcTimeoutBlock {
+                revision: revision_number(&block.chain_id),
+                height: block.height + blocks,
+            }),
+            TimeoutPolicy::RelativeSeconds(seconds) => {
+                IbcTimeout::with_timestamp(block.time.plus_seconds(*seconds))
+            }
+        }
+    }
+}
+
+impl From<u64> for TimeoutPolicy {
+    fn from(seconds: u64) -> Self {
+        TimeoutPolicy::RelativeSeconds(seconds)
+    }
+}
+
+impl From<Uint64> for TimeoutPolicy {
+    fn from(seconds: Uint64) -> Self {
+        TimeoutPolicy::RelativeSeconds(seconds.u64())
+    }
+}
+
+/// Revision number of a chain following the `{chain_name}-{revision_number}` chain ID format, 0 otherwise.
+fn revision_number(chain_id: &str) -> u64 {
+    chain_id
+        .rsplit_once('-')
+        .and_then(|(_, revision)| revision.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Timeouts stored or sent before timeout policies were added are plain amounts of seconds.
+#[derive(Deserialize, JsonSchema)]
+#[serde(crate = "cosmwasm_schema::serde", untagged)]
+#[schemars(crate = "cosmwasm_schema::schemars")]
+enum TimeoutPolicyOrSeconds {
+    Policy(TimeoutPolicy),
+    Seconds(Uint64),
+}
+
+fn deserialize_timeout_policy<'de, D>(deserializer: D) -> Result<Option<TimeoutPolicy>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(
+        Option::<TimeoutPolicyOrSeconds>::deserialize(deserializer)?.map(|timeout| match timeout {
+            TimeoutPolicyOrSeconds::Policy(policy) => policy,
+            TimeoutPolicyOrSeconds::Seconds(seconds) => seconds.into(),
+        }),
+    )
+}
+
 impl LibraryConfig {
     pub fn new(
         input_addr: LibraryAccountType,
@@ -119,7 +210,7 @@ impl LibraryConfig {
             ));
         }
 
-        if let Some(timeout) = self.remote_chain_info.ibc_transfer_timeout {
+        if let Some(timeout) = &self.remote_chain_info.ibc_transfer_timeout {
             if timeout.is_zero() {
                 return Err(LibraryError::ConfigurationError(
                     "Invalid IBC transfer config: remote_chain_info's ibc_transfer_timeout cannot be zero.".to_string(),
@@ -202,7 +293,7 @@ impl LibraryConfigUpdate {
                 ));
             }
 
-            if let Some(timeout) = remote_chain_info.ibc_transfer_timeout {
+            if let Some(timeout) = &remote_chain_info.ibc_transfer_timeout {
                 if timeout.is_zero() {
                     return Err(LibraryError::ConfigurationError(
                         "Invalid IBC transfer config: remote_chain_info's ibc_transfer_timeout cannot be zero.".to_string(),
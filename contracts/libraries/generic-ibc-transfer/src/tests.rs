@@ -1,7 +1,11 @@
 use crate::msg::{
     Config, FunctionMsgs, IbcTransferAmount, LibraryConfig, QueryMsg, RemoteChainInfo,
+    TimeoutPolicy,
+};
+use cosmwasm_std::{
+    coin, from_json, testing::mock_env, Addr, Empty, IbcTimeout, IbcTimeoutBlock, Timestamp,
+    Uint128, Uint64,
 };
-use cosmwasm_std::{coin, Addr, Empty, Uint128, Uint64};
 use cw_multi_test::{error::AnyResult, App, AppResponse, ContractWrapper, Executor};
 use cw_ownable::Ownership;
 use getset::{Getters, Setters};
@@ -248,7 +252,7 @@ fn instantiate_fails_for_invalid_ibc_transfer_timeout() {
         NTRN.to_string(),
         IbcTransferAmount::FixedAmount(Uint128::one()),
         "".to_string(),
-        RemoteChainInfo::new("channel-1".to_string(), Some(Uint64::zero())),
+        RemoteChainInfo::new("channel-1".to_string(), Some(Uint64::zero().into())),
     );
 
     // Instantiate IBC transfer contract
@@ -321,7 +325,7 @@ fn update_config_validates_ibc_timeout() {
     let lib = suite.ibc_transfer_init(&cfg);
 
     // Update config and set ibc timeout to zero
-    cfg.remote_chain_info.ibc_transfer_timeout = Some(Uint64::zero());
+    cfg.remote_chain_info.ibc_transfer_timeout = Some(Uint64::zero().into());
 
     // Execute update config action
     suite.update_config(lib.clone(), cfg).unwrap();
@@ -369,7 +373,7 @@ fn update_config_with_valid_config() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance for denom 'untrn' in config (required: 1000000000000, available: 0)."
+    expected = "Execution error 1001: Insufficient balance for denom 'untrn' in config (required: 1000000000000, available: 0)."
 )]
 fn ibc_transfer_fails_for_insufficient_balance() {
     let mut suite = IbcTransferTestSuite::default();
@@ -387,3 +391,88 @@ fn ibc_transfer_fails_for_insufficient_balance() {
     // Execute IBC transfer
     suite.execute_ibc_transfer(lib).unwrap();
 }
+
+#[test]
+fn timeout_policy_relative_seconds_is_added_to_block_time() {
+    let env = mock_env();
+
+    let timeout = TimeoutPolicy::RelativeSeconds(600).to_ibc_timeout(&env.block);
+
+    assert_eq!(
+        timeout,
+        IbcTimeout::with_timestamp(env.block.time.plus_seconds(600))
+    );
+}
+
+#[test]
+fn timeout_policy_relative_blocks_is_added_to_block_height() {
+    let mut env = mock_env();
+    env.block.chain_id = "neutron-1".to_string();
+
+    let timeout = TimeoutPolicy::RelativeBlocks(100).to_ibc_timeout(&env.block);
+
+    assert_eq!(
+        timeout,
+        IbcTimeout::with_block(IbcTimeoutBlock {
+            revision: 1,
+            height: env.block.height + 100,
+        })
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "Invalid IBC transfer config: remote_chain_info's ibc_transfer_timeout cannot be zero."
+)]
+fn instantiate_fails_for_zero_relative_blocks_timeout() {
+    let mut suite = IbcTransferTestSuite::default();
+
+    let cfg = suite.ibc_transfer_config(
+        NTRN.to_string(),
+        IbcTransferAmount::FullAmount,
+        "".to_string(),
+        RemoteChainInfo::new(
+            "channel-1".to_string(),
+            Some(TimeoutPolicy::RelativeBlocks(0)),
+        ),
+    );
+
+    // Instantiate contract
+    suite.ibc_transfer_init(&cfg);
+}
+
+#[test]
+#[allow(deprecated)]
+fn timeout_policy_fixed_is_used_as_is() {
+    let env = mock_env();
+    let timestamp = env.block.time.seconds() + 1_000;
+
+    let timeout = TimeoutPolicy::Fixed(timestamp).to_ibc_timeout(&env.block);
+
+    assert_eq!(
+        timeout,
+        IbcTimeout::with_timestamp(Timestamp::from_seconds(timestamp))
+    );
+}
+
+#[test]
+fn remote_chain_info_accepts_legacy_timeout_in_seconds() {
+    // Timeout as it was stored before timeout policies were added
+    let remote_chain_info: RemoteChainInfo =
+        from_json(r#"{"channel_id":"channel-1","ibc_transfer_timeout":"600"}"#).unwrap();
+    assert_eq!(
+        remote_chain_info.ibc_transfer_timeout,
+        Some(TimeoutPolicy::RelativeSeconds(600))
+    );
+
+    let remote_chain_info: RemoteChainInfo =
+        from_json(r#"{"channel_id":"channel-1","ibc_transfer_timeout":{"relative_seconds":600}}"#)
+            .unwrap();
+    assert_eq!(
+        remote_chain_info.ibc_transfer_timeout,
+        Some(TimeoutPolicy::RelativeSeconds(600))
+    );
+
+    let remote_chain_info: RemoteChainInfo = from_json(r#"{"channel_id":"channel-1"}"#).unwrap();
+    assert_eq!(remote_chain_info.ibc_transfer_timeout, None);
+}
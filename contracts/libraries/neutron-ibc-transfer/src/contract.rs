@@ -7,7 +7,10 @@ use valence_library_utils::{
     msg::{ExecuteMsg, InstantiateMsg},
 };
 
-use crate::msg::{Config, FunctionMsgs, LibraryConfig, LibraryConfigUpdate, QueryMsg};
+use crate::msg::{
+    Config, FunctionMsgs, LibraryConfig, LibraryConfigUpdate, QueryMsg, RemoteChainInfo,
+    TimeoutPolicy,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -20,6 +23,7 @@ pub fn instantiate(
     _info: MessageInfo,
     msg: InstantiateMsg<LibraryConfig>,
 ) -> Result<Response, LibraryError> {
+    ensure_timestamp_timeout(&msg.config.remote_chain_info)?;
     valence_library_base::instantiate(deps.into_empty(), CONTRACT_NAME, CONTRACT_VERSION, msg)
 }
 
@@ -40,10 +44,24 @@ pub fn execute(
     )
 }
 
+/// Neutron IBC transfers can only time out at a timestamp, so block based timeouts are rejected
+fn ensure_timestamp_timeout(remote_chain_info: &RemoteChainInfo) -> Result<(), LibraryError> {
+    if let Some(TimeoutPolicy::RelativeBlocks(_)) = remote_chain_info.ibc_transfer_timeout {
+        return Err(LibraryError::ConfigurationError(
+            "Invalid IBC transfer config: block based timeouts are not supported for Neutron IBC transfers.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 mod functions {
     use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, StdError};
     use neutron_sdk::bindings::query::NeutronQuery;
-    use valence_library_utils::{error::LibraryError, execute_on_behalf_of};
+    use valence_ibc_utils::error::IbcUtilsError;
+    use valence_library_utils::{
+        error::{LibraryError, ERR_EXECUTION_FAILED, ERR_INSUFFICIENT_BALANCE},
+        execute_on_behalf_of,
+    };
 
     use crate::msg::{Config, FunctionMsgs, IbcTransferAmount};
 
@@ -62,15 +80,24 @@ mod functions {
                     IbcTransferAmount::FullAmount => balance,
                     IbcTransferAmount::FixedAmount(amount) => {
                         if balance < *amount {
-                            return Err(LibraryError::ExecutionError(format!(
-                                "Insufficient balance for denom '{}' in config (required: {}, available: {}).",
-                                cfg.denom(), amount, balance,
-                            )));
+                            return Err(LibraryError::ExecutionError {
+                                code: ERR_INSUFFICIENT_BALANCE,
+                                msg: format!(
+                                    "Insufficient balance for denom '{}' in config (required: {}, available: {}).",
+                                    cfg.denom(), amount, balance,
+                                ),
+                            });
                         }
                         *amount
                     }
                 };
 
+                let timeout = cfg
+                    .remote_chain_info()
+                    .ibc_transfer_timeout
+                    .as_ref()
+                    .map(|policy| policy.to_ibc_timeout(&env.block));
+
                 // IBC Transfer funds from input account to output account on the remote chain
                 let ibc_send_msg = valence_ibc_utils::neutron::ibc_send_message(
                     deps,
@@ -81,15 +108,24 @@ mod functions {
                     cfg.denom(),
                     amount.u128(),
                     cfg.memo().clone(),
-                    cfg.remote_chain_info().ibc_transfer_timeout.map(Into::into),
+                    timeout,
                     cfg.denom_to_pfm_map().clone(),
                 )
-                .map_err(|err| {
-                    if let StdError::GenericErr { msg, .. } = err {
-                        LibraryError::ExecutionError(msg)
-                    } else {
-                        LibraryError::ExecutionError(err.to_string())
+                .map_err(|err| match err {
+                    IbcUtilsError::InsufficientFeeBalance { .. } => LibraryError::ExecutionError {
+                        code: ERR_INSUFFICIENT_BALANCE,
+                        msg: err.to_string(),
+                    },
+                    IbcUtilsError::Std(StdError::GenericErr { msg, .. }) => {
+                        LibraryError::ExecutionError {
+                            code: ERR_EXECUTION_FAILED,
+                            msg,
+                        }
                     }
+                    _ => LibraryError::ExecutionError {
+                        code: ERR_EXECUTION_FAILED,
+                        msg: err.to_string(),
+                    },
                 })?;
 
                 let input_account_msgs =
@@ -116,6 +152,9 @@ mod execute {
         _info: MessageInfo,
         new_config: LibraryConfigUpdate,
     ) -> Result<(), LibraryError> {
+        if let Some(remote_chain_info) = &new_config.remote_chain_info {
+            super::ensure_timestamp_timeout(remote_chain_info)?;
+        }
         new_config.update_config(deps)
     }
 }
@@ -129,6 +168,9 @@ pub fn query(deps: Deps<NeutronQuery>, _env: Env, msg: QueryMsg) -> StdResult<Bi
         QueryMsg::GetProcessor {} => {
             to_json_binary(&valence_library_base::get_processor(deps.storage)?)
         }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
         QueryMsg::GetLibraryConfig {} => {
             let config: Config = valence_library_base::load_config(deps.storage)?;
             to_json_binary(&config)
@@ -138,5 +180,8 @@ pub fn query(deps: Deps<NeutronQuery>, _env: Env, msg: QueryMsg) -> StdResult<Bi
                 valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
             to_json_binary(&raw_config)
         }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
     }
 }
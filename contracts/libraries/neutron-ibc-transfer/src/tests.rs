@@ -1,5 +1,6 @@
 use crate::msg::{
     Config, FunctionMsgs, IbcTransferAmount, LibraryConfig, QueryMsg, RemoteChainInfo,
+    TimeoutPolicy,
 };
 use cosmwasm_std::{
     coin, to_json_binary, Addr, Api, BlockInfo, CustomMsg, CustomQuery, Empty, Storage, Uint128,
@@ -341,7 +342,28 @@ fn instantiate_fails_for_invalid_ibc_transfer_timeout() {
         NTRN.to_string(),
         IbcTransferAmount::FixedAmount(Uint128::one()),
         "".to_string(),
-        RemoteChainInfo::new("channel-1".to_string(), Some(Uint64::zero())),
+        RemoteChainInfo::new("channel-1".to_string(), Some(Uint64::zero().into())),
+    );
+
+    // Instantiate IBC transfer contract
+    suite.ibc_transfer_init(&cfg);
+}
+
+#[test]
+#[should_panic(
+    expected = "Invalid IBC transfer config: block based timeouts are not supported for Neutron IBC transfers."
+)]
+fn instantiate_fails_for_relative_blocks_timeout() {
+    let mut suite = IbcTransferTestSuite::default();
+
+    let cfg = suite.ibc_transfer_config(
+        NTRN.to_string(),
+        IbcTransferAmount::FixedAmount(Uint128::one()),
+        "".to_string(),
+        RemoteChainInfo::new(
+            "channel-1".to_string(),
+            Some(TimeoutPolicy::RelativeBlocks(100)),
+        ),
     );
 
     // Instantiate IBC transfer contract
@@ -372,6 +394,33 @@ fn update_config_validates_config() {
     suite.update_config(lib.clone(), cfg).unwrap();
 }
 
+#[test]
+#[should_panic(
+    expected = "Invalid IBC transfer config: block based timeouts are not supported for Neutron IBC transfers."
+)]
+fn update_config_fails_for_relative_blocks_timeout() {
+    let mut suite = IbcTransferTestSuite::default();
+
+    let mut cfg = suite.ibc_transfer_config(
+        NTRN.to_string(),
+        IbcTransferAmount::FullAmount,
+        "".to_string(),
+        RemoteChainInfo::new("channel-1".to_string(), Some(600u64.into())),
+    );
+
+    // Instantiate IBC transfer contract
+    let lib = suite.ibc_transfer_init(&cfg);
+
+    // Update config and switch to a block based timeout
+    cfg.remote_chain_info = RemoteChainInfo::new(
+        "channel-1".to_string(),
+        Some(TimeoutPolicy::RelativeBlocks(100)),
+    );
+
+    // Execute update config action
+    suite.update_config(lib.clone(), cfg).unwrap();
+}
+
 #[test]
 fn update_config_with_valid_config() {
     let mut suite = IbcTransferTestSuite::default();
@@ -414,7 +463,7 @@ fn update_config_with_valid_config() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance for denom 'untrn' in config (required: 1000000000000, available: 0)."
+    expected = "Execution error 1001: Insufficient balance for denom 'untrn' in config (required: 1000000000000, available: 0)."
 )]
 fn ibc_transfer_fails_for_insufficient_balance() {
     let mut suite = IbcTransferTestSuite::default();
@@ -435,7 +484,7 @@ fn ibc_transfer_fails_for_insufficient_balance() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance to cover for IBC fees 'untrn' in sender account (required: 20000, available: 0)."
+    expected = "Execution error 1001: Insufficient balance to cover for IBC fees 'untrn' in sender account (required: 20000, available: 0)."
 )]
 fn ibc_transfer_fails_for_insufficient_fee_balance() {
     let mut suite = IbcTransferTestSuite::default();
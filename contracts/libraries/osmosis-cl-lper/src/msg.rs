@@ -3,7 +3,9 @@ use cosmwasm_std::{ensure, Addr, Deps, DepsMut, Uint128, Uint64};
 use cw_ownable::cw_ownable_query;
 
 use valence_library_utils::{
-    error::LibraryError, msg::LibraryConfigValidation, LibraryAccountType,
+    error::{LibraryError, ERR_INVALID_DENOM},
+    msg::LibraryConfigValidation,
+    LibraryAccountType,
 };
 use valence_macros::ValenceLibraryInterface;
 use valence_osmosis_utils::utils::cl_utils::{query_cl_pool, TickRange};
@@ -33,6 +35,8 @@ pub enum FunctionMsgs {
 pub enum QueryMsg {
     #[returns(Addr)]
     GetProcessor {},
+    #[returns(Vec<Addr>)]
+    GetProcessors {},
     #[returns(Config)]
     GetLibraryConfig {},
 }
@@ -104,7 +108,10 @@ impl LibraryConfigValidation<Config> for LibraryConfig {
 
         ensure!(
             asset_1_found && asset_2_found,
-            LibraryError::ExecutionError("Pool does not contain expected assets".to_string())
+            LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: "Pool does not contain expected assets".to_string()
+            }
         );
 
         Ok(Config {
@@ -4,7 +4,7 @@ use cosmwasm_std::{ensure, Addr, Deps, DepsMut, Uint128, Uint64};
 use cw_ownable::cw_ownable_query;
 use osmosis_std::types::osmosis::poolmanager::v1beta1::PoolmanagerQuerier;
 use valence_library_utils::{
-    error::LibraryError,
+    error::{LibraryError, ERR_INVALID_DENOM},
     liquidity_utils::{AssetData, DecimalRange},
     msg::LibraryConfigValidation,
     LibraryAccountType,
@@ -105,7 +105,10 @@ impl LibraryConfigValidation<Config> for LibraryConfig {
 
         ensure!(
             asset_1_found && asset_2_found,
-            LibraryError::ExecutionError("Pool does not contain expected assets".to_string())
+            LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: "Pool does not contain expected assets".to_string()
+            }
         );
 
         Ok(Config {
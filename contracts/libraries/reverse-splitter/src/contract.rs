@@ -49,7 +49,7 @@ mod functions {
 
     use valence_library_utils::{
         denoms::CheckedDenom,
-        error::LibraryError,
+        error::{LibraryError, ERR_INSUFFICIENT_BALANCE, ERR_INVALID_DENOM},
         execute_on_behalf_of,
         msg::{DynamicRatioQueryMsg, DynamicRatioResponse},
     };
@@ -138,10 +138,13 @@ mod functions {
                 SplitAmount::FixedAmount(amount) => {
                     // Stop if the specified amount is greater than the input account's balance
                     if *amount > balance {
-                        return Err(LibraryError::ExecutionError(format!(
-                            "Insufficient balance on account {} for denom '{:?}' in split config (required: {}, available: {}).",
-                            split.account(), denom, amount, balance,
-                        )));
+                        return Err(LibraryError::ExecutionError {
+                            code: ERR_INSUFFICIENT_BALANCE,
+                            msg: format!(
+                                "Insufficient balance on account {} for denom '{:?}' in split config (required: {}, available: {}).",
+                                split.account(), denom, amount, balance,
+                            ),
+                        });
                     }
                     denom_amount_count += 1;
                 }
@@ -252,10 +255,10 @@ mod functions {
         res.denom_ratios
             .get(&denom_name)
             .copied()
-            .ok_or(LibraryError::ExecutionError(format!(
-                "Dynamic ratio not found for denom '{}'.",
-                denom
-            )))
+            .ok_or(LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: format!("Dynamic ratio not found for denom '{}'.", denom),
+            })
     }
 
     fn account_key(account: &Addr, denom: &CheckedDenom) -> String {
@@ -292,6 +295,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetProcessor {} => {
             to_json_binary(&valence_library_base::get_processor(deps.storage)?)
         }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
         QueryMsg::GetLibraryConfig {} => {
             let config: Config = valence_library_base::load_config(deps.storage)?;
             to_json_binary(&config)
@@ -301,5 +307,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
             to_json_binary(&raw_config)
         }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
     }
 }
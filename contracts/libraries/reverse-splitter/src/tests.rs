@@ -656,7 +656,7 @@ fn split_native_two_token_partial_amounts_two_inputs() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance on account cosmwasm18ygxc482fgklywq5e2fsnmnkqflwaq5u07f9yw824ajfu2x6920sv28wwu for denom 'Native(\"untrn\")' in split config (required: 10000000000000, available: 1000000000000)."
+    expected = "Execution error 1001: Insufficient balance on account cosmwasm18ygxc482fgklywq5e2fsnmnkqflwaq5u07f9yw824ajfu2x6920sv28wwu for denom 'Native(\"untrn\")' in split config (required: 10000000000000, available: 1000000000000)."
 )]
 fn split_native_single_token_amount_fails_for_insufficient_balance() {
     let mut suite = ReverseSplitterTestSuite::default();
@@ -682,7 +682,7 @@ fn split_native_single_token_amount_fails_for_insufficient_balance() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance on account cosmwasm18ygxc482fgklywq5e2fsnmnkqflwaq5u07f9yw824ajfu2x6920sv28wwu for denom 'Cw20(Addr(\"cosmwasm1wug8sewp6cedgkmrmvhl3lf3tulagm9hnvy8p0rppz9yjw0g4wtqlrtkzd\"))' in split config (required: 10000000000000, available: 1000000000000)."
+    expected = "Execution error 1001: Insufficient balance on account cosmwasm18ygxc482fgklywq5e2fsnmnkqflwaq5u07f9yw824ajfu2x6920sv28wwu for denom 'Cw20(Addr(\"cosmwasm1wug8sewp6cedgkmrmvhl3lf3tulagm9hnvy8p0rppz9yjw0g4wtqlrtkzd\"))' in split config (required: 10000000000000, available: 1000000000000)."
 )]
 fn split_cw20_single_token_amount_fails_for_insufficient_balance() {
     let mut suite = ReverseSplitterTestSuite::default();
@@ -50,7 +50,7 @@ mod functions {
     use itertools::Itertools;
     use valence_library_utils::{
         denoms::CheckedDenom,
-        error::LibraryError,
+        error::{LibraryError, ERR_INSUFFICIENT_BALANCE, ERR_INVALID_DENOM},
         execute_on_behalf_of,
         msg::{DynamicRatioQueryMsg, DynamicRatioResponse},
     };
@@ -172,10 +172,13 @@ mod functions {
             let total_amount: Uint128 = amounts.iter().map(|(amount, _, _)| *amount).sum();
             let balance = denom_balances.get(&denom).unwrap();
             if total_amount > *balance {
-                return Err(LibraryError::ExecutionError(format!(
-                    "Insufficient balance for denom '{}' in split config (required: {}, available: {}).",
-                    denom, total_amount, balance,
-                )));
+                return Err(LibraryError::ExecutionError {
+                    code: ERR_INSUFFICIENT_BALANCE,
+                    msg: format!(
+                        "Insufficient balance for denom '{}' in split config (required: {}, available: {}).",
+                        denom, total_amount, balance,
+                    ),
+                });
             }
             Ok(())
         }).collect::<Result<Vec<()>, LibraryError>>()?;
@@ -200,10 +203,10 @@ mod functions {
         res.denom_ratios
             .get(&denom_name)
             .copied()
-            .ok_or(LibraryError::ExecutionError(format!(
-                "Dynamic ratio not found for denom '{}'.",
-                denom
-            )))
+            .ok_or(LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: format!("Dynamic ratio not found for denom '{}'.", denom),
+            })
     }
 
     fn denom_key(denom: &CheckedDenom) -> String {
@@ -240,6 +243,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::GetProcessor {} => {
             to_json_binary(&valence_library_base::get_processor(deps.storage)?)
         }
+        QueryMsg::GetProcessors {} => {
+            to_json_binary(&valence_library_base::get_processors(deps.storage)?)
+        }
         QueryMsg::GetLibraryConfig {} => {
             let config: Config = valence_library_base::load_config(deps.storage)?;
             to_json_binary(&config)
@@ -249,5 +255,8 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
                 valence_library_utils::raw_config::query_raw_library_config(deps.storage)?;
             to_json_binary(&raw_config)
         }
+        QueryMsg::GetConfigVersion {} => {
+            to_json_binary(&valence_library_base::get_config_version(deps.storage)?)
+        }
     }
 }
@@ -652,7 +652,7 @@ fn split_native_two_token_partial_amounts_two_outputs() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance for denom 'Native(\"untrn\")' in split config (required: 10000000000000, available: 1000000000000)."
+    expected = "Execution error 1001: Insufficient balance for denom 'Native(\"untrn\")' in split config (required: 10000000000000, available: 1000000000000)."
 )]
 fn split_native_single_token_amount_fails_for_insufficient_balance() {
     let mut suite = SplitterTestSuite::new(Some(vec![(ONE_MILLION, NTRN.into())]));
@@ -674,7 +674,7 @@ fn split_native_single_token_amount_fails_for_insufficient_balance() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance for denom 'Cw20(Addr(\"cosmwasm1uzyszmsnca8euusre35wuqj4el3hyj8jty84kwln7du5stwwxyns2z5hxp\"))' in split config (required: 10000000000000, available: 1000000000000)."
+    expected = "Execution error 1001: Insufficient balance for denom 'Cw20(Addr(\"cosmwasm1uzyszmsnca8euusre35wuqj4el3hyj8jty84kwln7du5stwwxyns2z5hxp\"))' in split config (required: 10000000000000, available: 1000000000000)."
 )]
 fn split_cw20_single_token_amount_fails_for_insufficient_balance() {
     let mut suite = SplitterTestSuite::default();
@@ -698,7 +698,7 @@ fn split_cw20_single_token_amount_fails_for_insufficient_balance() {
 
 #[test]
 #[should_panic(
-    expected = "Execution error: Insufficient balance for denom 'Native(\"untrn\")' in split config (required: 1200000000000, available: 1000000000000)."
+    expected = "Execution error 1001: Insufficient balance for denom 'Native(\"untrn\")' in split config (required: 1200000000000, available: 1000000000000)."
 )]
 fn split_native_single_token_amount_two_outputs_fails_for_insufficient_balance() {
     let mut suite = SplitterTestSuite::new(Some(vec![(ONE_MILLION, NTRN.into())]));
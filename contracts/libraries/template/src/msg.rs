@@ -26,6 +26,7 @@ pub enum QueryMsg {}
 ///  
 /// Fields that are Option<T>, will be generated as OptionUpdate<T>
 /// If a field cannot or should not be updated, it should be annotated with #[skip_update]
+/// Fields can be validated on update with #[validate(non_empty)], #[validate(positive)] or #[validate(addr)]
 #[cw_serde]
 #[derive(ValenceLibraryInterface)]
 pub struct LibraryConfig {
@@ -34,6 +35,8 @@ pub struct LibraryConfig {
     #[skip_update]
     pub skip_update_admin: LibraryAccountType,
     pub optional: Option<String>,
+    /// Updating this field to an empty string will fail
+    #[validate(non_empty)]
     pub optional2: String,
 }
 
@@ -56,8 +59,10 @@ impl LibraryConfigUpdate {
     pub fn update_config(self, deps: DepsMut) -> Result<(), LibraryError> {
         let mut config: Config = valence_library_base::load_config(deps.storage)?;
 
-        if let OptionUpdate::Set(optional) = self.optional {
-            config.optional = optional;
+        match self.optional {
+            OptionUpdate::Set(optional) => config.optional = optional,
+            OptionUpdate::Clear => config.optional = None,
+            OptionUpdate::None => {}
         }
 
         // While we get &mut Config, we can execute regular storage operations
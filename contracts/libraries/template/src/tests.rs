@@ -67,12 +67,16 @@ impl TemplateTestSuite {
         )
     }
 
-    fn update_config(&mut self, addr: Addr, new_config: LibraryConfig) -> AnyResult<AppResponse> {
+    fn update_config(
+        &mut self,
+        addr: Addr,
+        new_config: LibraryConfigUpdate,
+    ) -> AnyResult<AppResponse> {
         let owner = self.owner().clone();
         self.app_mut().execute_contract(
             owner,
             addr,
-            &ExecuteMsg::<FunctionMsgs, LibraryConfig>::UpdateConfig { new_config },
+            &ExecuteMsg::<FunctionMsgs, LibraryConfigUpdate>::UpdateConfig { new_config },
             &[],
         )
     }
@@ -188,3 +192,272 @@ fn execute_function() {
     // Execute function
     suite.execute_noop(lib).unwrap();
 }
+
+#[test]
+fn update_config_validates_fields() {
+    let mut suite = TemplateTestSuite::default();
+
+    let cfg = suite.template_config(suite.owner().to_string());
+
+    // Instantiate Template contract
+    let lib = suite.template_init(&cfg);
+
+    // optional2 is annotated with #[validate(non_empty)]
+    let err = suite
+        .update_config(
+            lib.clone(),
+            LibraryConfigUpdate {
+                optional: OptionUpdate::None,
+                optional2: Some("".to_string()),
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.root_cause().to_string(),
+        "Generic error: optional2 must not be empty"
+    );
+
+    suite
+        .update_config(
+            lib.clone(),
+            LibraryConfigUpdate {
+                optional: OptionUpdate::None,
+                optional2: Some("new".to_string()),
+            },
+        )
+        .unwrap();
+
+    let raw_config: LibraryConfig = suite.query_wasm(&lib, &QueryMsg::GetRawLibraryConfig {});
+    assert_eq!(raw_config.optional2, "new");
+}
+
+#[test]
+fn update_config_without_changes_is_noop() {
+    let mut suite = TemplateTestSuite::default();
+
+    let cfg = suite.template_config(suite.owner().to_string());
+
+    // Instantiate Template contract
+    let lib = suite.template_init(&cfg);
+
+    let res = suite
+        .update_config(lib.clone(), LibraryConfigUpdate::default())
+        .unwrap();
+
+    // The update is skipped before the raw config is written
+    assert!(res.events.iter().any(|event| event
+        .attributes
+        .iter()
+        .any(|attr| attr.key == "method" && attr.value == "update_config_noop")));
+
+    let raw_config: LibraryConfig = suite.query_wasm(&lib, &QueryMsg::GetRawLibraryConfig {});
+    assert_eq!(raw_config, cfg);
+}
+
+#[test]
+fn update_config_clear_optional() {
+    let mut suite = TemplateTestSuite::default();
+
+    let mut cfg = suite.template_config(suite.owner().to_string());
+    cfg.optional = Some("optional".to_string());
+
+    // Instantiate Template contract
+    let lib = suite.template_init(&cfg);
+
+    suite
+        .update_config(
+            lib.clone(),
+            LibraryConfigUpdate {
+                optional: OptionUpdate::Clear,
+                optional2: None,
+            },
+        )
+        .unwrap();
+
+    let raw_config: LibraryConfig = suite.query_wasm(&lib, &QueryMsg::GetRawLibraryConfig {});
+    assert_eq!(raw_config.optional, None);
+
+    let config: Config = suite.query_wasm(&lib, &QueryMsg::GetLibraryConfig {});
+    assert_eq!(config.optional, None);
+}
+
+#[test]
+fn update_config_increments_config_version() {
+    let mut suite = TemplateTestSuite::default();
+
+    let cfg = suite.template_config(suite.owner().to_string());
+
+    // Instantiate Template contract
+    let lib = suite.template_init(&cfg);
+
+    // No version until the config is updated
+    let version: Option<(u64, Addr, u64)> = suite.query_wasm(&lib, &QueryMsg::GetConfigVersion {});
+    assert_eq!(version, None);
+
+    // Noop updates don't change the version
+    suite
+        .update_config(lib.clone(), LibraryConfigUpdate::default())
+        .unwrap();
+    let version: Option<(u64, Addr, u64)> = suite.query_wasm(&lib, &QueryMsg::GetConfigVersion {});
+    assert_eq!(version, None);
+
+    for expected_version in 1..=2u64 {
+        suite.next_block();
+        let res = suite
+            .update_config(
+                lib.clone(),
+                LibraryConfigUpdate {
+                    optional: OptionUpdate::Set(Some(format!("optional{}", expected_version))),
+                    optional2: None,
+                },
+            )
+            .unwrap();
+
+        assert!(res
+            .events
+            .iter()
+            .any(|event| event
+                .attributes
+                .iter()
+                .any(|attr| attr.key == "config_version"
+                    && attr.value == expected_version.to_string())));
+
+        let (version, updater, height): (u64, Addr, u64) = suite
+            .query_wasm::<_, Option<(u64, Addr, u64)>>(&lib, &QueryMsg::GetConfigVersion {})
+            .unwrap();
+        assert_eq!(version, expected_version);
+        assert_eq!(&updater, suite.owner());
+        assert_eq!(height, suite.app().block_info().height);
+    }
+}
+
+#[test]
+fn multiple_processors_can_execute_functions() {
+    let mut suite = TemplateTestSuite::default();
+    let cfg = suite.template_config(suite.owner().to_string());
+    let lib = suite.template_init(&cfg);
+
+    let owner = suite.owner().clone();
+    let processor = suite.processor().clone();
+    let backup_processor = suite.api().addr_make("backup_processor");
+    let noop_msg = ExecuteMsg::<_, LibraryConfig>::ProcessFunction(FunctionMsgs::NoOp {});
+
+    // Backup processor is not allowed until it is added
+    suite
+        .app_mut()
+        .execute_contract(backup_processor.clone(), lib.clone(), &noop_msg, &[])
+        .unwrap_err();
+
+    // Only the owner can add processors
+    let add_msg = ExecuteMsg::<FunctionMsgs, LibraryConfig>::AddProcessor {
+        processor: backup_processor.to_string(),
+    };
+    suite
+        .app_mut()
+        .execute_contract(processor.clone(), lib.clone(), &add_msg, &[])
+        .unwrap_err();
+    suite
+        .app_mut()
+        .execute_contract(owner.clone(), lib.clone(), &add_msg, &[])
+        .unwrap();
+
+    let mut expected = vec![processor.clone(), backup_processor.clone()];
+    expected.sort();
+    let processors: Vec<Addr> = suite.query_wasm(&lib, &QueryMsg::GetProcessors {});
+    assert_eq!(processors, expected);
+
+    // Both processors can execute functions
+    suite
+        .app_mut()
+        .execute_contract(backup_processor.clone(), lib.clone(), &noop_msg, &[])
+        .unwrap();
+    suite.execute_noop(lib.clone()).unwrap();
+
+    // The primary processor can't be removed
+    suite
+        .app_mut()
+        .execute_contract(
+            owner.clone(),
+            lib.clone(),
+            &ExecuteMsg::<FunctionMsgs, LibraryConfig>::RemoveProcessor {
+                processor: processor.to_string(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    // Backup processor is no longer allowed once removed
+    suite
+        .app_mut()
+        .execute_contract(
+            owner,
+            lib.clone(),
+            &ExecuteMsg::<FunctionMsgs, LibraryConfig>::RemoveProcessor {
+                processor: backup_processor.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+        .app_mut()
+        .execute_contract(backup_processor, lib.clone(), &noop_msg, &[])
+        .unwrap_err();
+
+    let processors: Vec<Addr> = suite.query_wasm(&lib, &QueryMsg::GetProcessors {});
+    assert_eq!(processors, vec![processor]);
+    suite.execute_noop(lib).unwrap();
+}
+
+#[test]
+fn update_processor_replaces_all_processors() {
+    let mut suite = TemplateTestSuite::default();
+    let cfg = suite.template_config(suite.owner().to_string());
+    let lib = suite.template_init(&cfg);
+
+    let owner = suite.owner().clone();
+    let processor = suite.processor().clone();
+    let backup_processor = suite.api().addr_make("backup_processor");
+    let new_processor = suite.api().addr_make("new_processor");
+    let noop_msg = ExecuteMsg::<_, LibraryConfig>::ProcessFunction(FunctionMsgs::NoOp {});
+
+    suite
+        .app_mut()
+        .execute_contract(
+            owner.clone(),
+            lib.clone(),
+            &ExecuteMsg::<FunctionMsgs, LibraryConfig>::AddProcessor {
+                processor: backup_processor.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    suite
+        .app_mut()
+        .execute_contract(
+            owner,
+            lib.clone(),
+            &ExecuteMsg::<FunctionMsgs, LibraryConfig>::UpdateProcessor {
+                processor: new_processor.to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+
+    // The new processor is the primary one and the only one left
+    let primary: Addr = suite.query_wasm(&lib, &QueryMsg::GetProcessor {});
+    assert_eq!(primary, new_processor);
+    let processors: Vec<Addr> = suite.query_wasm(&lib, &QueryMsg::GetProcessors {});
+    assert_eq!(processors, vec![new_processor.clone()]);
+
+    // Previous processors are removed
+    for old_processor in [processor, backup_processor] {
+        suite
+            .app_mut()
+            .execute_contract(old_processor, lib.clone(), &noop_msg, &[])
+            .unwrap_err();
+    }
+    suite
+        .app_mut()
+        .execute_contract(new_processor, lib, &noop_msg, &[])
+        .unwrap();
+}
@@ -1,4 +1,6 @@
-use cosmwasm_std::{to_json_binary, Addr, BlockInfo, CosmosMsg, Storage, Uint64, WasmMsg};
+use cosmwasm_std::{
+    to_json_binary, Addr, BlockInfo, CosmosMsg, StdResult, Storage, Uint64, WasmMsg,
+};
 use valence_authorization_utils::{
     authorization::Subroutine,
     callback::ExecutionResult,
@@ -7,7 +9,9 @@ use valence_authorization_utils::{
 };
 use valence_polytone_utils::polytone::{CallbackRequest, PolytoneExecuteMsg};
 use valence_processor_utils::{
-    callback::{PendingPolytoneCallbackInfo, PolytoneCallbackMsg, PolytoneCallbackState},
+    callback::{
+        ExecutionRecord, PendingPolytoneCallbackInfo, PolytoneCallbackMsg, PolytoneCallbackState,
+    },
     processor::{Config, MessageBatch, ProcessorDomain},
 };
 
@@ -15,8 +19,8 @@ use crate::{
     error::ContractError,
     queue::{get_queue_map, put_back_into_queue},
     state::{
-        CONFIG, EXECUTION_ID_TO_BATCH, NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX,
-        PENDING_POLYTONE_CALLBACKS,
+        CONFIG, EXECUTION_HISTORY, EXECUTION_ID_TO_BATCH, MAX_EXECUTION_HISTORY,
+        NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX, PENDING_POLYTONE_CALLBACKS,
     },
 };
 
@@ -72,12 +76,49 @@ pub fn create_callback_message(
     Ok(message)
 }
 
+/// Adds the result of a batch to the execution history, removing the oldest record if the history is full
+pub fn record_execution(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    batch: &MessageBatch,
+    index: Option<usize>,
+    result: &ExecutionResult,
+) -> StdResult<()> {
+    let error = match result {
+        ExecutionResult::Rejected(error) | ExecutionResult::PartiallyExecuted(_, error) => {
+            Some(error.clone())
+        }
+        _ => None,
+    };
+
+    EXECUTION_HISTORY.push_back(
+        storage,
+        &ExecutionRecord {
+            batch_id: batch.id,
+            result: result.clone(),
+            timestamp: block.time.seconds(),
+            library: Addr::unchecked(
+                batch
+                    .subroutine
+                    .get_contract_address_by_function_index(index.unwrap_or_default()),
+            ),
+            error,
+        },
+    )?;
+    if EXECUTION_HISTORY.len(storage)? > MAX_EXECUTION_HISTORY {
+        EXECUTION_HISTORY.pop_front(storage)?;
+    }
+
+    Ok(())
+}
+
 pub fn handle_successful_non_atomic_callback(
     storage: &mut dyn Storage,
     index: usize,
     execution_id: u64,
     batch: &mut MessageBatch,
     messages: &mut Vec<CosmosMsg>,
+    block: &BlockInfo,
     processor_address: &Addr,
 ) -> Result<(), ContractError> {
     // Advance to the next function if there is one and if not, provide the successfull callback to the authorization module
@@ -85,6 +126,13 @@ pub fn handle_successful_non_atomic_callback(
     if next_index >= batch.msgs.len() {
         // We finished the batch, we'll provide the successfull callback to the authorization module
         let config = CONFIG.load(storage)?;
+        record_execution(
+            storage,
+            block,
+            batch,
+            Some(index),
+            &ExecutionResult::Success,
+        )?;
         messages.push(create_callback_message(
             storage,
             &config,
@@ -111,14 +159,16 @@ pub fn handle_successful_non_atomic_callback(
 pub fn handle_successful_atomic_callback(
     storage: &mut dyn Storage,
     config: &Config,
-    execution_id: u64,
+    batch: &MessageBatch,
     messages: &mut Vec<CosmosMsg>,
+    block: &BlockInfo,
     processor_address: &Addr,
 ) -> Result<(), ContractError> {
+    record_execution(storage, block, batch, None, &ExecutionResult::Success)?;
     messages.push(create_callback_message(
         storage,
         config,
-        execution_id,
+        batch.id,
         ExecutionResult::Success,
         processor_address,
     )?);
@@ -164,6 +214,7 @@ pub fn handle_unsuccessful_callback(
                         } else {
                             ExecutionResult::PartiallyExecuted(index, error)
                         };
+                        record_execution(storage, block, batch, Some(index), &execution_result)?;
 
                         messages.push(create_callback_message(
                             storage,
@@ -187,11 +238,13 @@ pub fn handle_unsuccessful_callback(
         }
         None => {
             // No retry logic, return callback to authorization module
+            let execution_result = ExecutionResult::Rejected(error);
+            record_execution(storage, block, batch, Some(index), &execution_result)?;
             messages.push(create_callback_message(
                 storage,
                 config,
                 execution_id,
-                ExecutionResult::Rejected(error),
+                execution_result,
                 processor_address,
             )?);
             // Clean up for non-atomic case
@@ -0,0 +1,120 @@
+use cosmwasm_std::{Addr, BlockInfo, Event, StdResult, Storage};
+use valence_authorization_utils::{authorization::Subroutine, function::Function};
+use valence_processor_utils::processor::{CircuitBreaker, Config, MessageBatch};
+
+use crate::{error::ContractError, state::FAILURE_COUNTS};
+
+/// A zero threshold would trip the circuit breaker of every library before anything is executed
+/// and a zero reset period would never keep it tripped
+pub fn validate_circuit_breaker(circuit_breaker: &CircuitBreaker) -> Result<(), ContractError> {
+    if circuit_breaker.failure_threshold == 0 {
+        return Err(ContractError::InvalidCircuitBreaker(
+            "failure_threshold must be greater than 0".to_string(),
+        ));
+    }
+    if circuit_breaker.reset_after_blocks == 0 {
+        return Err(ContractError::InvalidCircuitBreaker(
+            "reset_after_blocks must be greater than 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the libraries that are executed when processing a batch. For atomic batches all of them are executed at once
+/// and for non-atomic batches only the one of the function we are currently on
+pub fn get_batch_libraries(batch: &MessageBatch, index: Option<usize>) -> Vec<Addr> {
+    let mut libraries: Vec<Addr> = match &batch.subroutine {
+        Subroutine::Atomic(config) => config
+            .functions
+            .iter()
+            .map(|function| Addr::unchecked(function.get_contract_address()))
+            .collect(),
+        Subroutine::NonAtomic(config) => config
+            .functions
+            .get(index.unwrap_or_default())
+            .map(|function| Addr::unchecked(function.get_contract_address()))
+            .into_iter()
+            .collect(),
+    };
+    libraries.sort();
+    libraries.dedup();
+    libraries
+}
+
+/// Returns the first library that has its circuit breaker tripped, if any
+pub fn get_tripped_library(
+    storage: &dyn Storage,
+    config: &Config,
+    block: &BlockInfo,
+    libraries: &[Addr],
+) -> StdResult<Option<Addr>> {
+    let Some(circuit_breaker) = &config.circuit_breaker else {
+        return Ok(None);
+    };
+
+    for library in libraries {
+        let failures = current_failures(storage, circuit_breaker, block, library)?;
+        if failures >= circuit_breaker.failure_threshold {
+            return Ok(Some(library.clone()));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Increases the consecutive failures of the libraries and returns a `circuit_breaker_tripped` event for each library that reached the threshold
+pub fn record_failure(
+    storage: &mut dyn Storage,
+    config: &Config,
+    block: &BlockInfo,
+    libraries: &[Addr],
+) -> StdResult<Vec<Event>> {
+    let Some(circuit_breaker) = &config.circuit_breaker else {
+        return Ok(vec![]);
+    };
+
+    let mut events = vec![];
+    for library in libraries {
+        let failures = current_failures(storage, circuit_breaker, block, library)?
+            .checked_add(1)
+            .expect("Overflow");
+        FAILURE_COUNTS.save(storage, library.clone(), &(failures, block.height))?;
+
+        if failures == circuit_breaker.failure_threshold {
+            events.push(
+                Event::new("circuit_breaker_tripped")
+                    .add_attribute("library", library.to_string())
+                    .add_attribute("failures", failures.to_string()),
+            );
+        }
+    }
+
+    Ok(events)
+}
+
+/// A successful execution breaks the streak of consecutive failures
+pub fn record_success(storage: &mut dyn Storage, config: &Config, libraries: &[Addr]) {
+    if config.circuit_breaker.is_some() {
+        for library in libraries {
+            FAILURE_COUNTS.remove(storage, library.clone());
+        }
+    }
+}
+
+// Failures are forgotten once `reset_after_blocks` blocks have passed since the last one
+fn current_failures(
+    storage: &dyn Storage,
+    circuit_breaker: &CircuitBreaker,
+    block: &BlockInfo,
+    library: &Addr,
+) -> StdResult<u32> {
+    Ok(match FAILURE_COUNTS.may_load(storage, library.clone())? {
+        Some((failures, last_failure_block))
+            if block.height
+                < last_failure_block.saturating_add(circuit_breaker.reset_after_blocks) =>
+        {
+            failures
+        }
+        _ => 0,
+    })
+}
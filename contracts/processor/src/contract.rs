@@ -2,8 +2,8 @@
 use cosmwasm_std::entry_point;
 
 use cosmwasm_std::{
-    from_json, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Order, Reply,
-    Response, StdResult, SubMsg, SubMsgResult, Uint64, WasmMsg,
+    from_json, to_json_binary, to_json_string, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+    Order, Reply, Response, StdResult, SubMsg, SubMsgResult, Uint64, WasmMsg,
 };
 
 use cw_storage_plus::Bound;
@@ -18,7 +18,8 @@ use valence_polytone_utils::polytone::{
 };
 use valence_processor_utils::{
     callback::{
-        PendingCallback, PendingPolytoneCallbackInfo, PolytoneCallbackMsg, PolytoneCallbackState,
+        ExecutionRecord, PendingCallback, PendingPolytoneCallbackInfo, PolytoneCallbackMsg,
+        PolytoneCallbackState,
     },
     msg::{
         AuthorizationMsg, ExecuteMsg, InstantiateMsg, InternalProcessorMsg, PermissionlessMsg,
@@ -30,13 +31,17 @@ use valence_processor_utils::{
 use crate::{
     callback::{
         create_callback_message, handle_successful_atomic_callback,
-        handle_successful_non_atomic_callback, handle_unsuccessful_callback,
+        handle_successful_non_atomic_callback, handle_unsuccessful_callback, record_execution,
+    },
+    circuit_breaker::{
+        get_batch_libraries, get_tripped_library, record_failure, record_success,
+        validate_circuit_breaker,
     },
     error::{CallbackErrorReason, ContractError, UnauthorizedReason},
     queue::get_queue_map,
     state::{
-        CONFIG, EXECUTION_ID_TO_BATCH, NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX, PENDING_CALLBACK,
-        PENDING_POLYTONE_CALLBACKS,
+        CONFIG, EXECUTION_HISTORY, EXECUTION_ID_TO_BATCH, FAILURE_COUNTS,
+        NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX, PENDING_CALLBACK, PENDING_POLYTONE_CALLBACKS,
     },
 };
 
@@ -55,6 +60,10 @@ pub fn instantiate(
 ) -> Result<Response, ContractError> {
     cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
+    if let Some(circuit_breaker) = &msg.circuit_breaker {
+        validate_circuit_breaker(circuit_breaker)?;
+    }
+
     let config = Config {
         authorization_contract: msg.authorization_contract,
         processor_domain: match msg.polytone_contracts {
@@ -67,6 +76,7 @@ pub fn instantiate(
             None => ProcessorDomain::Main,
         },
         state: State::Active,
+        circuit_breaker: msg.circuit_breaker,
     };
     CONFIG.save(deps.storage, &config)?;
 
@@ -124,7 +134,8 @@ pub fn execute(
                     msgs,
                     subroutine,
                     priority,
-                } => enqueue_messages(deps, id, msgs, subroutine, priority),
+                    expiration_time,
+                } => enqueue_messages(deps, id, msgs, subroutine, priority, expiration_time),
                 AuthorizationMsg::EvictMsgs {
                     queue_position,
                     priority,
@@ -135,13 +146,28 @@ pub fn execute(
                     msgs,
                     subroutine,
                     priority,
-                } => insert_messages(deps, queue_position, id, msgs, subroutine, priority),
+                    expiration_time,
+                } => insert_messages(
+                    deps,
+                    queue_position,
+                    id,
+                    msgs,
+                    subroutine,
+                    priority,
+                    expiration_time,
+                ),
                 AuthorizationMsg::Pause {} => pause_processor(deps),
                 AuthorizationMsg::Resume {} => resume_processor(deps),
+                AuthorizationMsg::ResetCircuitBreaker { library } => {
+                    reset_circuit_breaker(deps, library)
+                }
             }
         }
         ExecuteMsg::PermissionlessAction(permissionless_msg) => match permissionless_msg {
-            PermissionlessMsg::Tick {} => process_tick(deps, env),
+            PermissionlessMsg::Tick { simulate } => match simulate {
+                true => simulate_tick(deps.as_ref(), env),
+                false => process_tick(deps, env),
+            },
             PermissionlessMsg::RetryCallback { execution_id } => {
                 retry_callback(deps, env, execution_id)
             }
@@ -180,6 +206,16 @@ fn resume_processor(deps: DepsMut) -> Result<Response, ContractError> {
     Ok(Response::new().add_attribute("method", "resume_processor"))
 }
 
+/// Clears the failures of a library so that its batches are processed again
+fn reset_circuit_breaker(deps: DepsMut, library: String) -> Result<Response, ContractError> {
+    let library = deps.api.addr_validate(&library)?;
+    FAILURE_COUNTS.remove(deps.storage, library.clone());
+
+    Ok(Response::new()
+        .add_attribute("method", "reset_circuit_breaker")
+        .add_attribute("library", library))
+}
+
 /// Adds the messages to the back of the corresponding queue
 fn enqueue_messages(
     deps: DepsMut,
@@ -187,6 +223,7 @@ fn enqueue_messages(
     msgs: Vec<ProcessorMessage>,
     subroutine: Subroutine,
     priority: Priority,
+    expiration_time: Option<u64>,
 ) -> Result<Response, ContractError> {
     let queue = get_queue_map(&priority);
 
@@ -196,6 +233,7 @@ fn enqueue_messages(
         subroutine,
         priority,
         retry: None,
+        expiration_time,
     };
     queue.push_back(deps.storage, &message_batch)?;
     EXECUTION_ID_TO_BATCH.save(deps.storage, id, &message_batch)?;
@@ -216,9 +254,18 @@ fn evict_messages(
         Some(batch) => {
             let config = CONFIG.load(deps.storage)?;
             // Do the clean up and send the callback
+            // Read the index before cleaning up, to record the library the batch was on
+            let index = NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.may_load(deps.storage, batch.id)?;
             EXECUTION_ID_TO_BATCH.remove(deps.storage, batch.id);
             NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.remove(deps.storage, batch.id);
             PENDING_CALLBACK.remove(deps.storage, batch.id);
+            record_execution(
+                deps.storage,
+                &env.block,
+                &batch,
+                index,
+                &ExecutionResult::RemovedByOwner,
+            )?;
             let callback_msg = create_callback_message(
                 deps.storage,
                 &config,
@@ -239,6 +286,7 @@ fn evict_messages(
 }
 
 /// Insert a set of messages in a specific position of the queue
+#[allow(clippy::too_many_arguments)]
 fn insert_messages(
     deps: DepsMut,
     queue_position: u64,
@@ -246,6 +294,7 @@ fn insert_messages(
     msgs: Vec<ProcessorMessage>,
     subroutine: Subroutine,
     priority: Priority,
+    expiration_time: Option<u64>,
 ) -> Result<Response, ContractError> {
     let mut queue = get_queue_map(&priority);
 
@@ -255,6 +304,7 @@ fn insert_messages(
         subroutine,
         priority,
         retry: None,
+        expiration_time,
     };
 
     queue.insert_at(deps.storage, queue_position, &message_batch)?;
@@ -282,6 +332,34 @@ fn process_tick(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
     let messages;
     match message_batch {
         Some(batch) => {
+            // If the batch has expired we won't execute it, we'll remove it and notify the authorization contract
+            if batch.is_expired(&env.block) {
+                // For non-atomic batches, the current index is the amount of functions that were already executed
+                let executed_functions = NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX
+                    .may_load(deps.storage, batch.id)?
+                    .unwrap_or_default();
+                // Clean up
+                NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.remove(deps.storage, batch.id);
+                EXECUTION_ID_TO_BATCH.remove(deps.storage, batch.id);
+                record_execution(
+                    deps.storage,
+                    &env.block,
+                    &batch,
+                    Some(executed_functions),
+                    &ExecutionResult::Expired(executed_functions),
+                )?;
+                let callback_msg = create_callback_message(
+                    deps.storage,
+                    &config,
+                    batch.id,
+                    ExecutionResult::Expired(executed_functions),
+                    &env.contract.address,
+                )?;
+                return Ok(Response::new()
+                    .add_message(callback_msg)
+                    .add_attribute("method", "tick")
+                    .add_attribute("action", "expired_batch"));
+            }
             // First we check if the current batch or function to be executed is retriable, if it isn't we'll just push it back to the end of the queue
             // If the retry_cooldown has not passed yet, we'll push the batch back to the queue and wait for the next tick
             if let Some(current_retry) = batch.retry.clone() {
@@ -292,6 +370,21 @@ fn process_tick(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
                         .add_attribute("action", "pushed_function_back_to_queue"));
                 }
             }
+            // If any of the libraries we are about to execute has its circuit breaker tripped, we'll push the batch back to the queue
+            let current_index =
+                NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.may_load(deps.storage, batch.id)?;
+            if let Some(library) = get_tripped_library(
+                deps.storage,
+                &config,
+                &env.block,
+                &get_batch_libraries(&batch, current_index),
+            )? {
+                queue.push_back(deps.storage, &batch)?;
+                return Ok(Response::new()
+                    .add_attribute("method", "tick")
+                    .add_attribute("action", "circuit_breaker_tripped")
+                    .add_attribute("library", library));
+            }
             // First we check if the function batch is atomic or not, as the way of processing them is different
             match batch.subroutine {
                 Subroutine::Atomic(_) => {
@@ -360,6 +453,72 @@ fn process_tick(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
     }
 }
 
+/// Goes through the same steps as a tick without modifying any state and returns what the tick would do and the messages
+/// it would dispatch, so that relayers can simulate those messages to check if they have enough gas before ticking
+fn simulate_tick(deps: Deps, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.state.eq(&State::Paused) {
+        return Err(ContractError::ProcessorPaused {});
+    }
+
+    let mut queue = get_queue_map(&Priority::High);
+    if queue.is_empty(deps.storage)? {
+        queue = get_queue_map(&Priority::Medium);
+    }
+
+    let batch = queue
+        .peek_front(deps.storage)?
+        .ok_or(ContractError::NoMessagesToProcess {})?;
+    let response = Response::new()
+        .add_attribute("method", "tick")
+        .add_attribute("simulate", "true")
+        .add_attribute("batch_id", batch.id.to_string());
+
+    if batch.is_expired(&env.block) {
+        return Ok(response.add_attribute("action", "expired_batch"));
+    }
+    if batch
+        .retry
+        .as_ref()
+        .is_some_and(|retry| !retry.retry_cooldown.is_expired(&env.block))
+    {
+        return Ok(response.add_attribute("action", "pushed_function_back_to_queue"));
+    }
+    let current_index = NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.may_load(deps.storage, batch.id)?;
+    if let Some(library) = get_tripped_library(
+        deps.storage,
+        &config,
+        &env.block,
+        &get_batch_libraries(&batch, current_index),
+    )? {
+        return Ok(response
+            .add_attribute("action", "circuit_breaker_tripped")
+            .add_attribute("library", library));
+    }
+
+    // The messages the libraries would receive, for atomic batches the processor wraps them in a call to itself
+    let messages: Vec<CosmosMsg> = match &batch.subroutine {
+        Subroutine::Atomic(_) => batch.clone().into(),
+        Subroutine::NonAtomic(subroutine) => {
+            let current_index = current_index.unwrap_or_default();
+            let sub_msgs = if subroutine.functions[current_index]
+                .callback_confirmation
+                .is_some()
+            {
+                batch.create_message_by_index_with_execution_id(current_index, batch.id)?
+            } else {
+                batch.create_message_by_index(current_index)
+            };
+            sub_msgs.into_iter().map(|sub_msg| sub_msg.msg).collect()
+        }
+    };
+
+    Ok(response
+        .add_attribute("action", "execute_batch")
+        .add_attribute("messages", to_json_string(&messages)?))
+}
+
 fn retry_callback(deps: DepsMut, env: Env, execution_id: u64) -> Result<Response, ContractError> {
     let pending_callback = PENDING_POLYTONE_CALLBACKS
         .load(deps.storage, execution_id)
@@ -454,12 +613,15 @@ fn process_callback(
 
     // Get the current index we are at for this non atomic function
     let index = NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.load(deps.storage, execution_id)?;
+    let config = CONFIG.load(deps.storage)?;
+    let libraries = get_batch_libraries(&pending_callback.message_batch, Some(index));
     let mut messages = vec![];
+    let mut events = vec![];
     // Check if the message sent is the one we are expecting
     // If it is, we'll proceed to next function or provide the callback to the authorization module (if we finished with all functions)
     // If it isn't, we need to see if we can retry the function or provide the error to the authorization module
     if msg != pending_callback.callback_msg {
-        let config = CONFIG.load(deps.storage)?;
+        events = record_failure(deps.storage, &config, &env.block, &libraries)?;
         handle_unsuccessful_callback(
             deps.storage,
             execution_id,
@@ -472,12 +634,14 @@ fn process_callback(
             &env.contract.address,
         )?;
     } else {
+        record_success(deps.storage, &config, &libraries);
         handle_successful_non_atomic_callback(
             deps.storage,
             index,
             execution_id,
             &mut pending_callback.message_batch,
             &mut messages,
+            &env.block,
             &env.contract.address,
         )?;
     }
@@ -487,6 +651,7 @@ fn process_callback(
 
     Ok(Response::new()
         .add_messages(messages)
+        .add_events(events)
         .add_attribute("method", "callback"))
 }
 
@@ -632,8 +797,19 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
     let config = CONFIG.load(deps.storage)?;
     let mut batch = EXECUTION_ID_TO_BATCH.load(deps.storage, msg.id)?;
     let mut messages = vec![];
+    let mut events = vec![];
+
+    let index = NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.may_load(deps.storage, msg.id)?;
+    // For atomic batches we can't know which library failed so all of them are accounted for
+    let libraries = get_batch_libraries(&batch, index);
+    match &msg.result {
+        SubMsgResult::Ok(_) => record_success(deps.storage, &config, &libraries),
+        SubMsgResult::Err(_) => {
+            events = record_failure(deps.storage, &config, &env.block, &libraries)?
+        }
+    }
 
-    match NON_ATOMIC_BATCH_CURRENT_FUNCTION_INDEX.may_load(deps.storage, msg.id)? {
+    match index {
         Some(index) => {
             // Non Atomic
             // Check if it replied because of error or success
@@ -647,6 +823,7 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
                             msg.id,
                             &mut batch,
                             &mut messages,
+                            &env.block,
                             &env.contract.address,
                         )?;
                     }
@@ -673,8 +850,9 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
                     handle_successful_atomic_callback(
                         deps.storage,
                         &config,
-                        msg.id,
+                        &batch,
                         &mut messages,
+                        &env.block,
                         &env.contract.address,
                     )?;
                 }
@@ -697,6 +875,7 @@ pub fn reply(deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractEr
 
     Ok(Response::new()
         .add_messages(messages)
+        .add_events(events)
         .add_attribute("method", "reply"))
 }
 
@@ -714,6 +893,9 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::PendingPolytoneCallback { execution_id } => {
             to_json_binary(&get_pending_polytone_callback(deps, execution_id)?)
         }
+        QueryMsg::GetExecutionHistory { start, limit } => {
+            to_json_binary(&get_execution_history(deps, start, limit)?)
+        }
     }
 }
 
@@ -760,3 +942,17 @@ fn get_pending_polytone_callback(
 ) -> StdResult<PendingPolytoneCallbackInfo> {
     PENDING_POLYTONE_CALLBACKS.load(deps.storage, execution_id)
 }
+
+fn get_execution_history(
+    deps: Deps,
+    start: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<ExecutionRecord>> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+
+    EXECUTION_HISTORY
+        .iter(deps.storage)?
+        .skip(start.unwrap_or_default() as usize)
+        .take(limit as usize)
+        .collect()
+}
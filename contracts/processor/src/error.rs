@@ -16,6 +16,9 @@ pub enum ContractError {
     #[error("There is currently nothing to process")]
     NoMessagesToProcess {},
 
+    #[error("Invalid circuit breaker: {0}")]
+    InvalidCircuitBreaker(String),
+
     #[error(transparent)]
     CallbackError(#[from] CallbackErrorReason),
 }
@@ -1,4 +1,5 @@
 pub mod callback;
+pub mod circuit_breaker;
 pub mod contract;
 pub mod error;
 pub mod queue;
@@ -1,6 +1,7 @@
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::Addr;
+use cw_storage_plus::{Deque, Item, Map};
 use valence_processor_utils::{
-    callback::{PendingCallback, PendingPolytoneCallbackInfo},
+    callback::{ExecutionRecord, PendingCallback, PendingPolytoneCallbackInfo},
     processor::{Config, MessageBatch},
     queue::QueueMap,
 };
@@ -33,3 +34,10 @@ pub const PENDING_CALLBACK: Map<u64, PendingCallback> = Map::new("pending_callba
 // The key will be the execution ID the callback was for and we will store the result and the status to re-send if the state is TimedOut
 pub const PENDING_POLYTONE_CALLBACKS: Map<u64, PendingPolytoneCallbackInfo> =
     Map::new("pending_polytone_callbacks");
+
+// Consecutive failures of each library and the block of its last failure, used by the circuit breaker
+pub const FAILURE_COUNTS: Map<Addr, (u32, u64)> = Map::new("failure_counts");
+
+// Last execution results of the processor, bounded to MAX_EXECUTION_HISTORY entries
+pub const EXECUTION_HISTORY: Deque<ExecutionRecord> = Deque::new("execution_history");
+pub const MAX_EXECUTION_HISTORY: u32 = 100;
@@ -1,11 +1,12 @@
 use cosmwasm_schema::write_api;
 
-use valence_program_registry_utils::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use valence_program_registry_utils::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
 
 fn main() {
     write_api! {
         instantiate: InstantiateMsg,
         execute: ExecuteMsg,
         query: QueryMsg,
+        migrate: MigrateMsg,
     }
 }
@@ -1,16 +1,27 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use cosmwasm_std::{
+    from_json, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+};
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
+use serde::Deserialize;
 
-use crate::state::{PROGRAMS, PROGRAMS_BACKUP};
+use crate::state::{
+    DEPRECATED, OWNER_PROGRAMS, PROGRAMS, PROGRAMS_BACKUP, PROGRAM_DESCRIPTIONS, PROGRAM_OWNERS,
+    PROGRAM_TAGS, TAG_INDEX,
+};
 use crate::{error::ContractError, state::LAST_ID};
-use valence_program_registry_utils::{ExecuteMsg, InstantiateMsg, ProgramResponse, QueryMsg};
+use valence_program_registry_utils::{
+    ExecuteMsg, InstantiateMsg, MigrateMsg, ProgramResponse, QueryMsg,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+const MAX_PAGE_LIMIT: u32 = 250;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
@@ -38,11 +49,22 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     match msg {
         ExecuteMsg::ReserveId {} => execute::reserve_id(deps, &info),
-        ExecuteMsg::SaveProgram { id, program_config } => {
-            execute::save_program(deps, &info, id, program_config)
-        }
-        ExecuteMsg::UpdateProgram { id, program_config } => {
-            execute::update_program(deps, &info, id, program_config)
+        ExecuteMsg::SaveProgram {
+            id,
+            owner,
+            program_config,
+            tags,
+            description,
+        } => execute::save_program(deps, &info, id, owner, program_config, tags, description),
+        ExecuteMsg::UpdateProgram {
+            id,
+            program_config,
+            owner,
+            tags,
+            description,
+        } => execute::update_program(deps, &info, id, program_config, owner, tags, description),
+        ExecuteMsg::DeprecateProgram { id, reason } => {
+            execute::deprecate_program(deps, &info, id, reason)
         }
         ExecuteMsg::UpdateOwnership(ownership_action) => {
             cw_ownable::update_ownership(deps, &env.block, &info.sender, ownership_action)?;
@@ -53,14 +75,20 @@ pub fn execute(
 }
 
 mod execute {
-    use cosmwasm_std::{Binary, DepsMut, MessageInfo, Response};
+    use cosmwasm_std::{Addr, Binary, DepsMut, MessageInfo, Response, Storage};
     use cw_ownable::assert_owner;
 
     use crate::{
-        state::{LAST_ID, PROGRAMS, PROGRAMS_BACKUP},
+        state::{
+            DEPRECATED, LAST_ID, OWNER_PROGRAMS, PROGRAMS, PROGRAMS_BACKUP, PROGRAM_DESCRIPTIONS,
+            PROGRAM_OWNERS, PROGRAM_TAGS, TAG_INDEX,
+        },
         ContractError,
     };
 
+    const MAX_TAGS: usize = 10;
+    const MAX_TAG_LENGTH: usize = 32;
+
     pub fn reserve_id(deps: DepsMut, info: &MessageInfo) -> Result<Response, ContractError> {
         assert_owner(deps.storage, &info.sender)?;
 
@@ -72,45 +100,188 @@ mod execute {
             .add_attribute("id", id.to_string()))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn save_program(
         deps: DepsMut,
         info: &MessageInfo,
         id: u64,
+        owner: String,
         program_config: Binary,
+        tags: Vec<String>,
+        description: Option<String>,
     ) -> Result<Response, ContractError> {
         assert_owner(deps.storage, &info.sender)?;
 
+        let owner_addr = deps.api.addr_validate(&owner)?;
+
         if PROGRAMS.has(deps.storage, id) {
             return Err(ContractError::ProgramAlreadyExists(id));
         } else {
+            set_tags(deps.storage, id, tags)?;
             PROGRAMS.save(deps.storage, id, &program_config)?;
+            set_owner(deps.storage, id, &owner_addr)?;
+            if let Some(description) = description {
+                PROGRAM_DESCRIPTIONS.save(deps.storage, id, &description)?;
+            }
         }
 
         Ok(Response::new()
             .add_attribute("method", "get_id")
-            .add_attribute("id", id.to_string()))
+            .add_attribute("id", id.to_string())
+            .add_attribute("owner", owner))
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn update_program(
         deps: DepsMut,
         info: &MessageInfo,
         id: u64,
         program_config: Binary,
+        owner: Option<String>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
     ) -> Result<Response, ContractError> {
         assert_owner(deps.storage, &info.sender)?;
 
         match PROGRAMS.may_load(deps.storage, id)? {
             Some(previous_program) => {
+                if let Some(owner) = owner {
+                    let owner_addr = deps.api.addr_validate(&owner)?;
+                    set_owner(deps.storage, id, &owner_addr)?;
+                }
+                if let Some(tags) = tags {
+                    set_tags(deps.storage, id, tags)?;
+                }
                 PROGRAMS_BACKUP.save(deps.storage, id, &previous_program)?;
                 PROGRAMS.save(deps.storage, id, &program_config)?;
             }
             None => return Err(ContractError::ProgramDoesntExists(id)),
         };
 
+        if let Some(description) = description {
+            PROGRAM_DESCRIPTIONS.save(deps.storage, id, &description)?;
+        }
+
         Ok(Response::new()
             .add_attribute("method", "get_id")
             .add_attribute("id", id.to_string()))
     }
+
+    /// Replace the owner of the program and move the program to the index of the new owner
+    pub fn set_owner(
+        storage: &mut dyn Storage,
+        id: u64,
+        owner: &Addr,
+    ) -> Result<(), ContractError> {
+        if let Some(previous_owner) = PROGRAM_OWNERS.may_load(storage, id)? {
+            OWNER_PROGRAMS.remove(storage, (previous_owner, id));
+        }
+        OWNER_PROGRAMS.save(storage, (owner.clone(), id), &true)?;
+        PROGRAM_OWNERS.save(storage, id, owner)?;
+
+        Ok(())
+    }
+
+    /// Validate the tags and replace the current tags of the program with them
+    fn set_tags(
+        storage: &mut dyn Storage,
+        id: u64,
+        tags: Vec<String>,
+    ) -> Result<(), ContractError> {
+        let mut tags = tags;
+        tags.sort();
+        tags.dedup();
+
+        if tags.len() > MAX_TAGS {
+            return Err(ContractError::TooManyTags(tags.len()));
+        }
+        if let Some(tag) = tags
+            .iter()
+            .find(|tag| tag.is_empty() || tag.chars().count() > MAX_TAG_LENGTH)
+        {
+            return Err(ContractError::InvalidTag(tag.clone()));
+        }
+
+        for tag in PROGRAM_TAGS.may_load(storage, id)?.unwrap_or_default() {
+            TAG_INDEX.remove(storage, (tag, id));
+        }
+        for tag in &tags {
+            TAG_INDEX.save(storage, (tag.clone(), id), &true)?;
+        }
+        PROGRAM_TAGS.save(storage, id, &tags)?;
+
+        Ok(())
+    }
+
+    pub fn deprecate_program(
+        deps: DepsMut,
+        info: &MessageInfo,
+        id: u64,
+        reason: String,
+    ) -> Result<Response, ContractError> {
+        let owner = PROGRAM_OWNERS
+            .may_load(deps.storage, id)?
+            .ok_or(ContractError::ProgramDoesntExists(id))?;
+
+        if owner != info.sender {
+            return Err(ContractError::NotProgramOwner(id));
+        }
+
+        if DEPRECATED.has(deps.storage, id) {
+            return Err(ContractError::ProgramAlreadyDeprecated(id));
+        }
+
+        DEPRECATED.save(deps.storage, id, &reason)?;
+
+        Ok(Response::new()
+            .add_attribute("method", "deprecate_program")
+            .add_attribute("id", id.to_string())
+            .add_attribute("reason", reason))
+    }
+}
+
+/// The part of a stored program config we need to index the program by owner
+#[derive(Deserialize)]
+struct StoredProgramOwner {
+    owner: String,
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    match msg {
+        MigrateMsg::BackfillOwners { start_after, limit } => {
+            let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+            let start = start_after.map(Bound::exclusive);
+
+            let programs = PROGRAMS
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .collect::<StdResult<Vec<_>>>()?;
+
+            let mut indexed = 0;
+            for (id, program_config) in &programs {
+                if PROGRAM_OWNERS.has(deps.storage, *id) {
+                    continue;
+                }
+                let stored: StoredProgramOwner = from_json(program_config)?;
+                let owner = deps.api.addr_validate(&stored.owner)?;
+                execute::set_owner(deps.storage, *id, &owner)?;
+                indexed += 1;
+            }
+
+            let mut response = Response::new()
+                .add_attribute("method", "backfill_owners")
+                .add_attribute("indexed", indexed.to_string());
+            // The next migration can continue after the last processed program
+            if let Some((last_id, _)) = programs.last() {
+                response = response.add_attribute("last_id", last_id.to_string());
+            }
+
+            Ok(response)
+        }
+    }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -118,22 +289,574 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::GetConfig { id } => {
             let config = PROGRAMS.load(deps.storage, id)?;
-            let program = ProgramResponse {
-                id,
-                program_config: config,
-            };
+            let program = program_response(deps, id, config)?;
             to_json_binary(&program)
         }
         QueryMsg::GetConfigBackup { id } => {
             let config = PROGRAMS_BACKUP.may_load(deps.storage, id)?;
-            let program = config.map(|config| ProgramResponse {
-                id,
-                program_config: config,
-            });
+            let program = config
+                .map(|config| program_response(deps, id, config))
+                .transpose()?;
             to_json_binary(&program)
         }
+        QueryMsg::GetConfigsByOwner {
+            owner,
+            start_after,
+            limit,
+        } => {
+            let owner = deps.api.addr_validate(&owner)?;
+            let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+            let start = start_after.map(Bound::exclusive);
+
+            let programs = OWNER_PROGRAMS
+                .prefix(owner)
+                .keys(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|id| {
+                    let id = id?;
+                    program_response(deps, id, PROGRAMS.load(deps.storage, id)?)
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_json_binary(&programs)
+        }
+        QueryMsg::GetProgramsByTag {
+            tag,
+            start_after,
+            limit,
+        } => {
+            let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+            let start = start_after.map(Bound::exclusive);
+
+            let programs = TAG_INDEX
+                .prefix(tag)
+                .keys(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|id| {
+                    let id = id?;
+                    program_response(deps, id, PROGRAMS.load(deps.storage, id)?)
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_json_binary(&programs)
+        }
+        QueryMsg::GetDeprecatedPrograms { start_after, limit } => {
+            let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT) as usize;
+            let start = start_after.map(Bound::exclusive);
+
+            let programs = DEPRECATED
+                .keys(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|id| {
+                    let id = id?;
+                    program_response(deps, id, PROGRAMS.load(deps.storage, id)?)
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_json_binary(&programs)
+        }
     }
 }
 
+fn program_response(deps: Deps, id: u64, program_config: Binary) -> StdResult<ProgramResponse> {
+    let deprecation_reason = DEPRECATED.may_load(deps.storage, id)?;
+
+    Ok(ProgramResponse {
+        id,
+        program_config,
+        is_deprecated: deprecation_reason.is_some(),
+        deprecation_reason,
+        tags: PROGRAM_TAGS.may_load(deps.storage, id)?.unwrap_or_default(),
+        description: PROGRAM_DESCRIPTIONS.may_load(deps.storage, id)?,
+    })
+}
+
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use cosmwasm_std::{
+        from_json,
+        testing::{message_info, mock_dependencies, mock_env, MockApi},
+        Addr, Binary,
+    };
+    use valence_program_registry_utils::{
+        ExecuteMsg, InstantiateMsg, MigrateMsg, ProgramResponse, QueryMsg,
+    };
+
+    use super::{execute, instantiate, migrate, query};
+
+    fn reserve_id(deps: cosmwasm_std::DepsMut, admin: &Addr) -> Result<u64, crate::ContractError> {
+        let res = execute(
+            deps,
+            mock_env(),
+            message_info(admin, &[]),
+            ExecuteMsg::ReserveId {},
+        )?;
+        let id: u64 = res.attributes[1].value.parse().unwrap();
+        Ok(id)
+    }
+
+    fn query_owner_ids(deps: cosmwasm_std::Deps, owner: &Addr) -> Vec<u64> {
+        let programs: Vec<ProgramResponse> = from_json(
+            query(
+                deps,
+                mock_env(),
+                QueryMsg::GetConfigsByOwner {
+                    owner: owner.to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        programs.into_iter().map(|p| p.id).collect()
+    }
+
+    #[test]
+    fn update_program_owner() {
+        let mut deps = mock_dependencies();
+        let api = MockApi::default();
+        let admin = api.addr_make("admin");
+        let owner1 = api.addr_make("owner1");
+        let owner2 = api.addr_make("owner2");
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            InstantiateMsg {
+                admin: admin.to_string(),
+            },
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let id = reserve_id(deps.as_mut(), &admin).unwrap();
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&admin, &[]),
+                ExecuteMsg::SaveProgram {
+                    id,
+                    owner: owner1.to_string(),
+                    program_config: Binary::default(),
+                    tags: vec![],
+                    description: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let update = |owner: Option<String>| ExecuteMsg::UpdateProgram {
+            id: 1,
+            program_config: Binary::default(),
+            owner,
+            tags: None,
+            description: None,
+        };
+
+        // Without an owner the program keeps its owner
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            update(None),
+        )
+        .unwrap();
+        assert_eq!(query_owner_ids(deps.as_ref(), &owner1), vec![1, 2]);
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            update(Some(owner2.to_string())),
+        )
+        .unwrap();
+        assert_eq!(query_owner_ids(deps.as_ref(), &owner1), vec![2]);
+        assert_eq!(query_owner_ids(deps.as_ref(), &owner2), vec![1]);
+
+        // Only the new owner can deprecate the program
+        let deprecate = ExecuteMsg::DeprecateProgram {
+            id: 1,
+            reason: "replaced".to_string(),
+        };
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner1, &[]),
+            deprecate.clone(),
+        )
+        .unwrap_err();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner2, &[]),
+            deprecate,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn migrate_backfills_owners() {
+        let mut deps = mock_dependencies();
+        let api = MockApi::default();
+        let admin = api.addr_make("admin");
+        let owner1 = api.addr_make("owner1");
+        let owner2 = api.addr_make("owner2");
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            InstantiateMsg {
+                admin: admin.to_string(),
+            },
+        )
+        .unwrap();
+
+        // Programs saved before programs were indexed by owner only have their config stored
+        for (id, owner) in [(1u64, &owner1), (2, &owner2), (3, &owner1)] {
+            let program_config = Binary::from(
+                format!(r#"{{"id":{id},"owner":"{owner}","libraries":{{}}}}"#).into_bytes(),
+            );
+            crate::state::PROGRAMS
+                .save(deps.as_mut().storage, id, &program_config)
+                .unwrap();
+        }
+        crate::state::LAST_ID
+            .save(deps.as_mut().storage, &3)
+            .unwrap();
+        assert!(query_owner_ids(deps.as_ref(), &owner1).is_empty());
+
+        let res = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg::BackfillOwners {
+                start_after: None,
+                limit: Some(2),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.attributes[1].value, "2");
+        assert_eq!(res.attributes[2].value, "2");
+        assert_eq!(query_owner_ids(deps.as_ref(), &owner1), vec![1]);
+        assert_eq!(query_owner_ids(deps.as_ref(), &owner2), vec![2]);
+
+        // Continue after the last processed program
+        let res = migrate(
+            deps.as_mut(),
+            mock_env(),
+            MigrateMsg::BackfillOwners {
+                start_after: Some(2),
+                limit: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(res.attributes[1].value, "1");
+        assert_eq!(res.attributes[2].value, "3");
+        assert_eq!(query_owner_ids(deps.as_ref(), &owner1), vec![1, 3]);
+
+        // The owner of a backfilled program can deprecate it
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner1, &[]),
+            ExecuteMsg::DeprecateProgram {
+                id: 3,
+                reason: "replaced".to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn get_configs_by_owner() {
+        let mut deps = mock_dependencies();
+        let api = MockApi::default();
+        let admin = api.addr_make("admin");
+        let owner1 = api.addr_make("owner1");
+        let owner2 = api.addr_make("owner2");
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            InstantiateMsg {
+                admin: admin.to_string(),
+            },
+        )
+        .unwrap();
+
+        for owner in [&owner1, &owner2, &owner1, &owner1] {
+            let id = reserve_id(deps.as_mut(), &admin).unwrap();
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&admin, &[]),
+                ExecuteMsg::SaveProgram {
+                    id,
+                    owner: owner.to_string(),
+                    program_config: Binary::from(id.to_be_bytes()),
+                    tags: vec![],
+                    description: None,
+                },
+            )
+            .unwrap();
+        }
+
+        // Only the admin can save programs
+        let id = reserve_id(deps.as_mut(), &admin).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner1, &[]),
+            ExecuteMsg::SaveProgram {
+                id,
+                owner: owner1.to_string(),
+                program_config: Binary::default(),
+                tags: vec![],
+                description: None,
+            },
+        )
+        .unwrap_err();
+
+        let query_ids = |owner: &Addr, start_after: Option<u64>, limit: Option<u32>| {
+            let programs: Vec<ProgramResponse> = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    QueryMsg::GetConfigsByOwner {
+                        owner: owner.to_string(),
+                        start_after,
+                        limit,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            programs
+                .into_iter()
+                .map(|program| {
+                    assert_eq!(
+                        program.program_config,
+                        Binary::from(program.id.to_be_bytes())
+                    );
+                    program.id
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(query_ids(&owner1, None, None), vec![1, 3, 4]);
+        assert_eq!(query_ids(&owner2, None, None), vec![2]);
+        assert_eq!(query_ids(&owner1, Some(1), Some(1)), vec![3]);
+        assert_eq!(query_ids(&owner1, Some(3), None), vec![4]);
+        assert!(query_ids(&admin, None, None).is_empty());
+    }
+
+    #[test]
+    fn deprecate_program() {
+        let mut deps = mock_dependencies();
+        let api = MockApi::default();
+        let admin = api.addr_make("admin");
+        let owner = api.addr_make("owner");
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            InstantiateMsg {
+                admin: admin.to_string(),
+            },
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            let id = reserve_id(deps.as_mut(), &admin).unwrap();
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&admin, &[]),
+                ExecuteMsg::SaveProgram {
+                    id,
+                    owner: owner.to_string(),
+                    program_config: Binary::default(),
+                    tags: vec![],
+                    description: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let deprecate = |id: u64| ExecuteMsg::DeprecateProgram {
+            id,
+            reason: format!("replaced by {}", id + 1),
+        };
+
+        // Only the owner of the program can deprecate it, not the admin
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            deprecate(1),
+        )
+        .unwrap_err();
+        // Program must exist
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            deprecate(3),
+        )
+        .unwrap_err();
+
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            deprecate(1),
+        )
+        .unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&owner, &[]),
+            deprecate(1),
+        )
+        .unwrap_err();
+
+        // Deprecated programs are still queryable
+        let program: ProgramResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::GetConfig { id: 1 }).unwrap())
+                .unwrap();
+        assert!(program.is_deprecated);
+        assert_eq!(
+            program.deprecation_reason,
+            Some("replaced by 2".to_string())
+        );
+
+        let program: ProgramResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::GetConfig { id: 2 }).unwrap())
+                .unwrap();
+        assert!(!program.is_deprecated);
+        assert_eq!(program.deprecation_reason, None);
+
+        let programs: Vec<ProgramResponse> = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                QueryMsg::GetDeprecatedPrograms {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(programs.len(), 1);
+        assert_eq!(programs[0].id, 1);
+    }
+
+    #[test]
+    fn get_programs_by_tag() {
+        let mut deps = mock_dependencies();
+        let api = MockApi::default();
+        let admin = api.addr_make("admin");
+        let owner = api.addr_make("owner");
+
+        instantiate(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            InstantiateMsg {
+                admin: admin.to_string(),
+            },
+        )
+        .unwrap();
+
+        let save = |id: u64, tags: Vec<&str>| ExecuteMsg::SaveProgram {
+            id,
+            owner: owner.to_string(),
+            program_config: Binary::default(),
+            tags: tags.into_iter().map(String::from).collect(),
+            description: Some(format!("program {id}")),
+        };
+
+        for tags in [vec!["dex", "osmosis"], vec!["lending"], vec!["dex"]] {
+            let id = reserve_id(deps.as_mut(), &admin).unwrap();
+            execute(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&admin, &[]),
+                save(id, tags),
+            )
+            .unwrap();
+        }
+
+        // Tags are validated
+        let id = reserve_id(deps.as_mut(), &admin).unwrap();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            save(id, vec![""]),
+        )
+        .unwrap_err();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            save(id, vec!["a-tag-that-is-longer-than-32-chars"]),
+        )
+        .unwrap_err();
+        let too_many: Vec<String> = (0..11).map(|i| format!("tag{i}")).collect();
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            save(id, too_many.iter().map(String::as_str).collect()),
+        )
+        .unwrap_err();
+
+        let query_ids = |deps: cosmwasm_std::Deps, tag: &str, start_after: Option<u64>| {
+            let programs: Vec<ProgramResponse> = from_json(
+                query(
+                    deps,
+                    mock_env(),
+                    QueryMsg::GetProgramsByTag {
+                        tag: tag.to_string(),
+                        start_after,
+                        limit: None,
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            programs.into_iter().map(|p| p.id).collect::<Vec<u64>>()
+        };
+
+        assert_eq!(query_ids(deps.as_ref(), "dex", None), vec![1, 3]);
+        assert_eq!(query_ids(deps.as_ref(), "dex", Some(1)), vec![3]);
+        assert_eq!(query_ids(deps.as_ref(), "lending", None), vec![2]);
+        assert!(query_ids(deps.as_ref(), "unknown", None).is_empty());
+
+        // Updating the tags replaces the index entries
+        execute(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&admin, &[]),
+            ExecuteMsg::UpdateProgram {
+                id: 1,
+                program_config: Binary::default(),
+                owner: None,
+                tags: Some(vec!["lending".to_string()]),
+                description: None,
+            },
+        )
+        .unwrap();
+        assert_eq!(query_ids(deps.as_ref(), "dex", None), vec![3]);
+        assert_eq!(query_ids(deps.as_ref(), "lending", None), vec![1, 2]);
+
+        let program: ProgramResponse =
+            from_json(query(deps.as_ref(), mock_env(), QueryMsg::GetConfig { id: 1 }).unwrap())
+                .unwrap();
+        assert_eq!(program.tags, vec!["lending".to_string()]);
+        assert_eq!(program.description, Some("program 1".to_string()));
+    }
+}
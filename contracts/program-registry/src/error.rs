@@ -14,4 +14,12 @@ pub enum ContractError {
     ProgramAlreadyExists(u64),
     #[error("Program doesn't exists with id {0}")]
     ProgramDoesntExists(u64),
+    #[error("Only the owner of the program can deprecate program with id {0}")]
+    NotProgramOwner(u64),
+    #[error("Program is already deprecated with id {0}")]
+    ProgramAlreadyDeprecated(u64),
+    #[error("Tag '{0}' must be between 1 and 32 characters")]
+    InvalidTag(String),
+    #[error("A program can have at most 10 tags, got {0}")]
+    TooManyTags(usize),
 }
@@ -1,6 +1,18 @@
-use cosmwasm_std::Binary;
+use cosmwasm_std::{Addr, Binary};
 use cw_storage_plus::{Item, Map};
 
 pub const LAST_ID: Item<u64> = Item::new("id");
 pub const PROGRAMS: Map<u64, Binary> = Map::new("programs");
 pub const PROGRAMS_BACKUP: Map<u64, Binary> = Map::new("programs_backups");
+/// Index of the programs of each owner
+pub const OWNER_PROGRAMS: Map<(Addr, u64), bool> = Map::new("owner_programs");
+/// Owner of each program
+pub const PROGRAM_OWNERS: Map<u64, Addr> = Map::new("program_owners");
+/// Deprecated programs with the reason of the deprecation
+pub const DEPRECATED: Map<u64, String> = Map::new("deprecated");
+/// Tags of each program
+pub const PROGRAM_TAGS: Map<u64, Vec<String>> = Map::new("program_tags");
+/// Description of each program
+pub const PROGRAM_DESCRIPTIONS: Map<u64, String> = Map::new("program_descriptions");
+/// Index of the programs with each tag
+pub const TAG_INDEX: Map<(String, u64), bool> = Map::new("tag_index");
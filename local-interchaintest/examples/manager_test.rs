@@ -28,7 +28,7 @@ use valence_program_manager::{
     account::{AccountInfo, AccountType},
     library::{LibraryConfig, LibraryConfigUpdate, LibraryInfo},
     program_config_builder::ProgramConfigBuilder,
-    program_migration::{FundsTransfer, ProgramConfigMigrate},
+    program_migration::{FundsTransfer, MigrationStrategy, ProgramConfigMigrate},
     program_update::{AuthorizationInfoUpdate, ProgramConfigUpdate},
 };
 use valence_splitter_library::msg::{UncheckedSplitAmount, UncheckedSplitConfig};
@@ -279,7 +279,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         DEFAULT_KEY,
         &serde_json::to_string(
             &valence_processor_utils::msg::ExecuteMsg::PermissionlessAction(
-                valence_processor_utils::msg::PermissionlessMsg::Tick {},
+                valence_processor_utils::msg::PermissionlessMsg::Tick { simulate: false },
             ),
         )
         .unwrap(),
@@ -414,11 +414,14 @@ fn main() -> Result<(), Box<dyn Error>> {
         },
     }];
 
-    let res = use_manager_migrate(ProgramConfigMigrate {
-        old_id: program_config.id,
-        new_program: new_program_config,
-        transfer_funds: transfers,
-    })
+    let res = use_manager_migrate(
+        ProgramConfigMigrate {
+            old_id: program_config.id,
+            new_program: new_program_config,
+            transfer_funds: transfers,
+        },
+        MigrationStrategy::Sequential,
+    )
     .unwrap();
 
     for instruction in res.instructions.iter() {
@@ -457,7 +460,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         DEFAULT_KEY,
         &serde_json::to_string(
             &valence_processor_utils::msg::ExecuteMsg::PermissionlessAction(
-                valence_processor_utils::msg::PermissionlessMsg::Tick {},
+                valence_processor_utils::msg::PermissionlessMsg::Tick { simulate: false },
             ),
         )
         .unwrap(),
@@ -321,7 +321,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         DEFAULT_KEY,
         &serde_json::to_string(
             &valence_processor_utils::msg::ExecuteMsg::PermissionlessAction(
-                valence_processor_utils::msg::PermissionlessMsg::Tick {},
+                valence_processor_utils::msg::PermissionlessMsg::Tick { simulate: false },
             ),
         )?,
         &format!(
@@ -424,7 +424,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         DEFAULT_KEY,
         &serde_json::to_string(
             &valence_processor_utils::msg::ExecuteMsg::PermissionlessAction(
-                valence_processor_utils::msg::PermissionlessMsg::Tick {},
+                valence_processor_utils::msg::PermissionlessMsg::Tick { simulate: false },
             ),
         )?,
         &format!(
@@ -4,12 +4,12 @@ use std::{
     time::{Duration, SystemTime},
 };
 
-use cosmwasm_std::{Binary, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Binary, CosmosMsg, Timestamp, Uint128, WasmMsg};
 use cosmwasm_std_old::Uint64;
 use cw_utils::Expiration;
 use local_interchaintest::utils::{
     authorization::set_up_authorization_and_processor,
-    polytone::salt_for_proxy,
+    polytone::{salt_for_proxy, wait_for_polytone_callback},
     processor::{get_processor_queue_items, tick_processor},
     relayer::restart_relayer,
     GAS_FLAGS, LOCAL_CODE_ID_CACHE_PATH_JUNO, LOCAL_CODE_ID_CACHE_PATH_NEUTRON, LOGS_FILE_PATH,
@@ -36,7 +36,7 @@ use valence_authorization_utils::{
         PermissionTypeInfo, Priority, Subroutine,
     },
     authorization_message::{Message, MessageDetails, MessageType},
-    callback::{ExecutionResult, ProcessorCallbackInfo},
+    callback::ExecutionResult,
     domain::{Connector, Domain, ExternalDomain, PolytoneProxyState},
     function::AtomicFunction,
     msg::{
@@ -45,7 +45,7 @@ use valence_authorization_utils::{
     },
 };
 
-use valence_library_utils::LibraryAccountType;
+use valence_library_utils::{ica::build_polytone_execute, LibraryAccountType};
 use valence_processor_utils::{
     callback::{PendingPolytoneCallbackInfo, PolytoneCallbackState},
     msg::PolytoneContracts,
@@ -336,6 +336,7 @@ fn main() -> Result<(), Box<dyn Error>> {
             polytone_note_address: polytone_note_on_juno_address.clone(),
             timeout_seconds: TIMEOUT_SECONDS,
         }),
+        circuit_breaker: None,
     };
 
     // Before instantiating the processor and adding the external domain we are going to stop the relayer to force timeouts
@@ -567,6 +568,64 @@ fn main() -> Result<(), Box<dyn Error>> {
     assert_eq!(remote_address, predicted_proxy_address_on_neutron);
     info!("Predicted and created addresses match!");
 
+    // Any account can execute messages through the note, the first execution creates its proxy on the remote domain
+    info!("Executing through the polytone note with a user account...");
+    let CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) = build_polytone_execute(
+        &Addr::unchecked(polytone_note_on_neutron_address.clone()),
+        vec![],
+        None,
+        600,
+    )
+    .unwrap() else {
+        panic!("Expected a wasm execute message");
+    };
+
+    contract_execute(
+        test_ctx
+            .get_request_builder()
+            .get_request_builder(NEUTRON_CHAIN_NAME),
+        &polytone_note_on_neutron_address,
+        USER_KEY_1,
+        &String::from_utf8(msg.to_vec()).unwrap(),
+        GAS_FLAGS,
+    )
+    .unwrap();
+
+    info!("Waiting for the proxy of the user to be created...");
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        let remote_address: Option<String> = serde_json::from_value(
+            contract_query(
+                test_ctx
+                    .get_request_builder()
+                    .get_request_builder(NEUTRON_CHAIN_NAME),
+                &polytone_note_on_neutron_address,
+                &serde_json::to_string(&polytone_note::msg::QueryMsg::RemoteAddress {
+                    local_address: NEUTRON_USER_ADDRESS_1.to_string(),
+                })
+                .unwrap(),
+            )["data"]
+                .clone(),
+        )
+        .unwrap();
+
+        if let Some(remote_address) = remote_address {
+            info!("Proxy of the user created: {remote_address}");
+            break;
+        }
+
+        if attempts % 5 == 0 {
+            // Sometimes the relayer doesn't pick up the changes, so we restart it
+            restart_relayer(&mut test_ctx);
+        }
+
+        if attempts > MAX_ATTEMPTS {
+            panic!("Maximum number of attempts reached. Cancelling execution.");
+        }
+        std::thread::sleep(Duration::from_secs(15));
+    }
+
     // Let's test the function creation and execution / retrying
 
     // First we are going to try to add an authorization with an function for an invalid domain, which should fail
@@ -594,6 +653,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         subroutine: Subroutine::Atomic(AtomicSubroutine {
             functions: vec![function.clone()],
             retry_logic: None,
+            execution_time_limit: None,
         }),
         priority: None,
     };
@@ -634,6 +694,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     authorization.subroutine = Subroutine::Atomic(AtomicSubroutine {
         functions: vec![function.clone()],
         retry_logic: None,
+        execution_time_limit: None,
     });
 
     contract_execute(
@@ -1115,48 +1176,32 @@ fn verify_authorization_execution_result(
     expected_result: &ExecutionResult,
 ) {
     let mut attempts = 0;
-    loop {
+    let callback_info = loop {
         attempts += 1;
-        let callback_info: ProcessorCallbackInfo = serde_json::from_value(
-            contract_query(
-                test_ctx
-                    .get_request_builder()
-                    .get_request_builder(NEUTRON_CHAIN_NAME),
-                authorization_address,
-                &serde_json::to_string(
-                    &valence_authorization_utils::msg::QueryMsg::ProcessorCallback { execution_id },
-                )
-                .unwrap(),
-            )["data"]
-                .clone(),
-        )
-        .unwrap();
-
-        let result_matches = match (expected_result, &callback_info.execution_result) {
-            (ExecutionResult::Rejected(_), ExecutionResult::Rejected(_)) => true,
-            _ => callback_info.execution_result.eq(expected_result),
-        };
-
-        if result_matches {
-            info!("Target execution result reached!");
-            break;
-        } else {
-            info!(
-                "Waiting for the right execution result, current execution result: {:?}",
-                callback_info.execution_result
-            );
+        match wait_for_polytone_callback(
+            test_ctx,
+            NEUTRON_CHAIN_NAME,
+            authorization_address,
+            execution_id,
+            5 * 15,
+        ) {
+            Ok(callback_info) => break callback_info,
+            Err(err) => info!("{err}"),
         }
 
-        if attempts % 5 == 0 {
-            // Sometimes the relayer doesn't pick up the changes, so we restart it
-            restart_relayer(test_ctx);
-        }
+        // Sometimes the relayer doesn't pick up the changes, so we restart it
+        restart_relayer(test_ctx);
 
-        if attempts > MAX_ATTEMPTS {
+        if attempts > MAX_ATTEMPTS / 5 {
             panic!("Maximum number of attempts reached. Cancelling execution.");
         }
-        std::thread::sleep(Duration::from_secs(15));
+    };
+
+    match (expected_result, &callback_info.execution_result) {
+        (ExecutionResult::Rejected(_), ExecutionResult::Rejected(_)) => {}
+        (expected, actual) => assert_eq!(actual, expected),
     }
+    info!("Target execution result reached!");
 }
 
 fn get_processor_pending_polytone_callback(
@@ -64,6 +64,7 @@ pub fn set_up_authorization_and_processor(
     let processor_instantiate_msg = valence_processor_utils::msg::InstantiateMsg {
         authorization_contract: predicted_authorization_contract_address.clone(),
         polytone_contracts: None,
+        circuit_breaker: None,
     };
 
     let processor_on_main_domain = contract_instantiate(
@@ -400,6 +401,7 @@ pub fn set_up_external_domain_with_polytone(
             polytone_note_address: polytone_note_on_external_domain_address.clone(),
             timeout_seconds,
         }),
+        circuit_breaker: None,
     };
 
     let processor_code_id_on_external_domain = test_ctx
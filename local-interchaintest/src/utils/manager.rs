@@ -3,11 +3,11 @@ use std::{collections::HashMap, env, error::Error, fs};
 use localic_std::modules::cosmwasm::contract_instantiate;
 use localic_utils::{utils::test_context::TestContext, DEFAULT_KEY, NEUTRON_CHAIN_NAME};
 use valence_program_manager::{
-    config::{ChainInfo, GLOBAL_CONFIG},
+    config::{default_max_retries, ChainInfo, EndpointSelector, GLOBAL_CONFIG},
     error::ManagerResult,
     init_program, migrate_program,
     program_config::ProgramConfig,
-    program_migration::{MigrateResponse, ProgramConfigMigrate},
+    program_migration::{MigrateResponse, MigrationStrategy, ProgramConfigMigrate},
     program_update::{ProgramConfigUpdate, UpdateResponse},
     update_program,
 };
@@ -285,7 +285,9 @@ fn get_chain_infos(chains_file_path: &str) -> HashMap<String, ChainInfo> {
             ChainInfo {
                 name: chain_name.to_string(),
                 rpc: rpc.to_string(),
-                grpc: format!("http://{}", grpc),
+                endpoints: vec![format!("http://{}", grpc)],
+                endpoint_selector: EndpointSelector::default(),
+                max_retries: default_max_retries(),
                 prefix: prefix.to_string(),
                 gas_price: gas_price.to_string(),
                 gas_denom: gas_denom.to_string(),
@@ -332,12 +334,13 @@ pub fn use_manager_update(
 
 pub fn use_manager_migrate(
     program_config_migrate: ProgramConfigMigrate,
+    strategy: MigrationStrategy,
 ) -> ManagerResult<MigrateResponse> {
     let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()
         .unwrap();
-    rt.block_on(migrate_program(program_config_migrate))
+    rt.block_on(migrate_program(program_config_migrate, strategy))
 }
 
 pub fn get_global_config(
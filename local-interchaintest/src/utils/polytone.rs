@@ -1,10 +1,18 @@
-use std::{collections::HashMap, error::Error, time::Duration};
+use std::{
+    collections::HashMap,
+    error::Error,
+    time::{Duration, Instant},
+};
 
 use cosmwasm_std_old::Uint64;
-use localic_std::{modules::cosmwasm::CosmWasm, relayer::Relayer};
+use localic_std::{
+    modules::cosmwasm::{contract_query, CosmWasm},
+    relayer::Relayer,
+};
 use localic_utils::{utils::test_context::TestContext, DEFAULT_KEY};
 use log::info;
 use sha2::{Digest, Sha512};
+use valence_authorization_utils::callback::{ExecutionResult, ProcessorCallbackInfo};
 use valence_program_manager::bridge::{Bridge, PolytoneSingleChainInfo};
 
 use crate::utils::manager::{
@@ -241,3 +249,42 @@ pub fn setup_polytone(
 
     Ok(())
 }
+
+/// polls the authorization contract until the processor callback for `execution_id` has been
+/// delivered through polytone (the execution is no longer in process).
+/// fails if it didn't arrive after `timeout_secs` seconds.
+pub fn wait_for_polytone_callback(
+    test_ctx: &mut TestContext,
+    chain_name: &str,
+    authorization_addr: &str,
+    execution_id: u64,
+    timeout_secs: u64,
+) -> Result<ProcessorCallbackInfo, Box<dyn Error>> {
+    let start = Instant::now();
+    loop {
+        let callback_info: ProcessorCallbackInfo = serde_json::from_value(
+            contract_query(
+                test_ctx
+                    .get_request_builder()
+                    .get_request_builder(chain_name),
+                authorization_addr,
+                &serde_json::to_string(
+                    &valence_authorization_utils::msg::QueryMsg::ProcessorCallback { execution_id },
+                )?,
+            )["data"]
+                .clone(),
+        )?;
+
+        if callback_info.execution_result != ExecutionResult::InProcess {
+            return Ok(callback_info);
+        }
+
+        if start.elapsed() > Duration::from_secs(timeout_secs) {
+            return Err(format!(
+                "Callback for execution {execution_id} not received after {timeout_secs} seconds"
+            )
+            .into());
+        }
+        std::thread::sleep(Duration::from_secs(5));
+    }
+}
@@ -51,7 +51,7 @@ pub fn tick_processor(
         key,
         &serde_json::to_string(
             &valence_processor_utils::msg::ExecuteMsg::PermissionlessAction(
-                valence_processor_utils::msg::PermissionlessMsg::Tick {},
+                valence_processor_utils::msg::PermissionlessMsg::Tick { simulate: false },
             ),
         )
         .unwrap(),
@@ -40,6 +40,20 @@ pub enum ExecuteMsg {
 pub enum QueryMsg {
     #[returns(Vec<String>)]
     ListApprovedLibraries {}, // Get list of approved libraries
+    #[returns(Vec<AccountCapability>)]
+    GetCapabilities {}, // Get the list of actions this account type supports
+}
+
+/// Actions an account can support, so callers can check that an account type
+/// supports an action before they try to execute it.
+#[cw_serde]
+pub enum AccountCapability {
+    ExecuteMsgs,
+    ExecuteSubmsgs,
+    ApproveLibrary,
+    RevokeLibrary,
+    SetSpendingLimit,
+    CreateSubAccount,
 }
 
 #[cw_serde]
@@ -1,6 +1,6 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::DepsMut;
-use valence_library_utils::error::LibraryError;
+use valence_library_utils::error::{LibraryError, ERR_INVALID_DENOM, ERR_INVALID_POOL};
 
 pub mod astroport_cw20_lp_token;
 pub mod astroport_native_lp_token;
@@ -17,14 +17,20 @@ pub trait AssetTrait {
 impl AssetTrait for astroport_native_lp_token::Asset {
     fn as_coin(&self) -> Result<cosmwasm_std::Coin, LibraryError> {
         self.as_coin()
-            .map_err(|error| LibraryError::ExecutionError(error.to_string()))
+            .map_err(|error| LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: error.to_string(),
+            })
     }
 }
 
 impl AssetTrait for astroport_cw20_lp_token::Asset {
     fn as_coin(&self) -> Result<cosmwasm_std::Coin, LibraryError> {
         self.to_coin()
-            .map_err(|error| LibraryError::ExecutionError(error.to_string()))
+            .map_err(|error| LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: error.to_string(),
+            })
     }
 }
 
@@ -67,7 +73,10 @@ pub fn get_pool_asset_amounts(
     for asset in assets {
         let coin = asset
             .as_coin()
-            .map_err(|error| LibraryError::ExecutionError(error.to_string()))?;
+            .map_err(|error| LibraryError::ExecutionError {
+                code: ERR_INVALID_DENOM,
+                msg: error.to_string(),
+            })?;
 
         if coin.denom == asset1_denom {
             asset1_balance = coin.amount.u128();
@@ -77,9 +86,10 @@ pub fn get_pool_asset_amounts(
     }
 
     if asset1_balance == 0 || asset2_balance == 0 {
-        return Err(LibraryError::ExecutionError(
-            "All pool assets must be non-zero".to_string(),
-        ));
+        return Err(LibraryError::ExecutionError {
+            code: ERR_INVALID_POOL,
+            msg: "All pool assets must be non-zero".to_string(),
+        });
     }
 
     Ok((asset1_balance, asset2_balance))
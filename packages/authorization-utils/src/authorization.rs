@@ -1,6 +1,6 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, Api, BlockInfo, Uint128};
-use cw_utils::Expiration;
+use cw_utils::{Duration, Expiration};
 
 use crate::function::{AtomicFunction, Function, NonAtomicFunction, RetryLogic};
 
@@ -143,6 +143,13 @@ impl Subroutine {
             }
         }
     }
+
+    pub fn get_execution_time_limit(&self) -> Option<u64> {
+        match self {
+            Subroutine::Atomic(config) => config.execution_time_limit,
+            Subroutine::NonAtomic(config) => config.execution_time_limit,
+        }
+    }
 }
 
 #[cw_serde]
@@ -150,11 +157,15 @@ pub struct AtomicSubroutine {
     pub functions: Vec<AtomicFunction>,
     // Used for Atomic batches, if we don't specify retry logic then the functions won't be retried.
     pub retry_logic: Option<RetryLogic>,
+    // Amount of seconds the processor has to execute the subroutine once the messages are sent, if it's not executed by then it will expire
+    pub execution_time_limit: Option<u64>,
 }
 
 #[cw_serde]
 pub struct NonAtomicSubroutine {
     pub functions: Vec<NonAtomicFunction>,
+    // Amount of seconds the processor has to execute the subroutine once the messages are sent, if it's not executed by then it will expire
+    pub execution_time_limit: Option<u64>,
 }
 
 #[cw_serde]
@@ -170,3 +181,26 @@ pub enum AuthorizationState {
     Enabled,
     Disabled,
 }
+
+#[cw_serde]
+#[derive(Copy, Eq, PartialOrd, Ord)]
+// Roles that can be assigned to addresses, each role includes the permissions of the roles before it
+pub enum Role {
+    Observer,
+    Operator,
+    Strategist,
+    Admin,
+}
+
+#[cw_serde]
+// Additional restrictions that can be applied to an authorization on top of its mode
+pub enum AuthorizationConstraint {
+    // Caps the total amount of a denom that can be sent in BankMsg::Send messages during each window.
+    // Only literal BankMsg::Send messages inside the processor messages are counted, so every message of the authorization
+    // must contain at least one, and funds moved in any other way (e.g. by a library function) are not limited.
+    SpendingLimit {
+        denom: String,
+        max_per_window: Uint128,
+        window_duration: Duration,
+    },
+}
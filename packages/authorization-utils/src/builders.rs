@@ -39,6 +39,7 @@ impl AuthorizationBuilder {
             subroutine: Subroutine::Atomic(AtomicSubroutine {
                 functions: vec![],
                 retry_logic: None,
+                execution_time_limit: None,
             }),
             priority: None,
         }
@@ -95,6 +96,7 @@ impl AuthorizationBuilder {
 pub struct AtomicSubroutineBuilder {
     functions: Vec<AtomicFunction>,
     retry_logic: Option<RetryLogic>,
+    execution_time_limit: Option<u64>,
 }
 
 impl Default for AtomicSubroutineBuilder {
@@ -108,6 +110,7 @@ impl AtomicSubroutineBuilder {
         AtomicSubroutineBuilder {
             functions: vec![],
             retry_logic: None,
+            execution_time_limit: None,
         }
     }
 
@@ -121,16 +124,23 @@ impl AtomicSubroutineBuilder {
         self
     }
 
+    pub fn with_execution_time_limit(mut self, execution_time_limit: u64) -> Self {
+        self.execution_time_limit = Some(execution_time_limit);
+        self
+    }
+
     pub fn build(self) -> Subroutine {
         Subroutine::Atomic(AtomicSubroutine {
             functions: self.functions,
             retry_logic: self.retry_logic,
+            execution_time_limit: self.execution_time_limit,
         })
     }
 }
 
 pub struct NonAtomicSubroutineBuilder {
     functions: Vec<NonAtomicFunction>,
+    execution_time_limit: Option<u64>,
 }
 
 impl Default for NonAtomicSubroutineBuilder {
@@ -141,7 +151,10 @@ impl Default for NonAtomicSubroutineBuilder {
 
 impl NonAtomicSubroutineBuilder {
     pub fn new() -> Self {
-        NonAtomicSubroutineBuilder { functions: vec![] }
+        NonAtomicSubroutineBuilder {
+            functions: vec![],
+            execution_time_limit: None,
+        }
     }
 
     pub fn with_function(mut self, function: NonAtomicFunction) -> Self {
@@ -149,9 +162,15 @@ impl NonAtomicSubroutineBuilder {
         self
     }
 
+    pub fn with_execution_time_limit(mut self, execution_time_limit: u64) -> Self {
+        self.execution_time_limit = Some(execution_time_limit);
+        self
+    }
+
     pub fn build(self) -> Subroutine {
         Subroutine::NonAtomic(NonAtomicSubroutine {
             functions: self.functions,
+            execution_time_limit: self.execution_time_limit,
         })
     }
 }
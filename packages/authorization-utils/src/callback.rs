@@ -50,6 +50,9 @@ pub enum ExecutionResult {
     // true - retriable
     // false - not retriable
     Timeout(bool),
+    // Expired - happens when the processor tries to execute the batch after its expiration time
+    // Indicates how many functions were executed before the batch expired (always 0 for atomic batches)
+    Expired(usize),
     // Unexpected error that should never happen but we'll store it here if it ever does
     UnexpectedError(String),
 }
@@ -4,7 +4,7 @@ use cw_ownable::{cw_ownable_execute, cw_ownable_query, Expiration};
 use valence_polytone_utils::polytone::CallbackMessage;
 
 use crate::{
-    authorization::{Authorization, AuthorizationInfo, Priority},
+    authorization::{Authorization, AuthorizationConstraint, AuthorizationInfo, Priority, Role},
     authorization_message::MessageType,
     callback::{ExecutionResult, ProcessorCallbackInfo},
     domain::{Domain, ExecutionEnvironment, ExternalDomain},
@@ -99,6 +99,10 @@ pub enum ExecuteMsg {
 pub enum OwnerMsg {
     AddSubOwner { sub_owner: String },
     RemoveSubOwner { sub_owner: String },
+    AssignRole { addr: String, role: Role },
+    RevokeRole { addr: String },
+    // Set the minimum role needed to execute an authorization, if None is passed anyone allowed by the authorization mode can execute it
+    SetMinRequiredRole { label: String, role: Option<Role> },
 }
 
 #[cw_serde]
@@ -154,6 +158,16 @@ pub enum PermissionedMsg {
     ResumeProcessor {
         domain: Domain,
     },
+    // Reset the circuit breaker of a library in the processor of any domain
+    ResetCircuitBreaker {
+        domain: Domain,
+        library: String,
+    },
+    // Set the constraint of an authorization, if None is passed the current constraint is removed
+    SetAuthorizationConstraint {
+        label: String,
+        constraint: Option<AuthorizationConstraint>,
+    },
 }
 
 #[cw_serde]
@@ -260,4 +274,10 @@ pub enum QueryMsg {
     },
     #[returns(ProcessorCallbackInfo)]
     ProcessorCallback { execution_id: u64 },
+    #[returns(Option<Role>)]
+    Role { addr: String },
+    #[returns(Option<Role>)]
+    MinRequiredRole { label: String },
+    #[returns(Option<AuthorizationConstraint>)]
+    AuthorizationConstraint { label: String },
 }
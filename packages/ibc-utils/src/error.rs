@@ -0,0 +1,18 @@
+use cosmwasm_std::{OverflowError, StdError, Uint128};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum IbcUtilsError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Overflow(#[from] OverflowError),
+
+    #[error("Insufficient balance to cover for IBC fees '{denom}' in sender account (required: {required}, available: {available}).")]
+    InsufficientFeeBalance {
+        denom: String,
+        required: Uint128,
+        available: Uint128,
+    },
+}
@@ -15,30 +15,26 @@ pub fn ibc_send_message(
     denom: String,
     amount: u128,
     memo: String,
-    timeout_seconds: Option<u64>,
+    timeout: Option<IbcTimeout>,
     denom_to_pfm_map: BTreeMap<String, PacketForwardMiddlewareConfig>,
 ) -> StdResult<CosmosMsg> {
+    let timeout = timeout.unwrap_or_else(|| {
+        IbcTimeout::with_timestamp(env.block.time.plus_seconds(DEFAULT_TIMEOUT_TIMESTAMP))
+    });
+
     let msg = match denom_to_pfm_map.get(&denom) {
         None => CosmosMsg::Ibc(cosmwasm_std::IbcMsg::Transfer {
             channel_id: channel,
             to_address: to,
             amount: coin(amount, denom),
-            timeout: IbcTimeout::with_timestamp(
-                env.block
-                    .time
-                    .plus_seconds(timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_TIMESTAMP)),
-            ),
+            timeout,
             memo: Some(memo),
         }),
         Some(pfm_config) => CosmosMsg::Ibc(cosmwasm_std::IbcMsg::Transfer {
             channel_id: pfm_config.local_to_hop_chain_channel_id.to_string(),
             to_address: pfm_config.hop_chain_receiver_address.to_string(),
             amount: coin(amount, denom),
-            timeout: IbcTimeout::with_timestamp(
-                env.block
-                    .time
-                    .plus_seconds(timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_TIMESTAMP)),
-            ),
+            timeout,
             memo: Some(to_json_string(&PacketMetadata {
                 forward: Some(ForwardMetadata {
                     receiver: to.clone(),
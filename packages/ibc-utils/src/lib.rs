@@ -1,3 +1,5 @@
+pub mod error;
+pub mod tracking;
 pub mod types;
 
 #[cfg(feature = "generic")]
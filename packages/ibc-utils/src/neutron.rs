@@ -3,7 +3,7 @@ use std::collections::BTreeMap;
 use cosmos_sdk_proto::cosmos::base::v1beta1::Coin;
 use cosmos_sdk_proto::traits::MessageExt;
 use cosmwasm_std::{
-    to_json_string, Addr, Binary, CosmosMsg, DepsMut, Env, StdError, StdResult, Uint128,
+    to_json_string, Addr, Binary, CosmosMsg, DepsMut, Env, IbcTimeout, StdError, Uint128,
 };
 use cw_denom::CheckedDenom;
 use neutron_sdk::{
@@ -11,6 +11,7 @@ use neutron_sdk::{
     query::min_ibc_fee::query_min_ibc_fee,
 };
 
+use crate::error::IbcUtilsError;
 use crate::types::{ForwardMetadata, PacketForwardMiddlewareConfig, PacketMetadata};
 
 // Default timeout for IbcTransfer is 600 seconds
@@ -27,9 +28,23 @@ pub fn ibc_send_message(
     denom: &CheckedDenom,
     amount: u128,
     memo: String,
-    timeout_seconds: Option<u64>,
+    timeout: Option<IbcTimeout>,
     denom_to_pfm_map: BTreeMap<String, PacketForwardMiddlewareConfig>,
-) -> StdResult<CosmosMsg> {
+) -> Result<CosmosMsg, IbcUtilsError> {
+    // Neutron transfers are only built with a timeout timestamp
+    let timeout_timestamp = match timeout {
+        Some(timeout) => match (timeout.block(), timeout.timestamp()) {
+            (None, Some(timestamp)) => timestamp,
+            _ => {
+                return Err(StdError::generic_err(
+                    "Only timestamp based timeouts are supported for Neutron IBC transfers.",
+                )
+                .into())
+            }
+        },
+        None => env.block.time.plus_seconds(DEFAULT_TIMEOUT_TIMESTAMP),
+    };
+
     // contract must pay for relaying of acknowledgements
     // See more info here: https://docs.neutron.org/neutron/feerefunder/overview
     let ibc_fee = min_ntrn_ibc_fee(
@@ -50,19 +65,21 @@ pub fn ibc_send_message(
             // Check that the balance is sufficient to cover the fees
             let amount_plus_fee = total_fee.checked_add(amount.into())?;
             if sender_ntrn_balance < amount_plus_fee {
-                return Err(StdError::generic_err(format!(
-                    "Insufficient balance to cover for IBC fees '{}' in sender account (required: {}, available: {}).",
-                    NTRN_DENOM, amount_plus_fee, sender_ntrn_balance,
-                )));
+                return Err(IbcUtilsError::InsufficientFeeBalance {
+                    denom: NTRN_DENOM.to_string(),
+                    required: amount_plus_fee,
+                    available: sender_ntrn_balance,
+                });
             }
             Uint128::from(amount)
         }
     } else {
         if sender_ntrn_balance < total_fee {
-            return Err(StdError::generic_err(format!(
-                "Insufficient balance to cover for IBC fees '{}' in sender account (required: {}, available: {}).",
-                NTRN_DENOM, total_fee, sender_ntrn_balance,
-            )));
+            return Err(IbcUtilsError::InsufficientFeeBalance {
+                denom: NTRN_DENOM.to_string(),
+                required: total_fee,
+                available: sender_ntrn_balance,
+            });
         }
         amount.into()
     };
@@ -80,11 +97,7 @@ pub fn ibc_send_message(
             receiver: to.clone(),
             token: Some(coin),
             timeout_height: None,
-            timeout_timestamp: env
-                .block
-                .time
-                .plus_seconds(timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_TIMESTAMP))
-                .nanos(),
+            timeout_timestamp: timeout_timestamp.nanos(),
             memo,
             fee: Some(get_transfer_fee(ibc_fee)),
         },
@@ -96,11 +109,7 @@ pub fn ibc_send_message(
                 receiver: pfm_config.hop_chain_receiver_address.to_string(),
                 token: Some(coin),
                 timeout_height: None,
-                timeout_timestamp: env
-                    .block
-                    .time
-                    .plus_seconds(timeout_seconds.unwrap_or(DEFAULT_TIMEOUT_TIMESTAMP))
-                    .nanos(),
+                timeout_timestamp: timeout_timestamp.nanos(),
                 memo: to_json_string(&PacketMetadata {
                     forward: Some(ForwardMetadata {
                         receiver: to.clone(),
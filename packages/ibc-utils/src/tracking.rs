@@ -0,0 +1,160 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Order, StdError, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Map};
+
+const MAX_PAGE_LIMIT: u32 = 250;
+
+// Packets that were sent and are waiting for an acknowledgement or a timeout, by sequence number
+pub const PENDING_PACKETS: Map<u64, PendingPacket> = Map::new("pending_packets");
+
+#[cw_serde]
+pub struct PendingPacket {
+    pub denom: String,
+    pub amount: Uint128,
+    pub recipient: String,
+    pub submitted_at_block: u64,
+    pub timeout_timestamp: Timestamp,
+}
+
+/// Start tracking a packet that was just sent, the sequence number is returned in the reply of the transfer
+pub fn track_outgoing_packet(
+    storage: &mut dyn Storage,
+    sequence: u64,
+    packet: &PendingPacket,
+) -> StdResult<()> {
+    if PENDING_PACKETS.has(storage, sequence) {
+        return Err(StdError::generic_err(format!(
+            "Packet with sequence {} is already tracked",
+            sequence
+        )));
+    }
+
+    PENDING_PACKETS.save(storage, sequence, packet)
+}
+
+/// The packet was acknowledged by the counterparty chain, stop tracking it and return it
+pub fn on_packet_acknowledged(
+    storage: &mut dyn Storage,
+    sequence: u64,
+) -> StdResult<PendingPacket> {
+    remove_pending_packet(storage, sequence)
+}
+
+/// The packet timed out and the funds are returned to the sender, stop tracking it and return it
+pub fn on_packet_timed_out(storage: &mut dyn Storage, sequence: u64) -> StdResult<PendingPacket> {
+    remove_pending_packet(storage, sequence)
+}
+
+pub fn query_pending_packets(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Vec<(u64, PendingPacket)>> {
+    let limit = limit.unwrap_or(MAX_PAGE_LIMIT).min(MAX_PAGE_LIMIT);
+    let start = start_after.map(Bound::exclusive);
+
+    PENDING_PACKETS
+        .range(storage, start, None, Order::Ascending)
+        .take(limit as usize)
+        .collect()
+}
+
+pub fn query_packet_by_sequence(
+    storage: &dyn Storage,
+    sequence: u64,
+) -> StdResult<Option<PendingPacket>> {
+    PENDING_PACKETS.may_load(storage, sequence)
+}
+
+fn remove_pending_packet(storage: &mut dyn Storage, sequence: u64) -> StdResult<PendingPacket> {
+    let packet = PENDING_PACKETS
+        .may_load(storage, sequence)?
+        .ok_or_else(|| {
+            StdError::generic_err(format!("No pending packet with sequence {}", sequence))
+        })?;
+    PENDING_PACKETS.remove(storage, sequence);
+
+    Ok(packet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cosmwasm_std::testing::mock_dependencies;
+
+    // What a sudo entry point would receive from the transfer module
+    enum MockSudoMsg {
+        Response { sequence: u64 },
+        Timeout { sequence: u64 },
+    }
+
+    fn mock_sudo(storage: &mut dyn Storage, msg: MockSudoMsg) -> StdResult<PendingPacket> {
+        match msg {
+            MockSudoMsg::Response { sequence } => on_packet_acknowledged(storage, sequence),
+            MockSudoMsg::Timeout { sequence } => on_packet_timed_out(storage, sequence),
+        }
+    }
+
+    fn packet(amount: u128) -> PendingPacket {
+        PendingPacket {
+            denom: "untrn".to_string(),
+            amount: Uint128::new(amount),
+            recipient: "recipient".to_string(),
+            submitted_at_block: 100,
+            timeout_timestamp: Timestamp::from_seconds(600),
+        }
+    }
+
+    #[test]
+    fn test_track_and_acknowledge() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+
+        track_outgoing_packet(storage, 1, &packet(100)).unwrap();
+        track_outgoing_packet(storage, 2, &packet(200)).unwrap();
+
+        // Can't track the same sequence twice
+        track_outgoing_packet(storage, 1, &packet(100)).unwrap_err();
+
+        assert_eq!(
+            query_pending_packets(storage, None, None).unwrap(),
+            vec![(1, packet(100)), (2, packet(200))]
+        );
+        assert_eq!(
+            query_pending_packets(storage, Some(1), None).unwrap(),
+            vec![(2, packet(200))]
+        );
+
+        assert_eq!(
+            mock_sudo(storage, MockSudoMsg::Response { sequence: 1 }).unwrap(),
+            packet(100)
+        );
+        assert_eq!(query_packet_by_sequence(storage, 1).unwrap(), None);
+        assert_eq!(
+            query_packet_by_sequence(storage, 2).unwrap(),
+            Some(packet(200))
+        );
+
+        // Acknowledgement for a packet we don't track
+        mock_sudo(storage, MockSudoMsg::Response { sequence: 1 }).unwrap_err();
+    }
+
+    #[test]
+    fn test_track_and_timeout() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+
+        track_outgoing_packet(storage, 1, &packet(100)).unwrap();
+
+        assert_eq!(
+            mock_sudo(storage, MockSudoMsg::Timeout { sequence: 1 }).unwrap(),
+            packet(100)
+        );
+        assert!(query_pending_packets(storage, None, None)
+            .unwrap()
+            .is_empty());
+
+        // The packet can't be acknowledged after it timed out
+        mock_sudo(storage, MockSudoMsg::Response { sequence: 1 }).unwrap_err();
+    }
+}
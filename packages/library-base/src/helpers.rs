@@ -1,11 +1,11 @@
 use cosmwasm_std::{Addr, Storage};
 use valence_library_utils::error::{LibraryError, UnauthorizedReason};
 
-use crate::state::PROCESSOR;
+use crate::state::{PRIMARY_PROCESSOR, PROCESSORS};
 
 pub fn assert_processor(store: &dyn Storage, sender: &Addr) -> Result<(), LibraryError> {
-    let processor = PROCESSOR.load(store)?;
-    if sender != processor {
+    // Libraries instantiated before multiple processors were supported only have the primary one stored
+    if !PROCESSORS.has(store, sender.clone()) && *sender != PRIMARY_PROCESSOR.load(store)? {
         return Err(LibraryError::Unauthorized(
             UnauthorizedReason::NotAllowed {},
         ));
@@ -3,7 +3,7 @@ use helpers::assert_processor;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
-use state::PROCESSOR;
+use state::{record_config_update, set_primary_processor, PRIMARY_PROCESSOR, PROCESSORS};
 use valence_library_utils::{
     error::LibraryError,
     msg::{ExecuteMsg, InstantiateMsg, LibraryConfigValidation},
@@ -14,7 +14,10 @@ use valence_library_utils::{
 pub mod helpers;
 pub mod state;
 
-pub use crate::state::{get_ownership, get_processor, load_config, load_raw_config, save_config};
+pub use crate::state::{
+    get_config_version, get_ownership, get_processor, get_processors, load_config, load_raw_config,
+    save_config,
+};
 
 pub fn instantiate<T, U>(
     deps: DepsMut,
@@ -29,7 +32,7 @@ where
     cw2::set_contract_version(deps.storage, contract_name, contract_version)?;
     cw_ownable::initialize_owner(deps.storage, deps.api, Some(&msg.owner))?;
 
-    PROCESSOR.save(deps.storage, &deps.api.addr_validate(&msg.processor)?)?;
+    set_primary_processor(deps.storage, &deps.api.addr_validate(&msg.processor)?)?;
 
     // Saves the raw library config
     save_raw_library_config(deps.storage, &msg.config)?;
@@ -68,18 +71,49 @@ where
         }
         ExecuteMsg::UpdateConfig { new_config } => {
             cw_ownable::assert_owner(deps.as_ref().storage, &info.sender)?;
+            // Nothing to update, we skip writing to storage
+            if !new_config.has_changes() {
+                return Ok(Response::new().add_attribute("method", "update_config_noop"));
+            }
             // We update the raw storage
-            new_config.update_raw(deps.storage)?;
+            new_config.update_raw(deps.api, deps.storage)?;
+            let version = record_config_update(deps.storage, &info.sender, env.block.height)?;
+            let updater = info.sender.to_string();
             update_config(deps, env, info, new_config)?;
-            Ok(Response::new().add_attribute("method", "update_config"))
+            Ok(Response::new()
+                .add_attribute("method", "update_config")
+                .add_attribute("config_version", version.to_string())
+                .add_attribute("updater", updater))
         }
         ExecuteMsg::UpdateProcessor { processor } => {
             cw_ownable::assert_owner(deps.as_ref().storage, &info.sender)?;
-            PROCESSOR.save(deps.storage, &deps.api.addr_validate(&processor)?)?;
+            // Removes all the processors and sets the new one as the primary processor
+            set_primary_processor(deps.storage, &deps.api.addr_validate(&processor)?)?;
             Ok(Response::default()
                 .add_attribute("method", "update_processor")
                 .add_attribute("processor", processor))
         }
+        ExecuteMsg::AddProcessor { processor } => {
+            cw_ownable::assert_owner(deps.as_ref().storage, &info.sender)?;
+            PROCESSORS.save(deps.storage, deps.api.addr_validate(&processor)?, &true)?;
+            Ok(Response::default()
+                .add_attribute("method", "add_processor")
+                .add_attribute("processor", processor))
+        }
+        ExecuteMsg::RemoveProcessor { processor } => {
+            cw_ownable::assert_owner(deps.as_ref().storage, &info.sender)?;
+            let processor_addr = deps.api.addr_validate(&processor)?;
+            if processor_addr == PRIMARY_PROCESSOR.load(deps.storage)? {
+                return Err(LibraryError::ConfigurationError(
+                    "The primary processor can't be removed, use UpdateProcessor to replace it"
+                        .to_string(),
+                ));
+            }
+            PROCESSORS.remove(deps.storage, processor_addr);
+            Ok(Response::default()
+                .add_attribute("method", "remove_processor")
+                .add_attribute("processor", processor))
+        }
         ExecuteMsg::UpdateOwnership(action) => {
             let result = cw_ownable::update_ownership(
                 deps.into_empty(),
@@ -1,20 +1,76 @@
 use std::any::type_name;
 
-use cosmwasm_std::{from_json, to_json_vec, Addr, StdError, StdResult, Storage};
+use cosmwasm_std::{from_json, to_json_vec, Addr, Order, StdError, StdResult, Storage};
 use cw_ownable::Ownership;
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 use serde::{de::DeserializeOwned, Serialize};
 use valence_library_utils::raw_config::load_raw_library_config;
 
 pub const CONFIG_KEY: &[u8] = b"config";
-pub const PROCESSOR: Item<Addr> = Item::new("processor");
+// Keeps the storage key of the former single processor so that it remains the primary one
+pub const PRIMARY_PROCESSOR: Item<Addr> = Item::new("processor");
+// All the processors allowed to execute library functions, including the primary one
+pub const PROCESSORS: Map<Addr, bool> = Map::new("processors");
+
+// Number of times the config was updated since instantiation
+pub const CONFIG_VERSION: Item<u64> = Item::new("config_version");
+// Address that last updated the config and the block height of the update
+pub const CONFIG_LAST_UPDATED: Item<(Addr, u64)> = Item::new("config_last_updated");
 
 pub fn get_ownership(store: &dyn Storage) -> StdResult<Ownership<Addr>> {
     cw_ownable::get_ownership(store)
 }
 
 pub fn get_processor(store: &dyn Storage) -> StdResult<Addr> {
-    PROCESSOR.load(store)
+    PRIMARY_PROCESSOR.load(store)
+}
+
+/// Returns all the processors, libraries instantiated before multiple processors were supported
+/// only have the primary one stored so it's added if it's missing from the list
+pub fn get_processors(store: &dyn Storage) -> StdResult<Vec<Addr>> {
+    let mut processors = PROCESSORS
+        .keys(store, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<Addr>>>()?;
+
+    if let Some(primary) = PRIMARY_PROCESSOR.may_load(store)? {
+        if !processors.contains(&primary) {
+            processors.insert(0, primary);
+        }
+    }
+
+    Ok(processors)
+}
+
+/// Replaces all processors with a single primary processor
+pub fn set_primary_processor(store: &mut dyn Storage, processor: &Addr) -> StdResult<()> {
+    PROCESSORS.clear(store);
+    PROCESSORS.save(store, processor.clone(), &true)?;
+    PRIMARY_PROCESSOR.save(store, processor)
+}
+
+/// Returns the config version, the address that last updated it and the block height of the update,
+/// or None if the config was never updated since instantiation
+pub fn get_config_version(store: &dyn Storage) -> StdResult<Option<(u64, Addr, u64)>> {
+    let Some((updater, height)) = CONFIG_LAST_UPDATED.may_load(store)? else {
+        return Ok(None);
+    };
+    Ok(Some((CONFIG_VERSION.load(store)?, updater, height)))
+}
+
+/// Increments the config version and records who updated it, returning the new version
+pub fn record_config_update(
+    store: &mut dyn Storage,
+    updater: &Addr,
+    height: u64,
+) -> StdResult<u64> {
+    let version = CONFIG_VERSION
+        .may_load(store)?
+        .unwrap_or_default()
+        .checked_add(1)
+        .expect("Overflow");
+    CONFIG_VERSION.save(store, &version)?;
+    CONFIG_LAST_UPDATED.save(store, &(updater.clone(), height))?;
+    Ok(version)
 }
 
 pub fn save_config<T>(store: &mut dyn Storage, config: &T) -> StdResult<()>
@@ -48,3 +104,39 @@ fn not_found_object_info<T>(key: &[u8]) -> String {
     let type_name = type_name::<T>();
     format!("type: {type_name}; key: {:02X?}", key)
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockStorage;
+
+    use crate::helpers::assert_processor;
+
+    use super::*;
+
+    #[test]
+    fn get_processors_of_legacy_storage() {
+        let mut storage = MockStorage::new();
+        let primary = Addr::unchecked("primary");
+
+        // Before multiple processors were supported only the primary one was stored
+        PRIMARY_PROCESSOR.save(&mut storage, &primary).unwrap();
+
+        assert_eq!(get_processors(&storage).unwrap(), vec![primary.clone()]);
+        assert_processor(&storage, &primary).unwrap();
+        assert_processor(&storage, &Addr::unchecked("other")).unwrap_err();
+    }
+
+    #[test]
+    fn get_processors_does_not_duplicate_primary() {
+        let mut storage = MockStorage::new();
+        let primary = Addr::unchecked("primary");
+        let secondary = Addr::unchecked("secondary");
+
+        set_primary_processor(&mut storage, &primary).unwrap();
+        PROCESSORS
+            .save(&mut storage, secondary.clone(), &true)
+            .unwrap();
+
+        assert_eq!(get_processors(&storage).unwrap(), vec![primary, secondary]);
+    }
+}
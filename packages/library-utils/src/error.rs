@@ -16,8 +16,45 @@ pub enum LibraryError {
     #[error("Configuration error: {0}")]
     ConfigurationError(String),
 
-    #[error("Execution error: {0}")]
-    ExecutionError(String),
+    #[error("Execution error {code}: {msg}")]
+    ExecutionError { code: u32, msg: String },
+}
+
+// Error codes of execution errors, so that they can be handled programmatically
+pub const ERR_EXECUTION_FAILED: u32 = 1000;
+pub const ERR_INSUFFICIENT_BALANCE: u32 = 1001;
+pub const ERR_SLIPPAGE_EXCEEDED: u32 = 1002;
+pub const ERR_INVALID_DENOM: u32 = 1003;
+pub const ERR_ARITHMETIC: u32 = 1004;
+pub const ERR_INVALID_POOL: u32 = 1005;
+pub const ERR_NOTHING_TO_PROCESS: u32 = 1006;
+pub const ERR_CONSTRAINT_NOT_MET: u32 = 1007;
+pub const ERR_INVALID_INPUT: u32 = 1008;
+
+/// Get the description of an execution error code
+pub fn from_error_code(code: u32) -> &'static str {
+    match code {
+        ERR_EXECUTION_FAILED => "Execution failed",
+        ERR_INSUFFICIENT_BALANCE => "Insufficient balance",
+        ERR_SLIPPAGE_EXCEEDED => "Slippage exceeded",
+        ERR_INVALID_DENOM => "Invalid denom",
+        ERR_ARITHMETIC => "Arithmetic error",
+        ERR_INVALID_POOL => "Invalid pool",
+        ERR_NOTHING_TO_PROCESS => "Nothing to process",
+        ERR_CONSTRAINT_NOT_MET => "Constraint not met",
+        ERR_INVALID_INPUT => "Invalid input",
+        _ => "Unknown error code",
+    }
+}
+
+impl LibraryError {
+    /// The error code of the error if it is an execution error
+    pub fn error_code(&self) -> Option<u32> {
+        match self {
+            LibraryError::ExecutionError { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Error, Debug, PartialEq)]
@@ -25,3 +62,46 @@ pub enum UnauthorizedReason {
     #[error("This address is not allowed to execute this action")]
     NotAllowed {},
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_error_code_describes_known_codes() {
+        assert_eq!(from_error_code(ERR_EXECUTION_FAILED), "Execution failed");
+        assert_eq!(
+            from_error_code(ERR_INSUFFICIENT_BALANCE),
+            "Insufficient balance"
+        );
+        assert_eq!(from_error_code(ERR_SLIPPAGE_EXCEEDED), "Slippage exceeded");
+        assert_eq!(from_error_code(ERR_INVALID_DENOM), "Invalid denom");
+        assert_eq!(from_error_code(ERR_ARITHMETIC), "Arithmetic error");
+        assert_eq!(from_error_code(ERR_INVALID_POOL), "Invalid pool");
+        assert_eq!(
+            from_error_code(ERR_NOTHING_TO_PROCESS),
+            "Nothing to process"
+        );
+        assert_eq!(
+            from_error_code(ERR_CONSTRAINT_NOT_MET),
+            "Constraint not met"
+        );
+        assert_eq!(from_error_code(ERR_INVALID_INPUT), "Invalid input");
+        assert_eq!(from_error_code(0), "Unknown error code");
+    }
+
+    #[test]
+    fn execution_error_displays_its_code() {
+        let err = LibraryError::ExecutionError {
+            code: ERR_INSUFFICIENT_BALANCE,
+            msg: "Not enough funds".to_string(),
+        };
+
+        assert_eq!(err.to_string(), "Execution error 1001: Not enough funds");
+        assert_eq!(err.error_code(), Some(ERR_INSUFFICIENT_BALANCE));
+        assert_eq!(
+            LibraryError::ConfigurationError("invalid".to_string()).error_code(),
+            None
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use cosmos_sdk_proto::{
+    cosmos::{
+        base::v1beta1::Coin as ProtoCoin,
+        staking::v1beta1::{MsgBeginRedelegate, MsgDelegate, MsgUndelegate},
+    },
+    traits::MessageExt,
+};
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Coin, CosmosMsg, StdError, StdResult, Uint64, WasmMsg,
+};
+use valence_polytone_utils::polytone::{CallbackRequest, PolytoneExecuteMsg};
+
+/// Builds the message that executes `msgs` on the remote chain through a Polytone note.
+/// The messages are executed by the proxy of the sender of this message on the remote chain.
+/// If a `callback` is given, the note sends the result of the execution to it.
+pub fn build_polytone_execute(
+    note_addr: &Addr,
+    msgs: Vec<CosmosMsg>,
+    callback: Option<CallbackRequest>,
+    timeout_seconds: u64,
+) -> StdResult<CosmosMsg> {
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: note_addr.to_string(),
+        msg: to_json_binary(&PolytoneExecuteMsg::Execute {
+            msgs,
+            callback,
+            timeout_seconds: Uint64::from(timeout_seconds),
+        })?,
+        funds: vec![],
+    }))
+}
+
+/// Builds the message for the interchain account `delegator` to delegate `amount` to `validator`
+pub fn build_ica_delegate(delegator: &str, validator: &str, amount: Coin) -> StdResult<CosmosMsg> {
+    let msg = MsgDelegate {
+        delegator_address: delegator.to_string(),
+        validator_address: validator.to_string(),
+        amount: Some(to_proto_coin(amount)),
+    };
+
+    stargate_msg("/cosmos.staking.v1beta1.MsgDelegate", msg.to_bytes())
+}
+
+/// Builds the message for the interchain account `delegator` to undelegate `amount` from `validator`
+pub fn build_ica_undelegate(
+    delegator: &str,
+    validator: &str,
+    amount: Coin,
+) -> StdResult<CosmosMsg> {
+    let msg = MsgUndelegate {
+        delegator_address: delegator.to_string(),
+        validator_address: validator.to_string(),
+        amount: Some(to_proto_coin(amount)),
+    };
+
+    stargate_msg("/cosmos.staking.v1beta1.MsgUndelegate", msg.to_bytes())
+}
+
+/// Builds the message for the interchain account `delegator` to move `amount` of its delegation
+/// from `src_validator` to `dst_validator`
+pub fn build_ica_redelegate(
+    delegator: &str,
+    src_validator: &str,
+    dst_validator: &str,
+    amount: Coin,
+) -> StdResult<CosmosMsg> {
+    let msg = MsgBeginRedelegate {
+        delegator_address: delegator.to_string(),
+        validator_src_address: src_validator.to_string(),
+        validator_dst_address: dst_validator.to_string(),
+        amount: Some(to_proto_coin(amount)),
+    };
+
+    stargate_msg("/cosmos.staking.v1beta1.MsgBeginRedelegate", msg.to_bytes())
+}
+
+fn to_proto_coin(coin: Coin) -> ProtoCoin {
+    ProtoCoin {
+        denom: coin.denom,
+        amount: coin.amount.to_string(),
+    }
+}
+
+fn stargate_msg<E: std::fmt::Display>(
+    type_url: &str,
+    value: Result<Vec<u8>, E>,
+) -> StdResult<CosmosMsg> {
+    let value = value.map_err(|e| StdError::generic_err(e.to_string()))?;
+
+    #[allow(deprecated)]
+    Ok(CosmosMsg::Stargate {
+        type_url: type_url.to_string(),
+        value: Binary::from(value),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{from_json, testing::MockApi, BankMsg, Coin};
+
+    use super::*;
+
+    // Proto encoding of Coin { denom: "uatom", amount: "100" }
+    fn coin_bytes() -> Vec<u8> {
+        [&[0x0a, 0x05][..], b"uatom", &[0x12, 0x03], b"100"].concat()
+    }
+
+    #[allow(deprecated)]
+    fn assert_stargate(msg: CosmosMsg, expected_type_url: &str, expected_value: Vec<u8>) {
+        let CosmosMsg::Stargate { type_url, value } = msg else {
+            panic!("Expected a stargate message");
+        };
+        assert_eq!(type_url, expected_type_url);
+        assert_eq!(value.to_vec(), expected_value);
+    }
+
+    #[test]
+    fn ica_delegate_and_undelegate() {
+        // delegator_address = 1, validator_address = 2, amount = 3
+        let expected = [
+            &[0x0a, 0x01][..],
+            b"d",
+            &[0x12, 0x01],
+            b"v",
+            &[0x1a, 0x0c],
+            &coin_bytes(),
+        ]
+        .concat();
+
+        assert_stargate(
+            build_ica_delegate("d", "v", Coin::new(100u128, "uatom")).unwrap(),
+            "/cosmos.staking.v1beta1.MsgDelegate",
+            expected.clone(),
+        );
+        assert_stargate(
+            build_ica_undelegate("d", "v", Coin::new(100u128, "uatom")).unwrap(),
+            "/cosmos.staking.v1beta1.MsgUndelegate",
+            expected,
+        );
+    }
+
+    #[test]
+    fn ica_redelegate() {
+        // delegator_address = 1, validator_src_address = 2, validator_dst_address = 3, amount = 4
+        let expected = [
+            &[0x0a, 0x01][..],
+            b"d",
+            &[0x12, 0x01],
+            b"s",
+            &[0x1a, 0x01],
+            b"t",
+            &[0x22, 0x0c],
+            &coin_bytes(),
+        ]
+        .concat();
+
+        assert_stargate(
+            build_ica_redelegate("d", "s", "t", Coin::new(100u128, "uatom")).unwrap(),
+            "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+            expected,
+        );
+    }
+
+    #[test]
+    fn polytone_execute() {
+        let api = MockApi::default();
+        let note = api.addr_make("note");
+        let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+            to_address: "remote_recipient".to_string(),
+            amount: vec![Coin::new(100u128, "uatom")],
+        }
+        .into()];
+
+        let msg = build_polytone_execute(&note, msgs.clone(), None, 600).unwrap();
+
+        let CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr,
+            msg,
+            funds,
+        }) = msg
+        else {
+            panic!("Expected a wasm execute message");
+        };
+        assert_eq!(contract_addr, note.to_string());
+        assert!(funds.is_empty());
+        assert_eq!(
+            from_json::<PolytoneExecuteMsg>(msg).unwrap(),
+            PolytoneExecuteMsg::Execute {
+                msgs,
+                callback: None,
+                timeout_seconds: Uint64::new(600),
+            }
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_json_binary, Addr, CosmosMsg, StdError, StdResult, Storage, SubMsg, WasmMsg,
+    to_json_binary, Addr, Api, CanonicalAddr, CosmosMsg, StdError, StdResult, Storage, SubMsg,
+    WasmMsg,
 };
 
 pub mod denoms {
@@ -8,9 +9,11 @@ pub mod denoms {
 }
 
 pub mod error;
+pub mod ica;
 pub mod liquidity_utils;
 pub mod msg;
 pub mod raw_config;
+pub mod validation;
 
 #[cfg(feature = "testing")]
 pub mod testing;
@@ -18,15 +21,21 @@ pub mod testing;
 pub type Id = u64;
 
 pub trait LibraryConfigUpdateTrait {
-    fn update_raw(&self, storage: &mut dyn Storage) -> StdResult<()>;
+    fn update_raw(&self, api: &dyn Api, storage: &mut dyn Storage) -> StdResult<()>;
+    /// Returns true if the update sets or unsets at least one field
+    fn has_changes(&self) -> bool;
 }
 
 #[cw_serde]
 #[derive(Default)]
 pub enum OptionUpdate<T> {
+    /// Leave the field as it is
     #[default]
     None,
+    /// Set the field to the given value
     Set(Option<T>),
+    /// Unset the field, same as `Set(None)`
+    Clear,
 }
 
 /// An account type that is used in the library configs
@@ -136,6 +145,16 @@ impl LibraryAccountType {
             ),
         }
     }
+
+    /// Get the canonical (decoded) bytes of the address, it must be of Addr variant
+    pub fn to_canonical_addr(&self, api: &dyn Api) -> StdResult<CanonicalAddr> {
+        api.addr_canonicalize(self.to_addr(api)?.as_str())
+    }
+
+    /// Reconstruct the Addr variant from the canonical bytes of an address
+    pub fn from_canonical(canonical: &CanonicalAddr, api: &dyn Api) -> StdResult<Self> {
+        Ok(LibraryAccountType::from(&api.addr_humanize(canonical)?))
+    }
 }
 
 // This is a helper function to execute a CosmosMsg on behalf of an account
@@ -147,6 +166,41 @@ pub fn execute_on_behalf_of(msgs: Vec<CosmosMsg>, account: &Addr) -> StdResult<C
     }))
 }
 
+// Executes each batch of messages on behalf of an account in a separate SubMsg, replying with the reply id of the batch
+pub fn execute_batch_on_behalf_of(
+    batches: Vec<Vec<CosmosMsg>>,
+    reply_ids: Vec<u64>,
+    account: &Addr,
+) -> StdResult<Vec<SubMsg>> {
+    if batches.len() != reply_ids.len() {
+        return Err(StdError::generic_err(
+            "Number of batches and reply ids must be the same",
+        ));
+    }
+
+    batches
+        .into_iter()
+        .zip(reply_ids)
+        .map(|(msgs, reply_id)| {
+            Ok(SubMsg::reply_always(
+                execute_on_behalf_of(msgs, account)?,
+                reply_id,
+            ))
+        })
+        .collect()
+}
+
+// Same as execute_batch_on_behalf_of but without replies for the batches
+pub fn execute_batch_on_behalf_of_no_reply(
+    batches: Vec<Vec<CosmosMsg>>,
+    account: &Addr,
+) -> StdResult<Vec<SubMsg>> {
+    batches
+        .into_iter()
+        .map(|msgs| Ok(SubMsg::new(execute_on_behalf_of(msgs, account)?)))
+        .collect()
+}
+
 pub fn execute_submsgs_on_behalf_of(
     msgs: Vec<SubMsg>,
     payload: Option<String>,
@@ -161,3 +215,71 @@ pub fn execute_submsgs_on_behalf_of(
         funds: vec![],
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::{testing::MockApi, BankMsg, Coin, ReplyOn};
+
+    use super::*;
+
+    #[test]
+    fn canonical_addr_roundtrip() {
+        let api = MockApi::default();
+        let addr = api.addr_make("account");
+        let account = LibraryAccountType::from(&addr);
+
+        let canonical = account.to_canonical_addr(&api).unwrap();
+        assert_eq!(canonical, api.addr_canonicalize(addr.as_str()).unwrap());
+
+        assert_eq!(
+            LibraryAccountType::from_canonical(&canonical, &api).unwrap(),
+            account
+        );
+    }
+
+    #[test]
+    fn canonical_addr_requires_address() {
+        let api = MockApi::default();
+
+        LibraryAccountType::AccountId(1)
+            .to_canonical_addr(&api)
+            .unwrap_err();
+        LibraryAccountType::LibraryId(1)
+            .to_canonical_addr(&api)
+            .unwrap_err();
+        LibraryAccountType::Addr("invalid".to_string())
+            .to_canonical_addr(&api)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn batch_execute_on_behalf_of() {
+        let api = MockApi::default();
+        let account = api.addr_make("account");
+        let send = |amount: u128| -> CosmosMsg {
+            BankMsg::Send {
+                to_address: api.addr_make("recipient").to_string(),
+                amount: vec![Coin::new(amount, "untrn")],
+            }
+            .into()
+        };
+        let batches = vec![vec![send(1)], vec![send(2), send(3)]];
+
+        let submsgs = execute_batch_on_behalf_of(batches.clone(), vec![10, 20], &account).unwrap();
+        assert_eq!(submsgs.len(), 2);
+        for ((submsg, msgs), reply_id) in submsgs.iter().zip(batches.clone()).zip([10, 20]) {
+            assert_eq!(submsg.id, reply_id);
+            assert_eq!(submsg.reply_on, ReplyOn::Always);
+            assert_eq!(submsg.msg, execute_on_behalf_of(msgs, &account).unwrap());
+        }
+
+        // Every batch needs a reply id
+        execute_batch_on_behalf_of(batches.clone(), vec![10], &account).unwrap_err();
+
+        let submsgs = execute_batch_on_behalf_of_no_reply(batches, &account).unwrap();
+        assert_eq!(submsgs.len(), 2);
+        assert!(submsgs
+            .iter()
+            .all(|submsg| submsg.reply_on == ReplyOn::Never));
+    }
+}
@@ -1,7 +1,9 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{ensure, Decimal};
+use cosmwasm_std::{ensure, Decimal, StdError, StdResult, Uint128, Uint256};
 
-use crate::error::LibraryError;
+use crate::error::{
+    LibraryError, ERR_ARITHMETIC, ERR_INVALID_INPUT, ERR_INVALID_POOL, ERR_SLIPPAGE_EXCEEDED,
+};
 
 #[cw_serde]
 pub struct AssetData {
@@ -27,8 +29,308 @@ impl DecimalRange {
     pub fn contains(&self, value: Decimal) -> Result<(), LibraryError> {
         ensure!(
             value >= self.min && value <= self.max,
-            LibraryError::ExecutionError("Value is not within the expected range".to_string())
+            LibraryError::ExecutionError {
+                code: ERR_SLIPPAGE_EXCEEDED,
+                msg: "Value is not within the expected range".to_string()
+            }
         );
         Ok(())
     }
 }
+
+/// Max iterations for the Newton's method used in the StableSwap invariant calculations
+const STABLESWAP_MAX_ITERATIONS: u8 = 255;
+
+/// Calculates the price impact of swapping `amount_in` in a constant product (x * y = k) pool, without fees.
+/// The price impact is the relative difference between the spot price and the effective price of the swap,
+/// which for a constant product pool is amount_in / (reserve_in + amount_in)
+pub fn calculate_price_impact(
+    reserve_in: Uint128,
+    reserve_out: Uint128,
+    amount_in: Uint128,
+) -> Result<Decimal, LibraryError> {
+    ensure!(
+        !reserve_in.is_zero() && !reserve_out.is_zero(),
+        LibraryError::ExecutionError {
+            code: ERR_INVALID_POOL,
+            msg: "Pool reserves can't be zero".to_string()
+        }
+    );
+
+    let new_reserve_in =
+        reserve_in
+            .checked_add(amount_in)
+            .map_err(|e| LibraryError::ExecutionError {
+                code: ERR_ARITHMETIC,
+                msg: e.to_string(),
+            })?;
+
+    decimal_checked_ops::from_ratio(amount_in, new_reserve_in)
+}
+
+/// Calculates the price impact of swapping `amount_in` of the asset at `token_in_idx` for the other asset
+/// of a two asset StableSwap pool, without fees. `amp` is the amplification coefficient A of the pool.
+pub fn calculate_stable_price_impact(
+    amp: u64,
+    reserves: &[Uint128],
+    amount_in: Uint128,
+    token_in_idx: usize,
+) -> Result<Decimal, LibraryError> {
+    ensure!(
+        reserves.len() == 2,
+        LibraryError::ExecutionError {
+            code: ERR_INVALID_POOL,
+            msg: "StableSwap pool must have two assets".to_string()
+        }
+    );
+    ensure!(
+        token_in_idx < reserves.len(),
+        LibraryError::ExecutionError {
+            code: ERR_INVALID_INPUT,
+            msg: "Invalid input token index".to_string()
+        }
+    );
+    ensure!(
+        amp > 0,
+        LibraryError::ExecutionError {
+            code: ERR_INVALID_INPUT,
+            msg: "Amplification coefficient can't be zero".to_string()
+        }
+    );
+    ensure!(
+        reserves.iter().all(|reserve| !reserve.is_zero()),
+        LibraryError::ExecutionError {
+            code: ERR_INVALID_POOL,
+            msg: "Pool reserves can't be zero".to_string()
+        }
+    );
+
+    if amount_in.is_zero() {
+        return Ok(Decimal::zero());
+    }
+
+    let reserve_in = reserves[token_in_idx];
+    let reserve_out = reserves[1 - token_in_idx];
+
+    // Ann = A * n^n
+    let ann = Uint256::from(amp)
+        .checked_mul(Uint256::from(4u8))
+        .map_err(StdError::from)?;
+    let d = stableswap_d(ann, reserve_in.into(), reserve_out.into())?;
+
+    // Amount received for amount_in
+    let new_reserve_in =
+        reserve_in
+            .checked_add(amount_in)
+            .map_err(|e| LibraryError::ExecutionError {
+                code: ERR_ARITHMETIC,
+                msg: e.to_string(),
+            })?;
+    let new_reserve_out = stableswap_y(ann, d, new_reserve_in.into())?;
+    let amount_out = Uint256::from(reserve_out).saturating_sub(new_reserve_out);
+    let amount_out = Uint128::try_from(amount_out).map_err(StdError::from)?;
+    let effective_price = decimal_checked_ops::from_ratio(amount_out, amount_in)?;
+
+    // Spot price from the partial derivatives of the invariant:
+    // (Ann + D^3 / (4 * x_in^2 * x_out)) / (Ann + D^3 / (4 * x_in * x_out^2))
+    let d = Uint128::try_from(d).map_err(StdError::from)?;
+    let ann = decimal_checked_ops::from_ratio(
+        Uint128::try_from(ann).map_err(StdError::from)?,
+        Uint128::one(),
+    )?;
+    let four = Decimal::percent(400);
+    let d_in = decimal_checked_ops::from_ratio(d, reserve_in)?;
+    let d_out = decimal_checked_ops::from_ratio(d, reserve_out)?;
+    let d_in_d_out = decimal_checked_ops::mul(d_in, d_out)?;
+    let numerator = decimal_checked_ops::add(
+        ann,
+        decimal_checked_ops::div(decimal_checked_ops::mul(d_in_d_out, d_in)?, four)?,
+    )?;
+    let denominator = decimal_checked_ops::add(
+        ann,
+        decimal_checked_ops::div(decimal_checked_ops::mul(d_in_d_out, d_out)?, four)?,
+    )?;
+    let spot_price = decimal_checked_ops::div(numerator, denominator)?;
+
+    // Rounding can make the effective price slightly better than the spot price
+    Ok(Decimal::one().saturating_sub(decimal_checked_ops::div(effective_price, spot_price)?))
+}
+
+/// Calculates the StableSwap invariant D of a two asset pool using Newton's method
+fn stableswap_d(ann: Uint256, x: Uint256, y: Uint256) -> StdResult<Uint256> {
+    let n = Uint256::from(2u8);
+    let sum = x.checked_add(y)?;
+    let mut d = sum;
+
+    for _ in 0..STABLESWAP_MAX_ITERATIONS {
+        // D_P = D^3 / (n^n * x * y)
+        let d_p = d
+            .checked_mul(d)?
+            .checked_div(x.checked_mul(n)?)?
+            .checked_mul(d)?
+            .checked_div(y.checked_mul(n)?)?;
+        let d_prev = d;
+        // D = (Ann * S + n * D_P) * D / ((Ann - 1) * D + (n + 1) * D_P)
+        d = ann
+            .checked_mul(sum)?
+            .checked_add(d_p.checked_mul(n)?)?
+            .checked_mul(d)?
+            .checked_div(
+                ann.checked_sub(Uint256::one())?
+                    .checked_mul(d)?
+                    .checked_add(n.checked_add(Uint256::one())?.checked_mul(d_p)?)?,
+            )?;
+
+        if d.abs_diff(d_prev) <= Uint256::one() {
+            return Ok(d);
+        }
+    }
+
+    Err(StdError::generic_err(
+        "StableSwap invariant did not converge",
+    ))
+}
+
+/// Calculates the reserve of the output asset of a two asset pool for invariant D, given the new reserve x of the input asset
+fn stableswap_y(ann: Uint256, d: Uint256, x: Uint256) -> StdResult<Uint256> {
+    let n = Uint256::from(2u8);
+    // c = D^3 / (n^n * x * Ann)
+    let c = d
+        .checked_mul(d)?
+        .checked_div(x.checked_mul(n)?)?
+        .checked_mul(d)?
+        .checked_div(ann.checked_mul(n)?)?;
+    // b = x + D / Ann
+    let b = x.checked_add(d.checked_div(ann)?)?;
+    let mut y = d;
+
+    for _ in 0..STABLESWAP_MAX_ITERATIONS {
+        let y_prev = y;
+        // y = (y^2 + c) / (2y + b - D)
+        y = y
+            .checked_mul(y)?
+            .checked_add(c)?
+            .checked_div(y.checked_mul(n)?.checked_add(b)?.checked_sub(d)?)?;
+
+        if y.abs_diff(y_prev) <= Uint256::one() {
+            return Ok(y);
+        }
+    }
+
+    Err(StdError::generic_err("StableSwap output did not converge"))
+}
+
+/// Overflow safe Decimal arithmetic that returns a LibraryError instead of panicking
+pub mod decimal_checked_ops {
+    use cosmwasm_std::{Decimal, Uint128};
+
+    use crate::error::{LibraryError, ERR_ARITHMETIC};
+
+    pub fn from_ratio(numerator: Uint128, denominator: Uint128) -> Result<Decimal, LibraryError> {
+        Decimal::checked_from_ratio(numerator, denominator).map_err(|e| {
+            LibraryError::ExecutionError {
+                code: ERR_ARITHMETIC,
+                msg: e.to_string(),
+            }
+        })
+    }
+
+    pub fn add(a: Decimal, b: Decimal) -> Result<Decimal, LibraryError> {
+        a.checked_add(b).map_err(|e| LibraryError::ExecutionError {
+            code: ERR_ARITHMETIC,
+            msg: e.to_string(),
+        })
+    }
+
+    pub fn sub(a: Decimal, b: Decimal) -> Result<Decimal, LibraryError> {
+        a.checked_sub(b).map_err(|e| LibraryError::ExecutionError {
+            code: ERR_ARITHMETIC,
+            msg: e.to_string(),
+        })
+    }
+
+    pub fn mul(a: Decimal, b: Decimal) -> Result<Decimal, LibraryError> {
+        a.checked_mul(b).map_err(|e| LibraryError::ExecutionError {
+            code: ERR_ARITHMETIC,
+            msg: e.to_string(),
+        })
+    }
+
+    pub fn div(a: Decimal, b: Decimal) -> Result<Decimal, LibraryError> {
+        a.checked_div(b).map_err(|e| LibraryError::ExecutionError {
+            code: ERR_ARITHMETIC,
+            msg: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn constant_product_price_impact() {
+        let reserve = Uint128::new(1_000_000_000_000);
+
+        // Swapping 10% of the pool: 0.1 / 1.1 = ~9.09%
+        assert_eq!(
+            calculate_price_impact(reserve, reserve, Uint128::new(100_000_000_000)).unwrap(),
+            Decimal::from_str("0.090909090909090909").unwrap()
+        );
+        // Swapping the size of the pool: 50%
+        assert_eq!(
+            calculate_price_impact(reserve, reserve, reserve).unwrap(),
+            Decimal::percent(50)
+        );
+        assert_eq!(
+            calculate_price_impact(reserve, reserve, Uint128::zero()).unwrap(),
+            Decimal::zero()
+        );
+
+        calculate_price_impact(Uint128::zero(), reserve, reserve).unwrap_err();
+    }
+
+    #[test]
+    fn stable_price_impact() {
+        let reserves = [
+            Uint128::new(1_000_000_000_000),
+            Uint128::new(1_000_000_000_000),
+        ];
+        let amount_in = Uint128::new(100_000_000_000);
+
+        // Swapping 10% of a balanced pool with a high amplification: ~0.05%
+        let impact = calculate_stable_price_impact(100, &reserves, amount_in, 0).unwrap();
+        assert!(impact > Decimal::from_str("0.0005").unwrap());
+        assert!(impact < Decimal::from_str("0.00051").unwrap());
+
+        // Lower amplification gets closer to constant product: ~3.24%
+        let impact = calculate_stable_price_impact(1, &reserves, amount_in, 1).unwrap();
+        assert!(impact > Decimal::from_str("0.032").unwrap());
+        assert!(impact < Decimal::from_str("0.033").unwrap());
+        assert!(impact < calculate_price_impact(reserves[1], reserves[0], amount_in).unwrap());
+
+        // Imbalanced pool: ~0.058%
+        let impact = calculate_stable_price_impact(
+            100,
+            &[
+                Uint128::new(1_000_000_000_000),
+                Uint128::new(2_000_000_000_000),
+            ],
+            amount_in,
+            0,
+        )
+        .unwrap();
+        assert!(impact > Decimal::from_str("0.00057").unwrap());
+        assert!(impact < Decimal::from_str("0.00059").unwrap());
+
+        assert_eq!(
+            calculate_stable_price_impact(100, &reserves, Uint128::zero(), 0).unwrap(),
+            Decimal::zero()
+        );
+        calculate_stable_price_impact(100, &reserves[..1], amount_in, 0).unwrap_err();
+        calculate_stable_price_impact(100, &reserves, amount_in, 2).unwrap_err();
+        calculate_stable_price_impact(0, &reserves, amount_in, 0).unwrap_err();
+    }
+}
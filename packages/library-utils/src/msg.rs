@@ -25,6 +25,8 @@ pub enum ExecuteMsg<T, U> {
     ProcessFunction(T),
     UpdateConfig { new_config: U },
     UpdateProcessor { processor: String },
+    AddProcessor { processor: String },
+    RemoveProcessor { processor: String },
 }
 
 #[cw_serde]
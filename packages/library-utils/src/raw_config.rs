@@ -31,8 +31,84 @@ where
     get_library_config_item::<T>().update(storage, action)
 }
 
+/// Migrate the stored raw library config from `OldT` to `NewT`, for when the shape of the
+/// `LibraryConfig` changes between library versions
+pub fn migrate_raw_library_config<OldT, NewT, F>(
+    storage: &mut dyn Storage,
+    migrate_fn: F,
+) -> StdResult<()>
+where
+    OldT: Serialize + DeserializeOwned,
+    NewT: Serialize + DeserializeOwned,
+    F: Fn(OldT) -> StdResult<NewT>,
+{
+    let old_config = load_raw_library_config::<OldT>(storage)?;
+    let new_config = migrate_fn(old_config)?;
+    save_raw_library_config(storage, &new_config)
+}
+
 pub fn query_raw_library_config<T: Serialize + DeserializeOwned>(
     storage: &dyn Storage,
 ) -> StdResult<T> {
     load_raw_library_config(storage)
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_schema::cw_serde;
+    use cosmwasm_std::testing::MockStorage;
+
+    use super::*;
+
+    #[cw_serde]
+    struct OldConfig {
+        value: u64,
+    }
+
+    #[cw_serde]
+    struct NewConfig {
+        value: u64,
+        label: String,
+    }
+
+    #[test]
+    fn migrate_raw_library_config_to_new_shape() {
+        let mut storage = MockStorage::new();
+        save_raw_library_config(&mut storage, &OldConfig { value: 42 }).unwrap();
+
+        // Stored config can't be loaded with the new shape before migrating
+        load_raw_library_config::<NewConfig>(&storage).unwrap_err();
+
+        migrate_raw_library_config(&mut storage, |old: OldConfig| {
+            Ok(NewConfig {
+                value: old.value,
+                label: "default".to_string(),
+            })
+        })
+        .unwrap();
+
+        assert_eq!(
+            load_raw_library_config::<NewConfig>(&storage).unwrap(),
+            NewConfig {
+                value: 42,
+                label: "default".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn migrate_raw_library_config_keeps_old_config_on_error() {
+        let mut storage = MockStorage::new();
+        save_raw_library_config(&mut storage, &OldConfig { value: 42 }).unwrap();
+
+        migrate_raw_library_config::<OldConfig, NewConfig, _>(&mut storage, |_| {
+            Err(StdError::generic_err("migration failed"))
+        })
+        .unwrap_err();
+
+        assert_eq!(
+            load_raw_library_config::<OldConfig>(&storage).unwrap(),
+            OldConfig { value: 42 }
+        );
+    }
+}
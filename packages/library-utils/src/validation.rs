@@ -0,0 +1,125 @@
+use cosmwasm_std::{Addr, Api, Decimal, StdError, StdResult, Uint128, Uint64};
+
+use crate::LibraryAccountType;
+
+// Validations used by the `#[validate(...)]` attributes of the `ValenceLibraryInterface` derive macro
+
+/// Values that can be validated with `#[validate(non_empty)]`
+pub trait NonEmpty {
+    fn is_empty_value(&self) -> bool;
+}
+
+/// Values that can be validated with `#[validate(positive)]`
+pub trait Positive {
+    fn is_positive(&self) -> bool;
+}
+
+/// Values that can be validated with `#[validate(addr)]`
+pub trait ValidateAddr {
+    fn validate_addr(&self, api: &dyn Api) -> StdResult<Addr>;
+}
+
+/// Configs with address fields, implemented by the `ValenceAddressFields` derive macro.
+/// `LibraryAccountType` fields and fields with `#[validate(addr)]` are address fields.
+pub trait HasAddressFields {
+    /// Name and value of each address field, unset optional fields are skipped
+    fn address_fields(&self) -> Vec<(&'static str, &dyn ValidateAddr)>;
+
+    /// Validate all the address fields in one pass.
+    /// Returns the addresses in field order, or the name and error of every field that failed.
+    fn validate_addresses(&self, api: &dyn Api) -> Result<Vec<Addr>, Vec<(String, StdError)>> {
+        let mut addrs = vec![];
+        let mut errors = vec![];
+
+        for (field, value) in self.address_fields() {
+            match value.validate_addr(api) {
+                Ok(addr) => addrs.push(addr),
+                Err(err) => errors.push((field.to_string(), err)),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(addrs)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+pub fn validate_non_empty<T: NonEmpty>(field: &str, value: &T) -> StdResult<()> {
+    if value.is_empty_value() {
+        return Err(StdError::generic_err(format!(
+            "{} must not be empty",
+            field
+        )));
+    }
+    Ok(())
+}
+
+pub fn validate_positive<T: Positive>(field: &str, value: &T) -> StdResult<()> {
+    if !value.is_positive() {
+        return Err(StdError::generic_err(format!("{} must be positive", field)));
+    }
+    Ok(())
+}
+
+pub fn validate_addr<T: ValidateAddr>(field: &str, value: &T, api: &dyn Api) -> StdResult<()> {
+    value
+        .validate_addr(api)
+        .map(|_| ())
+        .map_err(|e| StdError::generic_err(format!("{} is not a valid address: {}", field, e)))
+}
+
+impl NonEmpty for String {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> NonEmpty for Vec<T> {
+    fn is_empty_value(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+macro_rules! impl_positive {
+    ($($ty:ty),*) => {
+        $(impl Positive for $ty {
+            fn is_positive(&self) -> bool {
+                !self.is_zero()
+            }
+        })*
+    };
+}
+
+impl_positive!(Uint64, Uint128, Decimal);
+
+macro_rules! impl_positive_primitive {
+    ($($ty:ty),*) => {
+        $(impl Positive for $ty {
+            fn is_positive(&self) -> bool {
+                *self > 0
+            }
+        })*
+    };
+}
+
+impl_positive_primitive!(u8, u16, u32, u64, u128);
+
+impl ValidateAddr for String {
+    fn validate_addr(&self, api: &dyn Api) -> StdResult<Addr> {
+        api.addr_validate(self)
+    }
+}
+
+impl ValidateAddr for Addr {
+    fn validate_addr(&self, api: &dyn Api) -> StdResult<Addr> {
+        api.addr_validate(self.as_str())
+    }
+}
+
+impl ValidateAddr for LibraryAccountType {
+    fn validate_addr(&self, api: &dyn Api) -> StdResult<Addr> {
+        self.to_addr(api)
+    }
+}
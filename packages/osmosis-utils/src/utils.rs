@@ -73,7 +73,7 @@ pub mod cl_utils {
     use osmosis_std::types::osmosis::{
         concentratedliquidity::v1beta1::Pool, poolmanager::v1beta1::PoolmanagerQuerier,
     };
-    use valence_library_utils::error::LibraryError;
+    use valence_library_utils::error::{LibraryError, ERR_INVALID_INPUT};
 
     pub fn query_cl_pool(deps: &Deps, pool_id: u64) -> StdResult<Pool> {
         let querier = PoolmanagerQuerier::new(&deps.querier);
@@ -127,7 +127,10 @@ pub mod cl_utils {
         pub fn validate(&self) -> Result<(), LibraryError> {
             ensure!(
                 self.lower_tick < self.upper_tick,
-                LibraryError::ExecutionError("lower tick must be less than upper tick".to_string())
+                LibraryError::ExecutionError {
+                    code: ERR_INVALID_INPUT,
+                    msg: "lower tick must be less than upper tick".to_string()
+                }
             );
             Ok(())
         }
@@ -152,9 +155,10 @@ pub mod cl_utils {
 
             ensure!(
                 lower_compatible && upper_compatible,
-                LibraryError::ExecutionError(
-                    "tick range is not a multiple of the other".to_string()
-                )
+                LibraryError::ExecutionError {
+                    code: ERR_INVALID_INPUT,
+                    msg: "tick range is not a multiple of the other".to_string()
+                }
             );
             Ok(())
         }
@@ -162,9 +166,10 @@ pub mod cl_utils {
         pub fn ensure_contains(&self, other: &TickRange) -> Result<(), LibraryError> {
             ensure!(
                 self.lower_tick <= other.lower_tick && self.upper_tick >= other.upper_tick,
-                LibraryError::ExecutionError(
-                    "other tick range is not contained by this range".to_string()
-                )
+                LibraryError::ExecutionError {
+                    code: ERR_INVALID_INPUT,
+                    msg: "other tick range is not contained by this range".to_string()
+                }
             );
             Ok(())
         }
@@ -379,3 +384,183 @@ pub mod gamm_utils {
         }
     }
 }
+
+pub mod gamm_msgs {
+    use cosmwasm_std::{ensure, Coin, CosmosMsg, StdError, StdResult, Uint128};
+    use osmosis_std::{
+        cosmwasm_to_proto_coins,
+        types::osmosis::{
+            gamm::v1beta1::{MsgExitPool, MsgJoinPool},
+            poolmanager::v1beta1::{MsgSwapExactAmountIn, SwapAmountInRoute},
+        },
+    };
+
+    /// Builds the message to join a GAMM pool, receiving `share_out_amount` shares
+    /// while providing at most `token_in_maxs`
+    pub fn build_gamm_join_pool_msg(
+        sender: &str,
+        pool_id: u64,
+        share_out_amount: Uint128,
+        token_in_maxs: Vec<Coin>,
+    ) -> CosmosMsg {
+        MsgJoinPool {
+            sender: sender.to_string(),
+            pool_id,
+            share_out_amount: share_out_amount.to_string(),
+            token_in_maxs: cosmwasm_to_proto_coins(token_in_maxs),
+        }
+        .into()
+    }
+
+    /// Builds the message to exit a GAMM pool, burning `share_in_amount` shares
+    /// and receiving at least `token_out_mins`
+    pub fn build_gamm_exit_pool_msg(
+        sender: &str,
+        pool_id: u64,
+        share_in_amount: Uint128,
+        token_out_mins: Vec<Coin>,
+    ) -> CosmosMsg {
+        MsgExitPool {
+            sender: sender.to_string(),
+            pool_id,
+            share_in_amount: share_in_amount.to_string(),
+            token_out_mins: cosmwasm_to_proto_coins(token_out_mins),
+        }
+        .into()
+    }
+
+    /// Builds the message to swap `token_in` through the `routes`. The denom of `token_out_min`
+    /// must be the output denom of the last route, the message only carries its amount
+    pub fn build_gamm_swap_msg(
+        sender: &str,
+        routes: Vec<SwapAmountInRoute>,
+        token_in: Coin,
+        token_out_min: Coin,
+    ) -> StdResult<CosmosMsg> {
+        let token_out_denom = routes
+            .last()
+            .map(|route| route.token_out_denom.as_str())
+            .ok_or_else(|| StdError::generic_err("swap routes can't be empty"))?;
+
+        ensure!(
+            token_out_denom == token_out_min.denom,
+            StdError::generic_err(format!(
+                "token_out_min denom {} doesn't match the output denom of the routes {}",
+                token_out_min.denom, token_out_denom
+            ))
+        );
+
+        Ok(MsgSwapExactAmountIn {
+            sender: sender.to_string(),
+            routes,
+            token_in: Some(token_in.into()),
+            token_out_min_amount: token_out_min.amount.to_string(),
+        }
+        .into())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use cosmwasm_std::coin;
+        use osmosis_std::types::cosmos::base::v1beta1::Coin as ProtoCoin;
+
+        const SENDER: &str = "osmo1sender";
+
+        fn proto_coin(amount: u128, denom: &str) -> ProtoCoin {
+            ProtoCoin {
+                denom: denom.to_string(),
+                amount: amount.to_string(),
+            }
+        }
+
+        #[test]
+        fn test_build_gamm_join_pool_msg() {
+            let msg = build_gamm_join_pool_msg(
+                SENDER,
+                1,
+                Uint128::new(500),
+                vec![coin(100, "uatom"), coin(200, "uosmo")],
+            );
+
+            let expected: CosmosMsg = MsgJoinPool {
+                sender: SENDER.to_string(),
+                pool_id: 1,
+                share_out_amount: "500".to_string(),
+                token_in_maxs: vec![proto_coin(100, "uatom"), proto_coin(200, "uosmo")],
+            }
+            .into();
+            assert_eq!(msg, expected);
+        }
+
+        #[test]
+        fn test_build_gamm_exit_pool_msg() {
+            let msg =
+                build_gamm_exit_pool_msg(SENDER, 1, Uint128::new(500), vec![coin(10, "uatom")]);
+
+            let expected: CosmosMsg = MsgExitPool {
+                sender: SENDER.to_string(),
+                pool_id: 1,
+                share_in_amount: "500".to_string(),
+                token_out_mins: vec![proto_coin(10, "uatom")],
+            }
+            .into();
+            assert_eq!(msg, expected);
+        }
+
+        #[test]
+        fn test_build_gamm_swap_msg() {
+            let routes = vec![
+                SwapAmountInRoute {
+                    pool_id: 1,
+                    token_out_denom: "uosmo".to_string(),
+                },
+                SwapAmountInRoute {
+                    pool_id: 2,
+                    token_out_denom: "untrn".to_string(),
+                },
+            ];
+            let msg = build_gamm_swap_msg(
+                SENDER,
+                routes.clone(),
+                coin(100, "uatom"),
+                coin(90, "untrn"),
+            )
+            .unwrap();
+
+            let expected: CosmosMsg = MsgSwapExactAmountIn {
+                sender: SENDER.to_string(),
+                routes,
+                token_in: Some(proto_coin(100, "uatom")),
+                token_out_min_amount: "90".to_string(),
+            }
+            .into();
+            assert_eq!(msg, expected);
+        }
+
+        #[test]
+        fn test_build_gamm_swap_msg_denom_mismatch() {
+            let routes = vec![SwapAmountInRoute {
+                pool_id: 1,
+                token_out_denom: "uosmo".to_string(),
+            }];
+
+            // The minimum must be in the output denom of the last route
+            let err = build_gamm_swap_msg(SENDER, routes, coin(100, "uatom"), coin(90, "untrn"))
+                .unwrap_err();
+            assert_eq!(
+                err,
+                StdError::generic_err(
+                    "token_out_min denom untrn doesn't match the output denom of the routes uosmo"
+                )
+            );
+        }
+
+        #[test]
+        fn test_build_gamm_swap_msg_empty_routes() {
+            let err = build_gamm_swap_msg(SENDER, vec![], coin(100, "uatom"), coin(90, "untrn"))
+                .unwrap_err();
+            assert_eq!(err, StdError::generic_err("swap routes can't be empty"));
+        }
+    }
+}
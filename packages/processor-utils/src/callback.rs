@@ -32,3 +32,14 @@ pub enum PolytoneCallbackMsg {
     ExecutionID(u64),
     CreateProxy,
 }
+
+#[cw_serde]
+pub struct ExecutionRecord {
+    pub batch_id: u64,
+    pub result: ExecutionResult,
+    // Block time (in seconds) when the result was produced
+    pub timestamp: u64,
+    // Library of the function the batch was on when the result was produced (first function for atomic batches)
+    pub library: Addr,
+    pub error: Option<String>,
+}
@@ -7,8 +7,8 @@ use valence_authorization_utils::{
 use valence_polytone_utils::polytone::CallbackMessage;
 
 use crate::{
-    callback::PendingPolytoneCallbackInfo,
-    processor::{Config, MessageBatch},
+    callback::{ExecutionRecord, PendingPolytoneCallbackInfo},
+    processor::{CircuitBreaker, Config, MessageBatch},
 };
 
 #[cw_serde]
@@ -16,6 +16,8 @@ pub struct InstantiateMsg {
     pub authorization_contract: String,
     // In case the processor is sitting on a different domain
     pub polytone_contracts: Option<PolytoneContracts>,
+    // Optional circuit breaker to stop executing libraries that keep failing
+    pub circuit_breaker: Option<CircuitBreaker>,
 }
 
 #[cw_serde]
@@ -43,6 +45,8 @@ pub enum AuthorizationMsg {
         msgs: Vec<ProcessorMessage>,
         subroutine: Subroutine,
         priority: Priority,
+        // Absolute time (in seconds) after which the messages can't be executed anymore
+        expiration_time: Option<u64>,
     },
     EvictMsgs {
         queue_position: u64,
@@ -54,15 +58,27 @@ pub enum AuthorizationMsg {
         msgs: Vec<ProcessorMessage>,
         subroutine: Subroutine,
         priority: Priority,
+        // Absolute time (in seconds) after which the messages can't be executed anymore
+        expiration_time: Option<u64>,
     },
     Pause {},
     Resume {},
+    // Closes the circuit breaker of a library so that its batches are processed again
+    ResetCircuitBreaker {
+        library: String,
+    },
 }
 
 #[cw_serde]
 pub enum PermissionlessMsg {
-    Tick {},
-    RetryCallback { execution_id: u64 },
+    Tick {
+        // If true, nothing is executed and the messages that would be dispatched are returned as attributes
+        #[serde(default)]
+        simulate: bool,
+    },
+    RetryCallback {
+        execution_id: u64,
+    },
     RetryBridgeCreation {},
 }
 
@@ -93,4 +109,10 @@ pub enum QueryMsg {
     },
     #[returns(PendingPolytoneCallbackInfo)]
     PendingPolytoneCallback { execution_id: u64 },
+    // Most recent execution results, from oldest to newest
+    #[returns(Vec<ExecutionRecord>)]
+    GetExecutionHistory {
+        start: Option<u64>,
+        limit: Option<u32>,
+    },
 }
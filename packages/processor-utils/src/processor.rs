@@ -1,5 +1,5 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Binary, CosmosMsg, StdError, StdResult, SubMsg, WasmMsg};
+use cosmwasm_std::{Addr, Binary, BlockInfo, CosmosMsg, StdError, StdResult, SubMsg, WasmMsg};
 use cw_utils::Expiration;
 use serde_json::{json, Value};
 use valence_authorization_utils::{
@@ -15,6 +15,16 @@ pub struct Config {
     pub authorization_contract: String,
     pub processor_domain: ProcessorDomain,
     pub state: State,
+    // If set, libraries that fail too many times in a row will stop being executed
+    pub circuit_breaker: Option<CircuitBreaker>,
+}
+
+#[cw_serde]
+pub struct CircuitBreaker {
+    // Amount of consecutive failures of a library after which its batches won't be processed anymore
+    pub failure_threshold: u32,
+    // Amount of blocks after the last failure of a library after which its circuit breaker resets automatically
+    pub reset_after_blocks: u64,
 }
 
 #[cw_serde]
@@ -49,6 +59,8 @@ pub struct MessageBatch {
     pub subroutine: Subroutine,
     pub priority: Priority,
     pub retry: Option<CurrentRetry>,
+    // Absolute time (in seconds) after which the batch can't be executed anymore
+    pub expiration_time: Option<u64>,
 }
 
 impl From<MessageBatch> for Vec<CosmosMsg> {
@@ -85,6 +97,12 @@ fn create_cosmos_msg(msg: ProcessorMessage, contract_address: String) -> CosmosM
 }
 
 impl MessageBatch {
+    /// Checks if the batch has passed its expiration time and can't be executed anymore
+    pub fn is_expired(&self, block: &BlockInfo) -> bool {
+        self.expiration_time
+            .is_some_and(|expiration_time| block.time.seconds() >= expiration_time)
+    }
+
     /// This is used for non-atomic batches. We need to catch the reply always because we need to know if the message was successful to continue
     /// with the next message in the batch or apply the retry logic
     pub fn create_message_by_index(&self, index: usize) -> Vec<SubMsg> {
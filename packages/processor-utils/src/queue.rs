@@ -58,6 +58,18 @@ where
         Ok(Some(value))
     }
 
+    pub fn peek_front(&self, storage: &dyn Storage) -> StdResult<Option<T>> {
+        let start_index = self.start_index(storage)?;
+        let end_index = self.end_index(storage)?;
+
+        if start_index == end_index {
+            return Ok(None);
+        }
+
+        self.elements
+            .may_load(storage, start_index.checked_add(1).expect("Overflow"))
+    }
+
     pub fn insert_at(&mut self, storage: &mut dyn Storage, index: u64, value: &T) -> StdResult<()> {
         let len = self.len(storage)?;
         if index > len {
@@ -201,6 +213,31 @@ mod tests {
         assert!(queue.is_empty(storage).unwrap());
     }
 
+    #[test]
+    fn test_peek_front() {
+        let mut deps = mock_dependencies();
+        let storage = &mut deps.storage;
+        let queue = QueueMap::new("elements", "elements_start_index", "elements_end_index");
+
+        assert_eq!(queue.peek_front(storage).unwrap(), None);
+
+        queue.push_back(storage, &"first".to_string()).unwrap();
+        queue.push_back(storage, &"second".to_string()).unwrap();
+
+        // Peeking doesn't remove the element
+        assert_eq!(
+            queue.peek_front(storage).unwrap(),
+            Some("first".to_string())
+        );
+        assert_eq!(queue.len(storage).unwrap(), 2);
+
+        queue.pop_front(storage).unwrap();
+        assert_eq!(
+            queue.peek_front(storage).unwrap(),
+            Some("second".to_string())
+        );
+    }
+
     #[test]
     fn test_insert_and_remove_at() {
         let mut deps = mock_dependencies();
@@ -12,10 +12,41 @@ pub struct InstantiateMsg {
 pub enum ExecuteMsg {
     /// "Lock" an id for a program to avoid race conditions
     ReserveId {},
-    /// Save a new program config for the id
-    SaveProgram { id: u64, program_config: Binary },
-    /// Update a program config for the id
-    UpdateProgram { id: u64, program_config: Binary },
+    /// Save a new program config for the id, owned by the owner of the program
+    SaveProgram {
+        id: u64,
+        owner: String,
+        program_config: Binary,
+        /// Tags to categorize the program, at most 10 tags of up to 32 characters each
+        #[serde(default)]
+        tags: Vec<String>,
+        #[serde(default)]
+        description: Option<String>,
+    },
+    /// Update a program config for the id, owner, tags and description are only replaced if provided
+    UpdateProgram {
+        id: u64,
+        program_config: Binary,
+        #[serde(default)]
+        owner: Option<String>,
+        #[serde(default)]
+        tags: Option<Vec<String>>,
+        #[serde(default)]
+        description: Option<String>,
+    },
+    /// Mark a program as deprecated, it stays queryable. Only the owner of the program can deprecate it
+    DeprecateProgram { id: u64, reason: String },
+}
+
+#[cw_serde]
+pub enum MigrateMsg {
+    /// Index the owners of the programs that were saved before programs were indexed by owner.
+    /// The owner is read from the program config. Programs are processed by id in pages of `limit`,
+    /// so the migration can be repeated with `start_after` until all programs are indexed
+    BackfillOwners {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
@@ -28,10 +59,34 @@ pub enum QueryMsg {
     /// returns None if there is no backup
     #[returns(Option<ProgramResponse>)]
     GetConfigBackup { id: u64 },
+    /// Gets the program configs of an owner, ordered by id
+    #[returns(Vec<ProgramResponse>)]
+    GetConfigsByOwner {
+        owner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the deprecated program configs, ordered by id
+    #[returns(Vec<ProgramResponse>)]
+    GetDeprecatedPrograms {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the program configs with a tag, ordered by id
+    #[returns(Vec<ProgramResponse>)]
+    GetProgramsByTag {
+        tag: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
 #[cw_serde]
 pub struct ProgramResponse {
     pub id: u64,
     pub program_config: Binary,
+    pub is_deprecated: bool,
+    pub deprecation_reason: Option<String>,
+    pub tags: Vec<String>,
+    pub description: Option<String>,
 }
@@ -10,6 +10,67 @@ pub(crate) fn has_skip_update_attr(attrs: &[Attribute]) -> bool {
     attrs.iter().any(|attr| attr.path().is_ident("skip_update"))
 }
 
+/// Validations that can be set on a field with `#[validate(...)]`
+pub(crate) enum FieldValidation {
+    NonEmpty,
+    Positive,
+    Addr,
+}
+
+// Parse all the `#[validate(...)]` attributes on the field.
+pub(crate) fn get_validate_attrs(attrs: &[Attribute]) -> syn::Result<Vec<FieldValidation>> {
+    let mut validations = vec![];
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("validate")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("non_empty") {
+                validations.push(FieldValidation::NonEmpty);
+            } else if meta.path.is_ident("positive") {
+                validations.push(FieldValidation::Positive);
+            } else if meta.path.is_ident("addr") {
+                validations.push(FieldValidation::Addr);
+            } else {
+                return Err(meta.error("expected one of: non_empty, positive, addr"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(validations)
+}
+
+// Generate the validation calls for a value of a field.
+pub(crate) fn generate_validations(
+    validations: &[FieldValidation],
+    field_name: &str,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let calls = validations.iter().map(|validation| match validation {
+        FieldValidation::NonEmpty => quote! {
+            valence_library_utils::validation::validate_non_empty(#field_name, #value)?;
+        },
+        FieldValidation::Positive => quote! {
+            valence_library_utils::validation::validate_positive(#field_name, #value)?;
+        },
+        FieldValidation::Addr => quote! {
+            valence_library_utils::validation::validate_addr(#field_name, #value, api)?;
+        },
+    });
+
+    quote! { #(#calls)* }
+}
+
+// Check if the last segment of the type path is `ident`, e.g. `LibraryAccountType`
+pub(crate) fn is_type(ty: &Type, ident: &str) -> bool {
+    match ty {
+        Type::Path(TypePath { path, .. }) => path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == ident),
+        _ => false,
+    }
+}
+
 pub(crate) fn get_option_inner_type(ty: &Type) -> Option<&Type> {
     if let Type::Path(TypePath { path, .. }) = ty {
         if let Some(segment) = path.segments.last() {
@@ -1,11 +1,14 @@
 mod helpers;
 
-use helpers::{get_option_inner_type, has_skip_update_attr, merge_variants};
+use helpers::{
+    generate_validations, get_option_inner_type, get_validate_attrs, has_skip_update_attr, is_type,
+    merge_variants, FieldValidation,
+};
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
 use syn::{parse_macro_input, Data, DeriveInput, Fields, Type};
 
-#[proc_macro_derive(ValenceLibraryInterface, attributes(skip_update))]
+#[proc_macro_derive(ValenceLibraryInterface, attributes(skip_update, validate))]
 pub fn valence_library_interface_derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let name = &ast.ident;
@@ -42,22 +45,61 @@ pub fn valence_library_interface_derive(input: TokenStream) -> TokenStream {
         }
     });
 
-    let update_fields = filtered_fields.iter().map(|f| {
+    let mut update_fields = Vec::with_capacity(filtered_fields.len());
+    for f in filtered_fields.iter() {
         let name = &f.ident;
-
-        if get_option_inner_type(&f.ty).is_some() {
+        let field_name = name
+            .as_ref()
+            .map(|name| name.to_string())
+            .unwrap_or_default();
+        let validations = match get_validate_attrs(&f.attrs) {
+            Ok(validations) => validations,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+        // Validate the new value before it is written to storage
+        let validate = generate_validations(&validations, &field_name, &quote!(value));
+        let update_field = if get_option_inner_type(&f.ty).is_some() {
+            // Only a set value is validated, the field can always be unset
+            let validate = (!validations.is_empty()).then(|| {
+                quote! {
+                    if let Some(value) = value {
+                        #validate
+                    }
+                }
+            });
             quote! {
                 match &self.#name {
-                    OptionUpdate::Set(value) => raw_config.#name = value.clone(),
+                    OptionUpdate::Set(value) => {
+                        #validate
+                        raw_config.#name = value.clone();
+                    }
+                    OptionUpdate::Clear => raw_config.#name = None,
                     OptionUpdate::None => {}
                 }
             }
         } else {
             quote! {
                 if let Some(value) = &self.#name {
+                    #validate
                     raw_config.#name = value.clone();
                 }
             }
+        };
+        update_fields.push(update_field);
+    }
+
+    let has_changes_fields = filtered_fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if get_option_inner_type(&f.ty).is_some() {
+            quote! {
+                !matches!(self.#name, OptionUpdate::None)
+            }
+        } else {
+            quote! {
+                self.#name.is_some()
+            }
         }
     });
 
@@ -79,6 +121,26 @@ pub fn valence_library_interface_derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    let apply_update_fields = filtered_fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if get_option_inner_type(&f.ty).is_some() {
+            quote! {
+                match &update.#name {
+                    OptionUpdate::Set(value) => self.#name = value.clone(),
+                    OptionUpdate::Clear => self.#name = None,
+                    OptionUpdate::None => {}
+                }
+            }
+        } else {
+            quote! {
+                if let Some(value) = &update.#name {
+                    self.#name = value.clone();
+                }
+            }
+        }
+    });
+
     // New: Generate fields for into_update implementation
     let into_update_fields = filtered_fields.iter().map(|f| {
         let name = &f.ident;
@@ -105,13 +167,18 @@ pub fn valence_library_interface_derive(input: TokenStream) -> TokenStream {
         }
 
         impl LibraryConfigUpdateTrait for #filter_name {
-            fn update_raw(&self, storage: &mut dyn cosmwasm_std::Storage) -> StdResult<()> {
+            #[allow(unused_variables)]
+            fn update_raw(&self, api: &dyn cosmwasm_std::Api, storage: &mut dyn cosmwasm_std::Storage) -> StdResult<()> {
                 let mut raw_config = load_raw_library_config::<#name>(storage)?;
 
                 #(#update_fields)*
 
                 save_raw_library_config(storage, &raw_config)
             }
+
+            fn has_changes(&self) -> bool {
+                false #(|| #has_changes_fields)*
+            }
         }
 
         impl #name {
@@ -128,6 +195,11 @@ pub fn valence_library_interface_derive(input: TokenStream) -> TokenStream {
                 }
             }
 
+            /// Apply the set fields of the update on this config
+            pub fn apply_update(&mut self, update: &#filter_name) {
+                #(#apply_update_fields)*
+            }
+
             pub fn into_update(&self) -> #filter_name {
                 #filter_name {
                     #(#into_update_fields)*
@@ -139,6 +211,68 @@ pub fn valence_library_interface_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Implements `HasAddressFields` for a config struct, so all its addresses can be validated at once.
+/// `LibraryAccountType` fields and fields annotated with `#[validate(addr)]` are address fields,
+/// optional fields are only validated when set.
+#[proc_macro_derive(ValenceAddressFields, attributes(validate))]
+pub fn valence_address_fields_derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let name = &ast.ident;
+
+    let fields = match &ast.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields,
+            _ => panic!("ValenceAddressFields only works on structs with named fields"),
+        },
+        _ => panic!("ValenceAddressFields only works on structs"),
+    };
+
+    let mut pushes = vec![];
+    for f in fields.named.iter() {
+        let ident = &f.ident;
+        let field_name = ident
+            .as_ref()
+            .map(|ident| ident.to_string())
+            .unwrap_or_default();
+        let validations = match get_validate_attrs(&f.attrs) {
+            Ok(validations) => validations,
+            Err(err) => return err.to_compile_error().into(),
+        };
+        let has_addr_attr = validations
+            .iter()
+            .any(|validation| matches!(validation, FieldValidation::Addr));
+
+        match get_option_inner_type(&f.ty) {
+            Some(inner_type) if has_addr_attr || is_type(inner_type, "LibraryAccountType") => {
+                pushes.push(quote! {
+                    if let Some(value) = &self.#ident {
+                        fields.push((#field_name, value));
+                    }
+                });
+            }
+            None if has_addr_attr || is_type(&f.ty, "LibraryAccountType") => {
+                pushes.push(quote! {
+                    fields.push((#field_name, &self.#ident));
+                });
+            }
+            _ => {}
+        }
+    }
+
+    quote! {
+        impl valence_library_utils::validation::HasAddressFields for #name {
+            fn address_fields(
+                &self,
+            ) -> Vec<(&'static str, &dyn valence_library_utils::validation::ValidateAddr)> {
+                let mut fields: Vec<(&'static str, &dyn valence_library_utils::validation::ValidateAddr)> = vec![];
+                #(#pushes)*
+                fields
+            }
+        }
+    }
+    .into()
+}
+
 #[proc_macro_attribute]
 pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> TokenStream {
     // Parse the input tokens into a syntax tree
@@ -160,31 +294,53 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
     let mut get_instantiate_msg_matches = Vec::new();
     let mut per_validate_matches = Vec::new();
     let mut get_account_ids_matches = Vec::new();
+    let mut apply_update_matches = Vec::new();
 
     for variant in variants {
         let variant_ident = &variant.ident;
+        // Keep the docs of the variant on the update variant and on the match arms of the variant
+        let docs: Vec<_> = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"))
+            .collect();
+        // Doc comments are not used on match arms, they are only there to read the expanded code
+        let arm_docs = if docs.is_empty() {
+            quote! {}
+        } else {
+            quote! {
+                #[allow(unused_doc_comments)]
+                #(#docs)*
+            }
+        };
 
         if variant_ident == "None" {
             // Add None variant
             update_variants.push(quote! {
+                #(#docs)*
                 #[default]
                 None
             });
 
             // Add None matches for all methods
             update_msg_matches.push(quote! {
+                #arm_docs
                 #update_enum_ident::None => return Err(LibraryError::NoLibraryConfigUpdate)
             });
             replace_config_matches.push(quote! {
+                #arm_docs
                 #enum_ident::None => return Err(LibraryError::NoLibraryConfig)
             });
             get_instantiate_msg_matches.push(quote! {
+                #arm_docs
                 #enum_ident::None => return Err(LibraryError::NoLibraryConfig)
             });
             per_validate_matches.push(quote! {
+                #arm_docs
                 #enum_ident::None => Err(LibraryError::NoLibraryConfig)
             });
             get_account_ids_matches.push(quote! {
+                #arm_docs
                 #enum_ident::None => Err(LibraryError::NoLibraryConfig)
             });
             continue;
@@ -205,11 +361,13 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
 
                     // Add update variant
                     update_variants.push(quote! {
+                        #(#docs)*
                         #variant_ident(#new_path)
                     });
 
                     // Add get_update_msg match for update enum
                     update_msg_matches.push(quote! {
+                        #arm_docs
                         #update_enum_ident::#variant_ident(service_config_update) => {
                             to_json_binary(&valence_library_utils::msg::ExecuteMsg::<
                                 Empty,
@@ -222,6 +380,7 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
 
                     // Add replace_config match
                     replace_config_matches.push(quote! {
+                        #arm_docs
                         #enum_ident::#variant_ident(ref mut config) => {
                             let json = serde_json::to_string(&config)?;
                             let res = ac.replace_all(&json, &replace_with);
@@ -231,6 +390,7 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
 
                     // Add get_instantiate_msg match
                     get_instantiate_msg_matches.push(quote! {
+                        #arm_docs
                         #enum_ident::#variant_ident(config) => to_vec(&InstantiateMsg {
                             owner,
                             processor,
@@ -240,6 +400,7 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
 
                     // Add per_validate_config match
                     per_validate_matches.push(quote! {
+                        #arm_docs
                         #enum_ident::#variant_ident(config) => {
                             config.pre_validate(api)?;
                             Ok(())
@@ -248,10 +409,20 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
 
                     // Add get_account_ids match
                     get_account_ids_matches.push(quote! {
+                        #arm_docs
                         #enum_ident::#variant_ident(config) => {
                             Self::find_account_ids(ac, serde_json::to_string(&config)?)
                         }
                     });
+
+                    // Add apply_update match
+                    apply_update_matches.push(quote! {
+                        #arm_docs
+                        (#enum_ident::#variant_ident(config), #update_enum_ident::#variant_ident(update)) => {
+                            config.apply_update(update);
+                            Ok(())
+                        }
+                    });
                 } else {
                     panic!("Expected Path type");
                 }
@@ -323,6 +494,19 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
                     #(#get_account_ids_matches,)*
                 }
             }
+
+            /// Apply an update on the config, the update must be for the same library as the config
+            pub fn apply_update(&mut self, update: &#update_enum_ident) -> LibraryResult<()> {
+                match (self, update) {
+                    (#enum_ident::None, _) => Err(LibraryError::NoLibraryConfig),
+                    (_, #update_enum_ident::None) => Err(LibraryError::NoLibraryConfigUpdate),
+                    #(#apply_update_matches,)*
+                    (config, update) => Err(LibraryError::ConfigsMismatch(
+                        config.to_string(),
+                        update.to_string(),
+                    )),
+                }
+            }
         }
 
         #input_enum
@@ -331,23 +515,51 @@ pub fn manager_impl_library_configs(_attr: TokenStream, input: TokenStream) -> T
     expanded.into()
 }
 
+/// Adds the base library queries to the query enum.
+///
+/// Extra queries that are shared between libraries can be added by passing an enum with them:
+///
+/// ```ignore
+/// #[valence_library_query(
+///     enum ExecutionQueries {
+///         #[returns(u64)]
+///         GetExecutionCount {},
+///     }
+/// )]
+/// #[cw_serde]
+/// #[derive(QueryResponses)]
+/// pub enum QueryMsg {}
+/// ```
 #[proc_macro_attribute]
 pub fn valence_library_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
-    merge_variants(
-        metadata,
+    let merged = merge_variants(
+        TokenStream::new(),
         input,
         quote!(
             enum ValenceLibraryQuery {
                 /// Query to get the processor address.
                 #[returns(Addr)]
                 GetProcessor {},
+                /// Query to get all the processors that can execute the library functions, including the primary one.
+                #[returns(Vec<Addr>)]
+                GetProcessors {},
                 /// Query to get the library configuration.
                 #[returns(Config)]
                 GetLibraryConfig {},
                 #[returns(LibraryConfig)]
                 GetRawLibraryConfig {},
+                /// Query to get the config version, the address that last updated it and the block height of the update.
+                /// Returns None if the config was never updated since instantiation.
+                #[returns(Option<(u64, Addr, u64)>)]
+                GetConfigVersion {},
             }
         )
         .into(),
-    )
+    );
+
+    if metadata.is_empty() {
+        merged
+    } else {
+        merge_variants(TokenStream::new(), merged, metadata)
+    }
 }
@@ -0,0 +1,5 @@
+#[test]
+fn valence_library_query() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/library_query_extra_queries.rs");
+}
@@ -0,0 +1,41 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+use valence_macros::valence_library_query;
+
+#[cw_serde]
+pub struct Config {}
+
+#[cw_serde]
+pub struct LibraryConfig {}
+
+#[valence_library_query(
+    enum ExecutionQueries {
+        /// Query to get the number of executions of the library.
+        #[returns(u64)]
+        GetExecutionCount {},
+    }
+)]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Addr)]
+    GetOutputAccount {},
+}
+
+fn main() {
+    // The base queries and the extra queries are merged into the query enum
+    let responses = QueryMsg::response_schemas().unwrap();
+    for query in [
+        "get_output_account",
+        "get_processor",
+        "get_processors",
+        "get_library_config",
+        "get_raw_library_config",
+        "get_config_version",
+        "get_execution_count",
+    ] {
+        assert!(responses.contains_key(query), "missing query {query}");
+    }
+
+    let _ = QueryMsg::GetExecutionCount {};
+}
@@ -1,6 +1,7 @@
 use cosmwasm_schema::{cw_serde, write_api};
 use valence_program_manager::{
-    program_config::ProgramConfig, program_migration::ProgramConfigMigrate,
+    program_config::{export_program_config_schema, ProgramConfig},
+    program_migration::ProgramConfigMigrate,
     program_update::ProgramConfigUpdate,
 };
 
@@ -12,6 +13,15 @@ struct Types {
 }
 
 fn main() {
+    // Print only the program config schema, to be used by external program builders
+    if std::env::args().any(|arg| arg == "--print-schema") {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&export_program_config_schema()).unwrap()
+        );
+        return;
+    }
+
     write_api! {
         instantiate: Types,
     }
@@ -1,9 +1,15 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    future::Future,
+    time::{Duration, Instant},
+};
 
+use dashmap::DashMap;
+use log::warn;
 use once_cell::sync::Lazy;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use thiserror::Error;
-use tokio::sync::Mutex;
+use tokio::{sync::Mutex, time::sleep};
 
 use crate::bridge::Bridge;
 
@@ -11,6 +17,21 @@ pub type ConfigResult<T> = Result<T, ConfigError>;
 
 pub static GLOBAL_CONFIG: Lazy<Mutex<Config>> = Lazy::new(|| Mutex::new(Config::default()));
 
+/// The index of the next endpoint to use per chain, used by the round robin endpoint selector
+static NEXT_ENDPOINT: Lazy<DashMap<String, usize>> = Lazy::new(DashMap::new);
+
+/// The latency of the last successful request per endpoint, used by the latency endpoint selector
+static ENDPOINT_LATENCY: Lazy<DashMap<String, Duration>> = Lazy::new(DashMap::new);
+
+/// When an endpoint last failed, recently failed endpoints are tried last
+static FAILED_ENDPOINTS: Lazy<DashMap<String, Instant>> = Lazy::new(DashMap::new);
+
+/// Backoff before the first retry, it is doubled on every retry after
+const BASE_BACKOFF_MS: u64 = 250;
+
+/// How long a failed endpoint is moved to the back of the list
+const FAILED_ENDPOINT_COOLDOWN: Duration = Duration::from_secs(60);
+
 #[derive(Error, Debug)]
 pub enum ConfigError {
     #[error("Chain not found for: {0}")]
@@ -24,6 +45,12 @@ pub enum ConfigError {
 
     #[error("Bridge details not found for: {0}")]
     ChainBridgeNotFound(String),
+
+    #[error("No endpoints configured for: {0}")]
+    NoEndpoints(String),
+
+    #[error("All endpoints failed for: {chain}, last error: {error}")]
+    EndpointsFailed { chain: String, error: String },
 }
 
 #[derive(Debug, Deserialize, Default)]
@@ -78,13 +105,149 @@ pub struct Config {
 pub struct ChainInfo {
     pub name: String,
     pub rpc: String,
-    pub grpc: String,
+    /// List of grpc endpoints, if a request to one fails we move to the next one
+    #[serde(alias = "grpc", deserialize_with = "deserialize_endpoints")]
+    pub endpoints: Vec<String>,
+    /// How we pick the endpoint to use first
+    #[serde(default)]
+    pub endpoint_selector: EndpointSelector,
+    /// How many times we retry a failed request on the next endpoint before giving up
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u8,
     pub prefix: String,
     pub gas_price: String,
     pub gas_denom: String,
     pub coin_type: u64,
 }
 
+pub fn default_max_retries() -> u8 {
+    3
+}
+
+/// Accept a single endpoint as well, so configs that still use `grpc = "..."` keep working
+fn deserialize_endpoints<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(endpoint) => vec![endpoint],
+        OneOrMany::Many(endpoints) => endpoints,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointSelector {
+    /// Always start with the first endpoint, the others are only used when it fails
+    #[default]
+    Primary,
+    /// Start with the endpoint after the last one that was used successfully
+    RoundRobin,
+    /// Start with the endpoint that had the lowest latency on previous requests,
+    /// endpoints we didn't measure yet are tried first
+    Latency,
+}
+
+impl ChainInfo {
+    /// Send a request to the endpoints of the chain based on the endpoint selector.
+    /// If the request fails, we back off exponentially and retry on the next endpoint, up to `max_retries` times.
+    /// Returns the endpoint that succeeded with the response.
+    pub async fn with_failover<T, F, Fut>(&self, mut request: F) -> ConfigResult<(String, T)>
+    where
+        F: FnMut(String) -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        if self.endpoints.is_empty() {
+            return Err(ConfigError::NoEndpoints(self.name.clone()));
+        }
+
+        let endpoints = self.ordered_endpoints();
+        let mut last_error = String::new();
+
+        for attempt in 0..=u32::from(self.max_retries) {
+            if attempt > 0 {
+                sleep(Duration::from_millis(BASE_BACKOFF_MS << (attempt - 1))).await;
+            }
+
+            let endpoint = &endpoints[attempt as usize % endpoints.len()];
+            let start = Instant::now();
+            let res = request(endpoint.clone()).await;
+
+            match res {
+                Ok(res) => {
+                    if let Some(index) = self.endpoints.iter().position(|e| e == endpoint) {
+                        NEXT_ENDPOINT.insert(self.name.clone(), (index + 1) % self.endpoints.len());
+                    }
+                    ENDPOINT_LATENCY.insert(endpoint.clone(), start.elapsed());
+                    FAILED_ENDPOINTS.remove(endpoint);
+
+                    return Ok((endpoint.clone(), res));
+                }
+                Err(err) => {
+                    self.mark_endpoint_failed(endpoint);
+                    warn!(
+                        "Request to {} failed (attempt {}/{}): {}",
+                        self.name,
+                        attempt + 1,
+                        u32::from(self.max_retries) + 1,
+                        err
+                    );
+                    last_error = err.to_string();
+                }
+            }
+        }
+
+        Err(ConfigError::EndpointsFailed {
+            chain: self.name.clone(),
+            error: last_error,
+        })
+    }
+
+    /// Mark an endpoint as failed, it will be tried last until the cooldown is over.
+    /// Connectors call this when a request fails after the client was created, so reconnecting moves to another endpoint.
+    pub fn mark_endpoint_failed(&self, endpoint: &str) {
+        FAILED_ENDPOINTS.insert(endpoint.to_string(), Instant::now());
+    }
+
+    /// The endpoints in the order we should try them
+    pub(crate) fn ordered_endpoints(&self) -> Vec<String> {
+        let mut endpoints = self.endpoints.clone();
+
+        match self.endpoint_selector {
+            EndpointSelector::Primary => {}
+            EndpointSelector::RoundRobin => {
+                let next = NEXT_ENDPOINT.get(&self.name).map(|next| *next).unwrap_or(0);
+                endpoints.rotate_left(next % endpoints.len());
+            }
+            EndpointSelector::Latency => {
+                // Endpoints without a measurement sort first, so every endpoint gets measured
+                endpoints.sort_by_key(|endpoint| {
+                    ENDPOINT_LATENCY
+                        .get(endpoint)
+                        .map(|latency| *latency)
+                        .unwrap_or_default()
+                });
+            }
+        }
+
+        // Recently failed endpoints go last, the sort is stable so the order above is kept
+        endpoints.sort_by_key(|endpoint| {
+            FAILED_ENDPOINTS
+                .get(endpoint)
+                .is_some_and(|failed_at| failed_at.elapsed() < FAILED_ENDPOINT_COOLDOWN)
+        });
+
+        endpoints
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct GeneralConfig {
     pub registry_addr: String,
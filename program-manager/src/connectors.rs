@@ -1,16 +1,70 @@
-use dashmap::DashMap;
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use cosmwasm_std::Decimal;
+use dashmap::{mapref::entry::Entry, DashMap, DashSet};
+use log::warn;
+use tokio::{task::JoinHandle, time::sleep};
 
 use crate::{
     domain::{Connector, Domain},
     error::{ManagerError, ManagerResult},
+    gas_price::{CachedGasPriceOracle, GasPriceOracle},
 };
 
+/// How many times we try to reconnect to a chain before we mark it as unhealthy
+const RECONNECT_RETRIES: u64 = 3;
+
+/// The status of the connection to a chain from the last health check
+#[derive(Debug, Clone)]
+pub struct ConnectionStatus {
+    pub last_checked: Instant,
+    pub latency_ms: u64,
+    pub is_healthy: bool,
+}
+
 #[derive(Debug, Default)]
 pub struct Connectors {
     connectors: DashMap<Domain, Box<dyn Connector>>,
+    /// The domains we created a connector for, kept apart so the monitor can list them without waiting on a connector in use
+    domains: DashSet<Domain>,
+    /// Key: chain name | Value: status of the connection
+    statuses: DashMap<String, ConnectionStatus>,
+    gas_price_oracle: CachedGasPriceOracle,
 }
 
 impl Connectors {
+    /// Use a different gas price oracle than the default cosmos one
+    pub fn with_gas_price_oracle(mut self, oracle: Box<dyn GasPriceOracle>) -> Self {
+        self.gas_price_oracle = CachedGasPriceOracle::new(oracle);
+        self
+    }
+
+    /// Get the current gas price of a chain, prices are cached for 30 seconds
+    pub async fn get_gas_price(&self, chain: &str) -> ManagerResult<Decimal> {
+        self.gas_price_oracle.get_gas_price(chain).await
+    }
+
+    /// Create a connector for the domain that pays its fees with the current gas price of the chain
+    async fn generate_connector(&self, domain: &Domain) -> ManagerResult<Box<dyn Connector>> {
+        // If we can't get the current gas price, we fallback to the gas price in the config
+        let gas_price = match self.get_gas_price(domain.get_chain_name()).await {
+            Ok(gas_price) => Some(gas_price),
+            Err(err) => {
+                warn!(
+                    "Failed to get the gas price of {}, using the configured gas price: {}",
+                    domain, err
+                );
+                None
+            }
+        };
+
+        Ok(domain.generate_connector(gas_price).await?)
+    }
+
     /// Get the domain from ctx if exists
     /// otherwise it gets a new domain connector and save it in cache
     pub async fn get_or_create_connector(
@@ -18,8 +72,9 @@ impl Connectors {
         domain: &Domain,
     ) -> ManagerResult<dashmap::mapref::one::RefMut<'_, Domain, Box<dyn Connector>>> {
         if !self.connectors.contains_key(domain) {
-            let connector = domain.generate_connector().await?;
+            let connector = self.generate_connector(domain).await?;
             self.connectors.insert(domain.clone(), connector);
+            self.domains.insert(domain.clone());
         }
 
         self.connectors
@@ -28,4 +83,141 @@ impl Connectors {
                 "Failed to get connector from cache",
             ))
     }
+
+    /// Check the connection of all the connectors we have,
+    /// if a connection is dropped, we try to reconnect to the chain.
+    /// Returns if the connection to each chain is healthy after the check.
+    pub async fn health_check(&self) -> ManagerResult<HashMap<String, bool>> {
+        let domains = self
+            .connectors
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect::<Vec<_>>();
+
+        let mut health = HashMap::with_capacity(domains.len());
+
+        for domain in domains {
+            let mut is_healthy = self.check_connection(&domain).await?;
+
+            if !is_healthy {
+                is_healthy = self.reconnect(&domain).await?;
+            }
+
+            health.insert(domain.get_chain_name().to_string(), is_healthy);
+        }
+
+        Ok(health)
+    }
+
+    /// Get the status of the connection to a chain from the last health check
+    pub fn get_connection_status(&self, chain: &str) -> Option<ConnectionStatus> {
+        self.statuses.get(chain).map(|status| status.clone())
+    }
+
+    /// Health check the connectors every `interval` in the background, and reconnect the ones that became unavailable.
+    /// The task stops once the connectors are dropped.
+    pub fn spawn_connection_monitor(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let connectors = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            loop {
+                sleep(interval).await;
+
+                match connectors.upgrade() {
+                    Some(connectors) => connectors.monitor_connections().await,
+                    None => break,
+                }
+            }
+        })
+    }
+
+    /// Check the connectors that are not in use and replace the ones that are unavailable
+    async fn monitor_connections(&self) {
+        let domains = self
+            .domains
+            .iter()
+            .map(|domain| domain.clone())
+            .collect::<Vec<_>>();
+
+        for domain in domains {
+            // We take the connector out of the cache so we don't hold its lock while we wait on the chain.
+            // A locked connector is being used right now, its requests fail over to the next endpoint on their own.
+            let mut connector = match self.connectors.try_entry(domain.clone()) {
+                Some(Entry::Occupied(entry)) => entry.remove(),
+                _ => continue,
+            };
+
+            if !self.check_connector(&domain, connector.as_mut()).await {
+                match self.new_healthy_connector(&domain).await {
+                    Some(new_connector) => connector = new_connector,
+                    None => warn!("Connection to {} is still unavailable", domain),
+                }
+            }
+
+            // If a request created a new connector in the meantime, we keep that one
+            if let Some(Entry::Vacant(entry)) = self.connectors.try_entry(domain) {
+                entry.insert(connector);
+            }
+        }
+    }
+
+    /// Check the connection to the domain and save its status
+    async fn check_connection(&self, domain: &Domain) -> ManagerResult<bool> {
+        let mut connector = self.get_or_create_connector(domain).await?;
+
+        Ok(self
+            .check_connector(domain, connector.value_mut().as_mut())
+            .await)
+    }
+
+    /// Health check a connector and save the status of its chain
+    async fn check_connector(&self, domain: &Domain, connector: &mut dyn Connector) -> bool {
+        let start = Instant::now();
+        let is_healthy = connector.health_check().await.is_ok();
+
+        self.statuses.insert(
+            domain.get_chain_name().to_string(),
+            ConnectionStatus {
+                last_checked: Instant::now(),
+                latency_ms: start.elapsed().as_millis() as u64,
+                is_healthy,
+            },
+        );
+
+        is_healthy
+    }
+
+    /// Replace the connector of the domain with a new connection until it is healthy
+    async fn reconnect(&self, domain: &Domain) -> ManagerResult<bool> {
+        match self.new_healthy_connector(domain).await {
+            Some(connector) => {
+                self.connectors.insert(domain.clone(), connector);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Create new connectors for the domain until one of them is healthy
+    async fn new_healthy_connector(&self, domain: &Domain) -> Option<Box<dyn Connector>> {
+        for attempt in 1..=RECONNECT_RETRIES {
+            warn!(
+                "Connection to {} is unavailable, reconnecting (attempt {}/{})",
+                domain, attempt, RECONNECT_RETRIES
+            );
+
+            match self.generate_connector(domain).await {
+                Ok(mut connector) => {
+                    if self.check_connector(domain, connector.as_mut()).await {
+                        return Some(connector);
+                    }
+                }
+                Err(err) => warn!("Failed to reconnect to {}: {}", domain, err),
+            }
+
+            sleep(Duration::from_secs(attempt)).await;
+        }
+
+        None
+    }
 }
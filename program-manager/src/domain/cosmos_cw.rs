@@ -17,9 +17,10 @@ use anyhow::{anyhow, Context};
 use async_trait::async_trait;
 use cosmos_grpc_client::{
     cosmos_sdk_proto::{
+        cosmos::base::query::v1beta1::PageRequest,
         cosmos::tx::v1beta1::{GetTxRequest, GetTxResponse},
         cosmwasm::wasm::v1::{
-            MsgExecuteContract, MsgInstantiateContract2, QueryCodeRequest,
+            MsgExecuteContract, MsgInstantiateContract2, QueryCodeRequest, QueryCodesRequest,
             QueryContractInfoRequest, QuerySmartContractStateRequest,
         },
     },
@@ -28,11 +29,12 @@ use cosmos_grpc_client::{
 };
 use cosmwasm_std::{from_json, instantiate2_address, to_json_binary};
 use futures::future::BoxFuture;
+use log::warn;
 use serde_json::to_vec;
 use strum::VariantNames;
 use thiserror::Error;
 use tokio::time::sleep;
-use valence_authorization_utils::authorization::AuthorizationInfo;
+use valence_authorization_utils::authorization::{AuthorizationInfo, Priority};
 
 use super::{Connector, ConnectorResult, POLYTONE_TIMEOUT};
 
@@ -87,6 +89,9 @@ pub struct CosmosCosmwasmConnector {
     code_ids: HashMap<String, u64>,
     chain_name: String,
     prefix: String,
+    chain_info: ChainInfo,
+    /// The grpc endpoint the wallet is currently connected to
+    endpoint: String,
 }
 
 impl fmt::Debug for CosmosCosmwasmConnector {
@@ -98,17 +103,28 @@ impl fmt::Debug for CosmosCosmwasmConnector {
 }
 
 impl CosmosCosmwasmConnector {
-    pub async fn new(chain_name: &str) -> Result<Self, CosmosCosmwasmError> {
+    pub async fn new(
+        chain_name: &str,
+        gas_price: Option<Decimal>,
+    ) -> Result<Self, CosmosCosmwasmError> {
         let gc = GLOBAL_CONFIG.lock().await;
-        let chain_info: &ChainInfo = gc.get_chain_info(chain_name)?;
-        let code_ids: &HashMap<String, u64> = gc.get_code_ids(chain_name)?;
-
-        let grpc = GrpcClient::new(&chain_info.grpc).await.context(format!(
-            "Failed to create new client for: {}",
-            chain_info.name
-        ))?;
+        let chain_info: ChainInfo = gc.get_chain_info(chain_name)?.clone();
+        let code_ids: HashMap<String, u64> = gc.get_code_ids(chain_name)?.clone();
+        // We don't want to hold the config while we are trying the endpoints
+        drop(gc);
+
+        let (endpoint, grpc) = chain_info
+            .with_failover(|endpoint| async move {
+                GrpcClient::new(&endpoint)
+                    .await
+                    .context(format!("Failed to create new client for: {}", endpoint))
+            })
+            .await?;
 
-        let gas_price = Decimal::from_str(&chain_info.gas_price)?;
+        let gas_price = match gas_price {
+            Some(gas_price) => gas_price,
+            None => Decimal::from_str(&chain_info.gas_price)?,
+        };
         let gas_adj = Decimal::from_str("1.5")?;
 
         let wallet = Wallet::from_seed_phrase(
@@ -130,9 +146,11 @@ impl CosmosCosmwasmConnector {
         Ok(CosmosCosmwasmConnector {
             is_main_chain: chain_info.name == *NEUTRON_CHAIN,
             wallet,
-            code_ids: code_ids.clone(),
+            code_ids,
             chain_name: chain_info.name.clone(),
             prefix: chain_info.prefix.clone(),
+            chain_info,
+            endpoint,
         })
     }
 }
@@ -443,6 +461,7 @@ impl Connector for CosmosCosmwasmConnector {
         let msg = to_vec(&valence_processor_utils::msg::InstantiateMsg {
             authorization_contract: authorization,
             polytone_contracts: polytone_config,
+            circuit_breaker: None,
         })
         .map_err(CosmosCosmwasmError::SerdeJsonError)?;
 
@@ -675,16 +694,11 @@ impl Connector for CosmosCosmwasmConnector {
         };
 
         let config_res = from_json::<valence_program_registry_utils::ProgramResponse>(
-            self.wallet
-                .client
-                .clients
-                .wasm
-                .smart_contract_state(config_req)
-                .await
-                .context("'query_program_registry' Failed to query program registry")
-                .map_err(CosmosCosmwasmError::Error)?
-                .into_inner()
-                .data,
+            self.query_smart_contract(
+                config_req,
+                "'query_program_registry' Failed to query program registry",
+            )
+            .await?,
         )
         .map_err(CosmosCosmwasmError::CosmwasmStdError)?;
 
@@ -741,6 +755,66 @@ impl Connector for CosmosCosmwasmConnector {
             .map_err(|e| e.into())
     }
 
+    async fn verify_library_code_id(
+        &mut self,
+        library_config: &LibraryConfig,
+    ) -> ConnectorResult<()> {
+        let code_id = *self
+            .code_ids
+            .get(&library_config.to_string())
+            .context(format!("Code id not found for: {}", library_config))
+            .map_err(CosmosCosmwasmError::Error)?;
+
+        // The code query fails if the code id is not stored on chain
+        self.get_checksum(code_id).await?;
+
+        Ok(())
+    }
+
+    async fn query_processor_queue_len(
+        &mut self,
+        processor_addr: String,
+    ) -> ConnectorResult<usize> {
+        let mut len = 0;
+
+        for priority in [Priority::High, Priority::Medium] {
+            let query_data = to_vec(&valence_processor_utils::msg::QueryMsg::GetQueue {
+                from: None,
+                to: None,
+                priority,
+            })
+            .map_err(CosmosCosmwasmError::SerdeJsonError)?;
+            let queue_req = QuerySmartContractStateRequest {
+                address: processor_addr.clone(),
+                query_data,
+            };
+
+            let queue = from_json::<Vec<valence_processor_utils::processor::MessageBatch>>(
+                self.query_smart_contract(
+                    queue_req,
+                    "'query_processor_queue_len' Failed to query processor queue",
+                )
+                .await?,
+            )
+            .map_err(CosmosCosmwasmError::CosmwasmStdError)?;
+
+            len += queue.len();
+        }
+
+        Ok(len)
+    }
+
+    async fn health_check(&mut self) -> ConnectorResult<()> {
+        if self.ping().await.is_ok() {
+            return Ok(());
+        }
+
+        // The endpoint we are connected to is down, move to the next one
+        self.switch_endpoint().await?;
+
+        Ok(self.ping().await?)
+    }
+
     async fn verify_authorization_addr(&mut self, addr: String) -> ConnectorResult<()> {
         let code_id = *self
             .code_ids
@@ -784,7 +858,10 @@ impl Connector for CosmosCosmwasmConnector {
 
         let msg = to_vec(&valence_program_registry_utils::ExecuteMsg::SaveProgram {
             id: config.id,
+            owner: config.owner.clone(),
             program_config: program_binary,
+            tags: vec![],
+            description: None,
         })
         .map_err(CosmosCosmwasmError::SerdeJsonError)?;
 
@@ -827,6 +904,9 @@ impl Connector for CosmosCosmwasmConnector {
         let msg = to_vec(&valence_program_registry_utils::ExecuteMsg::UpdateProgram {
             id: config.id,
             program_config: program_binary,
+            owner: Some(config.owner.clone()),
+            tags: None,
+            description: None,
         })
         .map_err(CosmosCosmwasmError::SerdeJsonError)?;
 
@@ -862,19 +942,19 @@ impl Connector for CosmosCosmwasmConnector {
 
         let res = from_json::<valence_program_registry_utils::ProgramResponse>(
             &self
-                .wallet
-                .client
-                .clients
-                .wasm
-                .smart_contract_state(query)
-                .await
-                .context("Failed to query the workflow config from registry")
-                .map_err(CosmosCosmwasmError::Error)?
-                .into_inner()
-                .data,
+                .query_smart_contract(query, "Failed to query the workflow config from registry")
+                .await?,
         )
         .map_err(CosmosCosmwasmError::CosmwasmStdError)?;
 
+        if res.is_deprecated {
+            warn!(
+                "Program {} is deprecated: {}",
+                id,
+                res.deprecation_reason.unwrap_or_default()
+            );
+        }
+
         Ok(from_json::<ProgramConfig>(&res.program_config)
             .map_err(CosmosCosmwasmError::CosmwasmStdError)?)
     }
@@ -887,14 +967,29 @@ impl CosmosCosmwasmConnector {
         msg: prost_types::Any,
         err_id: &str,
     ) -> Result<GetTxResponse, CosmosCosmwasmError> {
-        let res = self
+        let res = match self
             .wallet
             .broadcast_tx(vec![msg], None, None, BroadcastMode::Sync)
             .await
-            .map_err(CosmosCosmwasmError::Error)?
-            .tx_response
-            .context(format!("'{err_id}' failed to get tx_response"))
-            .map_err(CosmosCosmwasmError::Error)?;
+        {
+            Ok(res) => res,
+            Err(err) => {
+                // We don't resend the tx because it might have been broadcasted,
+                // but if the endpoint is down the next request should use another one
+                if self.ping().await.is_err() {
+                    if let Err(switch_err) = self.switch_endpoint().await {
+                        warn!(
+                            "Failed to switch endpoint for {}: {}",
+                            self.chain_name, switch_err
+                        );
+                    }
+                }
+                return Err(CosmosCosmwasmError::Error(err));
+            }
+        }
+        .tx_response
+        .context(format!("'{err_id}' failed to get tx_response"))
+        .map_err(CosmosCosmwasmError::Error)?;
 
         if res.code != 0 {
             return Err(CosmosCosmwasmError::Error(anyhow::anyhow!(
@@ -906,6 +1001,85 @@ impl CosmosCosmwasmConnector {
         self.query_tx_hash(res.txhash, 15).await
     }
 
+    /// Query a contract, if the query fails and the endpoint is down, we move to the next endpoint and retry,
+    /// up to `max_retries` times. Errors returned by a healthy endpoint are returned as is.
+    async fn query_smart_contract(
+        &mut self,
+        req: QuerySmartContractStateRequest,
+        err_msg: &str,
+    ) -> Result<Vec<u8>, CosmosCosmwasmError> {
+        let mut retries = self.chain_info.max_retries;
+
+        loop {
+            let err = match self
+                .wallet
+                .client
+                .clients
+                .wasm
+                .smart_contract_state(req.clone())
+                .await
+            {
+                Ok(res) => return Ok(res.into_inner().data),
+                Err(err) => err,
+            };
+
+            if retries == 0 || self.ping().await.is_ok() {
+                return Err(CosmosCosmwasmError::Error(
+                    anyhow::Error::from(err).context(err_msg.to_string()),
+                ));
+            }
+
+            warn!(
+                "Query to {} failed on {}, moving to the next endpoint: {}",
+                self.chain_name, self.endpoint, err
+            );
+            retries -= 1;
+            self.switch_endpoint().await?;
+        }
+    }
+
+    /// Cheapest query we can do that still goes through the node
+    async fn ping(&mut self) -> Result<(), CosmosCosmwasmError> {
+        let req = QueryCodesRequest {
+            pagination: Some(PageRequest {
+                limit: 1,
+                ..Default::default()
+            }),
+        };
+
+        self.wallet
+            .client
+            .clients
+            .wasm
+            .codes(req)
+            .await
+            .context(format!(
+                "'health_check' Failed for: {} on {}",
+                self.chain_name, self.endpoint
+            ))?;
+
+        Ok(())
+    }
+
+    /// Mark the current endpoint as failed and connect the wallet to the next endpoint that works
+    async fn switch_endpoint(&mut self) -> Result<(), CosmosCosmwasmError> {
+        self.chain_info.mark_endpoint_failed(&self.endpoint);
+
+        let (endpoint, grpc) = self
+            .chain_info
+            .with_failover(|endpoint| async move {
+                GrpcClient::new(&endpoint)
+                    .await
+                    .context(format!("Failed to create new client for: {}", endpoint))
+            })
+            .await?;
+
+        self.wallet.client = grpc;
+        self.endpoint = endpoint;
+
+        Ok(())
+    }
+
     /// we retry every second, so retry here means how many seconds we should wait for the tx to appear.
     /// If the tx is on chain we will return early, so retry is the MAX amount of seconds we will wait.
     fn query_tx_hash(
@@ -954,16 +1128,11 @@ impl CosmosCosmwasmConnector {
         };
 
         let config_res = from_json::<valence_processor_utils::processor::Config>(
-            self.wallet
-                .client
-                .clients
-                .wasm
-                .smart_contract_state(config_req)
-                .await
-                .context("'_should_retry_processor_bridge_account_creation' Failed to query the processor")
-                .map_err(CosmosCosmwasmError::Error)?
-                .into_inner()
-                .data,
+            self.query_smart_contract(
+                config_req,
+                "'_should_retry_processor_bridge_account_creation' Failed to query the processor",
+            )
+            .await?,
         )
         .map_err(CosmosCosmwasmError::CosmwasmStdError)?;
 
@@ -1030,16 +1199,8 @@ impl CosmosCosmwasmConnector {
         };
 
         let res = from_json::<valence_authorization_utils::domain::ExternalDomain>(
-            self.wallet
-                .client
-                .clients
-                .wasm
-                .smart_contract_state(req)
-                .await
-                .context("'_should_retry_authorization_bridge_account_creation' Failed to query the authorization")
-                .map_err(CosmosCosmwasmError::Error)?
-                .into_inner()
-                .data,
+            self.query_smart_contract(req, "'_should_retry_authorization_bridge_account_creation' Failed to query the authorization")
+                .await?,
         )
         .map_err(CosmosCosmwasmError::CosmwasmStdError)?;
 
@@ -8,6 +8,7 @@ use async_trait::async_trait;
 use cosmos_cw::{CosmosCosmwasmConnector, CosmosCosmwasmError};
 
 use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_std::Decimal;
 use serde::{Deserialize, Serialize};
 
 // use cosmos_evm::CosmosEvmError;
@@ -89,10 +90,14 @@ impl Domain {
         }
     }
 
-    pub async fn generate_connector(&self) -> ConnectorResult<Box<dyn Connector>> {
+    /// The connector pays its fees with `gas_price`, or with the gas price of the chain config if None
+    pub async fn generate_connector(
+        &self,
+        gas_price: Option<Decimal>,
+    ) -> ConnectorResult<Box<dyn Connector>> {
         Ok(match self {
             Domain::CosmosCosmwasm(chain_name) => {
-                Box::new(CosmosCosmwasmConnector::new(chain_name.as_str()).await?)
+                Box::new(CosmosCosmwasmConnector::new(chain_name.as_str(), gas_price).await?)
             } // Domain::CosmosEvm(_) => {
               //     return Err(ConnectorError::ConfigError(
               //         ConfigError::ChainBridgeNotFound("test".to_string()),
@@ -174,6 +179,19 @@ pub trait Connector: fmt::Debug + Send + Sync {
     // Verify the bridge account was instantiated
     async fn verify_bridge_account(&mut self, bridge_addr: String) -> ConnectorResult<()>;
 
+    /// Verify the code id we use for this library is stored on the chain
+    async fn verify_library_code_id(
+        &mut self,
+        library_config: &LibraryConfig,
+    ) -> ConnectorResult<()>;
+
+    /// Get the number of batches that are waiting to be executed in the processor queues
+    async fn query_processor_queue_len(&mut self, processor_addr: String)
+        -> ConnectorResult<usize>;
+
+    /// Verify the connection to the chain is still alive
+    async fn health_check(&mut self) -> ConnectorResult<()>;
+
     // ---------------------------------------------------------------------------------------
     // Below are functions that sohuld only be implemented on a specific domain
     // For example authorization contract methods should only be implemented on the main domain
@@ -86,6 +86,27 @@ pub enum ManagerError {
 
     #[error("Account id was not found in new program config: {0}")]
     AccountIdWasNotFound(u64),
+
+    #[error("Update was rolled back - succeeded: {succeeded:?}, failed: {failed:?}")]
+    UpdateRolledBack {
+        succeeded: Vec<String>,
+        failed: Vec<String>,
+    },
+
+    #[error("Connection to chains is unhealthy: {chains:?}, error: {error}")]
+    UnhealthyConnections {
+        chains: Vec<String>,
+        error: Box<ManagerError>,
+    },
+
+    #[error("Template variables are missing: {0:?}")]
+    MissingTemplateVariables(BTreeSet<String>),
+
+    #[error("Invalid template placeholder: {0}")]
+    InvalidTemplatePlaceholder(String),
+
+    #[error("Migration validation failed: {0:?}")]
+    MigrationValidationFailed(Vec<String>),
 }
 
 impl ManagerError {
@@ -0,0 +1,156 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+use async_trait::async_trait;
+use cosmos_grpc_client::cosmos_sdk_proto::cosmos::base::node::v1beta1::{
+    service_client::ServiceClient, ConfigRequest,
+};
+use cosmwasm_std::Decimal;
+use dashmap::DashMap;
+
+use crate::{
+    config::{ConfigError, GLOBAL_CONFIG},
+    error::{ManagerError, ManagerResult},
+};
+
+/// How long we keep a gas price before we ask the oracle again
+pub const GAS_PRICE_CACHE_DURATION: Duration = Duration::from_secs(30);
+
+#[async_trait]
+pub trait GasPriceOracle: fmt::Debug + Send + Sync {
+    /// Get the current gas price of the chain in its gas denom
+    async fn get_gas_price(&self, chain: &str) -> ManagerResult<Decimal>;
+}
+
+/// Gets the minimum gas price the nodes of a cosmos chain accept.
+/// Nodes that don't set a minimum gas price for the gas denom fallback to the price in our config.
+#[derive(Debug, Default)]
+pub struct CosmosGasPriceOracle;
+
+#[async_trait]
+impl GasPriceOracle for CosmosGasPriceOracle {
+    async fn get_gas_price(&self, chain: &str) -> ManagerResult<Decimal> {
+        let chain_info = GLOBAL_CONFIG.lock().await.get_chain_info(chain)?.clone();
+
+        if chain_info.endpoints.is_empty() {
+            return Err(ConfigError::NoEndpoints(chain_info.name).into());
+        }
+
+        // We only ask a single endpoint, if it fails the connector falls back to the configured
+        // gas price, so a node without the config service doesn't get marked as failed
+        let endpoint = chain_info.ordered_endpoints().swap_remove(0);
+
+        let mut client = ServiceClient::connect(endpoint.clone())
+            .await
+            .context(format!("Failed to connect to: {}", endpoint))?;
+
+        let minimum_gas_price = client
+            .config(ConfigRequest {})
+            .await
+            .context(format!("Failed to query node config: {}", endpoint))?
+            .into_inner()
+            .minimum_gas_price;
+
+        match parse_min_gas_price(&minimum_gas_price, &chain_info.gas_denom)? {
+            Some(price) => Ok(price),
+            None => Ok(Decimal::from_str(&chain_info.gas_price)
+                .map_err(|e| ManagerError::generic_err(e.to_string()))?),
+        }
+    }
+}
+
+/// Returns the price of the denom from a node minimum gas price, e.g. "0.0053untrn,0.1uatom"
+/// A zero price means the node doesn't set a minimum, so we treat it as unset
+pub fn parse_min_gas_price(min_gas_price: &str, denom: &str) -> ManagerResult<Option<Decimal>> {
+    for coin in min_gas_price.split(',').map(str::trim) {
+        let Some(amount) = coin.strip_suffix(denom) else {
+            continue;
+        };
+
+        // Make sure we matched the whole denom and not only its suffix
+        if amount.is_empty() || !amount.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            continue;
+        }
+
+        let price =
+            Decimal::from_str(amount).map_err(|e| ManagerError::generic_err(e.to_string()))?;
+
+        if price.is_zero() {
+            return Ok(None);
+        }
+
+        return Ok(Some(price));
+    }
+
+    Ok(None)
+}
+
+/// Gas prices set manually, used for testing
+#[derive(Debug, Default)]
+pub struct MockGasPriceOracle {
+    pub prices: HashMap<String, Decimal>,
+}
+
+#[async_trait]
+impl GasPriceOracle for MockGasPriceOracle {
+    async fn get_gas_price(&self, chain: &str) -> ManagerResult<Decimal> {
+        self.prices
+            .get(chain)
+            .cloned()
+            .ok_or_else(|| ManagerError::generic_err(format!("No gas price for {}", chain)))
+    }
+}
+
+/// Caches the gas prices of an oracle, for `GAS_PRICE_CACHE_DURATION` by default
+#[derive(Debug)]
+pub struct CachedGasPriceOracle {
+    oracle: Box<dyn GasPriceOracle>,
+    cache_duration: Duration,
+    /// Key: chain name | Value: gas price and when we got it
+    cache: DashMap<String, (Decimal, Instant)>,
+}
+
+impl Default for CachedGasPriceOracle {
+    fn default() -> Self {
+        Self::new(Box::new(CosmosGasPriceOracle))
+    }
+}
+
+impl CachedGasPriceOracle {
+    pub fn new(oracle: Box<dyn GasPriceOracle>) -> Self {
+        Self {
+            oracle,
+            cache_duration: GAS_PRICE_CACHE_DURATION,
+            cache: DashMap::new(),
+        }
+    }
+
+    /// Keep the gas prices for `cache_duration` instead of the default duration
+    pub fn with_cache_duration(mut self, cache_duration: Duration) -> Self {
+        self.cache_duration = cache_duration;
+        self
+    }
+}
+
+#[async_trait]
+impl GasPriceOracle for CachedGasPriceOracle {
+    async fn get_gas_price(&self, chain: &str) -> ManagerResult<Decimal> {
+        if let Some(entry) = self.cache.get(chain) {
+            let (price, fetched_at) = *entry;
+            if fetched_at.elapsed() < self.cache_duration {
+                return Ok(price);
+            }
+        }
+
+        let price = self.oracle.get_gas_price(chain).await?;
+        self.cache
+            .insert(chain.to_string(), (price, Instant::now()));
+
+        Ok(price)
+    }
+}
@@ -4,6 +4,7 @@ pub mod config;
 pub mod connectors;
 pub mod domain;
 pub mod error;
+pub mod gas_price;
 pub mod helpers;
 pub mod library;
 pub mod macros;
@@ -13,38 +14,103 @@ pub mod program_migration;
 pub mod program_update;
 pub mod tests;
 
+use std::{sync::Arc, time::Duration};
+
 use connectors::Connectors;
-use error::ManagerResult;
-use program_config::ProgramConfig;
-use program_migration::{MigrateResponse, ProgramConfigMigrate};
+use domain::Domain;
+use error::{ManagerError, ManagerResult};
+use program_config::{ExportTemplate, ImportTemplate, ProgramConfig};
+use program_migration::{MigrateResponse, MigrationStrategy, ProgramConfigMigrate};
 use program_update::{ProgramConfigUpdate, UpdateResponse};
 
 // Main chain name
 const NEUTRON_CHAIN: &str = "neutron";
+// How often we check the connections in the background while working on a program
+const CONNECTION_MONITOR_INTERVAL: Duration = Duration::from_secs(30);
 // // Main domain
 // const MAIN_DOMAIN: Domain = Domain::CosmosCosmwasm(MAIN_CHAIN);
 // // Neutron domain
 // const NEUTRON_DOMAIN: Domain = Domain::CosmosCosmwasm("neutron");
 
 pub async fn init_program(program_config: &mut ProgramConfig) -> ManagerResult<()> {
-    let connectors = Connectors::default();
+    let connectors = Arc::new(Connectors::default());
+    let monitor = connectors.spawn_connection_monitor(CONNECTION_MONITOR_INTERVAL);
 
     // TODO: We probably want to register the error we got, with the config in question so we can know when it failed and why
-    program_config.init(&connectors).await
+    let res = program_config.init(&connectors).await;
+    monitor.abort();
+
+    if let Err(err) = res {
+        // If a connection was dropped, we want the error to tell us which chain we lost
+        let unhealthy_chains = connectors
+            .health_check()
+            .await?
+            .into_iter()
+            .filter_map(|(chain, is_healthy)| (!is_healthy).then_some(chain))
+            .collect::<Vec<_>>();
+
+        if !unhealthy_chains.is_empty() {
+            return Err(ManagerError::UnhealthyConnections {
+                chains: unhealthy_chains,
+                error: Box::new(err),
+            });
+        }
+
+        return Err(err);
+    }
+
+    Ok(())
 }
 
 pub async fn update_program(
     mut program_config: ProgramConfigUpdate,
 ) -> ManagerResult<UpdateResponse> {
-    let connectors = Connectors::default();
+    let connectors = Arc::new(Connectors::default());
+    let monitor = connectors.spawn_connection_monitor(CONNECTION_MONITOR_INTERVAL);
 
-    program_config.update(&connectors).await
+    let res = program_config.update(&connectors).await;
+    monitor.abort();
+
+    res
 }
 
 pub async fn migrate_program(
     mut program_config: ProgramConfigMigrate,
+    strategy: MigrationStrategy,
 ) -> ManagerResult<MigrateResponse> {
+    let connectors = Arc::new(Connectors::default());
+    let monitor = connectors.spawn_connection_monitor(CONNECTION_MONITOR_INTERVAL);
+
+    let res = program_config.migrate(&connectors, strategy).await;
+    monitor.abort();
+
+    res
+}
+
+/// Read a program from the registry and export it as a template that can be imported in another environment
+pub async fn export_program(id: u64) -> ManagerResult<ExportTemplate> {
     let connectors = Connectors::default();
+    let neutron_domain = Domain::CosmosCosmwasm(NEUTRON_CHAIN.to_string());
+
+    // 0 is not a valid id of a program
+    if id == 0 {
+        return Err(ManagerError::InvalidProgramId);
+    }
+
+    let config = connectors
+        .get_or_create_connector(&neutron_domain)
+        .await?
+        .get_program_config(id)
+        .await?;
+
+    Ok(ExportTemplate::from_config(config))
+}
+
+/// Deploy a program from an exported template with the code ids of the current environment
+pub async fn import_program(template: ImportTemplate) -> ManagerResult<ProgramConfig> {
+    let mut program_config = template.into_program_config().await?;
+
+    init_program(&mut program_config).await?;
 
-    program_config.migrate(&connectors).await
+    Ok(program_config)
 }
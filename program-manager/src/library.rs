@@ -76,15 +76,28 @@ impl LibraryInfo {
 pub enum LibraryConfig {
     #[default]
     None,
+    /// Forwards funds from an input account to an output account
     ValenceForwarderLibrary(valence_forwarder_library::msg::LibraryConfig),
+    /// Splits funds from an input account to one or more output accounts
     ValenceSplitterLibrary(valence_splitter_library::msg::LibraryConfig),
+    /// Routes funds from one or more input accounts to a single output account
     ValenceReverseSplitterLibrary(valence_reverse_splitter_library::msg::LibraryConfig),
+    /// Provides liquidity into an Astroport pool
     ValenceAstroportLper(valence_astroport_lper::msg::LibraryConfig),
+    /// Withdraws liquidity from an Astroport pool
     ValenceAstroportWithdrawer(valence_astroport_withdrawer::msg::LibraryConfig),
+    /// Provides liquidity into an Osmosis GAMM pool
     ValenceOsmosisGammLper(valence_osmosis_gamm_lper::msg::LibraryConfig),
+    /// Withdraws liquidity from an Osmosis GAMM pool
     ValenceOsmosisGammWithdrawer(valence_osmosis_gamm_withdrawer::msg::LibraryConfig),
+    /// Creates concentrated liquidity positions on Osmosis
     ValenceOsmosisClLper(valence_osmosis_cl_lper::msg::LibraryConfig),
+    /// Liquidates concentrated liquidity positions on Osmosis
     ValenceOsmosisClWithdrawer(valence_osmosis_cl_withdrawer::msg::LibraryConfig),
+    /// Calls contracts on other chains through Axelar
+    ValenceAxelarGatewayLibrary(valence_axelar_gateway_library::msg::LibraryConfig),
+    /// Settles a queue of withdrawal obligations from a settlement account
+    ValenceClearingHouseLibrary(valence_clearing_house_library::msg::LibraryConfig),
 }
 
 // TODO: create macro for the methods that work the same over all of the configs
@@ -1,20 +1,26 @@
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-use cosmwasm_schema::schemars::JsonSchema;
+use aho_corasick::AhoCorasick;
+use cosmwasm_schema::{
+    schema_for,
+    schemars::{schema::RootSchema, JsonSchema},
+};
 use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
-use valence_authorization_utils::authorization::AuthorizationInfo;
+use valence_authorization_utils::authorization::{AuthorizationInfo, Subroutine};
 
-use valence_library_utils::{GetId, Id};
+use valence_library_utils::{GetId, Id, LibraryAccountType};
 
 use crate::{
     account::{AccountInfo, AccountType, InstantiateAccountData},
+    config::GLOBAL_CONFIG,
     connectors::Connectors,
     domain::Domain,
     error::{ManagerError, ManagerResult},
     helpers::get_polytone_info,
-    library::LibraryInfo,
+    library::{LibraryConfig, LibraryInfo},
     macros::ensure,
+    program_update::{AuthorizationInfoUpdate, ProgramConfigUpdate},
     NEUTRON_CHAIN,
 };
 
@@ -88,6 +94,11 @@ pub struct ProgramConfig {
     pub authorization_data: AuthorizationData,
 }
 
+/// JSON schema of the program config, for building programs outside of the manager
+pub fn export_program_config_schema() -> RootSchema {
+    schema_for!(ProgramConfig)
+}
+
 impl ProgramConfig {
     /// Instantiate a program on all domains.
     pub async fn init(&mut self, connectors: &Connectors) -> ManagerResult<()> {
@@ -720,3 +731,487 @@ impl ProgramConfig {
         self.libraries.insert(library_id, library.clone());
     }
 }
+
+/// The value of a single component before and after a change
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ConfigChange<T> {
+    pub before: T,
+    pub after: T,
+}
+
+/// Library data as shown in a diff.
+/// Unlike [`LibraryInfo`], the library config is included when serialized.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct LibraryDiffInfo {
+    pub name: String,
+    pub domain: Domain,
+    pub config: LibraryConfig,
+    pub addr: Option<String>,
+}
+
+impl From<&LibraryInfo> for LibraryDiffInfo {
+    fn from(library: &LibraryInfo) -> Self {
+        Self {
+            name: library.name.clone(),
+            domain: library.domain.clone(),
+            config: library.config.clone(),
+            addr: library.addr.clone(),
+        }
+    }
+}
+
+/// All the differences between 2 program configs
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ProgramConfigDiff {
+    pub added_accounts: BTreeMap<Id, AccountInfo>,
+    pub removed_accounts: BTreeMap<Id, AccountInfo>,
+    pub changed_accounts: BTreeMap<Id, ConfigChange<AccountInfo>>,
+    pub added_libraries: BTreeMap<Id, LibraryDiffInfo>,
+    pub removed_libraries: BTreeMap<Id, LibraryDiffInfo>,
+    pub changed_libraries: BTreeMap<Id, ConfigChange<LibraryDiffInfo>>,
+    /// Subroutines of authorizations that exist in both configs, by authorization label
+    pub changed_subroutines: BTreeMap<String, ConfigChange<Subroutine>>,
+}
+
+impl ProgramConfigDiff {
+    pub fn is_empty(&self) -> bool {
+        self == &ProgramConfigDiff::default()
+    }
+
+    /// Pretty printed json of the diff, to be shown to the operator
+    pub fn to_pretty_json(&self) -> ManagerResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| ManagerError::generic_err(e.to_string()))
+    }
+}
+
+/// Get a diff of everything that changed from the old config to the new config
+pub fn diff_program_configs(old: &ProgramConfig, new: &ProgramConfig) -> ProgramConfigDiff {
+    let (added_accounts, removed_accounts, changed_accounts) =
+        diff_maps(&old.accounts, &new.accounts, |account| account.clone());
+    let (added_libraries, removed_libraries, changed_libraries) =
+        diff_maps(&old.libraries, &new.libraries, LibraryDiffInfo::from);
+
+    let changed_subroutines = old
+        .authorizations
+        .iter()
+        .filter_map(|old_auth| {
+            new.authorizations
+                .iter()
+                .find(|new_auth| new_auth.label == old_auth.label)
+                .filter(|new_auth| new_auth.subroutine != old_auth.subroutine)
+                .map(|new_auth| {
+                    (
+                        old_auth.label.clone(),
+                        ConfigChange {
+                            before: old_auth.subroutine.clone(),
+                            after: new_auth.subroutine.clone(),
+                        },
+                    )
+                })
+        })
+        .collect();
+
+    ProgramConfigDiff {
+        added_accounts,
+        removed_accounts,
+        changed_accounts,
+        added_libraries,
+        removed_libraries,
+        changed_libraries,
+        changed_subroutines,
+    }
+}
+
+type MapDiff<V> = (
+    BTreeMap<Id, V>,
+    BTreeMap<Id, V>,
+    BTreeMap<Id, ConfigChange<V>>,
+);
+
+/// Returns the added, removed and changed entries between 2 maps by id
+fn diff_maps<T: PartialEq, V>(
+    old: &BTreeMap<Id, T>,
+    new: &BTreeMap<Id, T>,
+    to_value: impl Fn(&T) -> V,
+) -> MapDiff<V> {
+    let mut added = BTreeMap::new();
+    let mut removed = BTreeMap::new();
+    let mut changed = BTreeMap::new();
+
+    for (id, old_value) in old.iter() {
+        match new.get(id) {
+            None => {
+                removed.insert(*id, to_value(old_value));
+            }
+            Some(new_value) if new_value != old_value => {
+                changed.insert(
+                    *id,
+                    ConfigChange {
+                        before: to_value(old_value),
+                        after: to_value(new_value),
+                    },
+                );
+            }
+            Some(_) => {}
+        }
+    }
+
+    for (id, new_value) in new.iter() {
+        if !old.contains_key(id) {
+            added.insert(*id, to_value(new_value));
+        }
+    }
+
+    (added, removed, changed)
+}
+
+/// Merge an update on top of a base config and validate the result as a new config.
+/// This is used to apply chain specific overrides on a shared config before deployment,
+/// so the id of the update is ignored and only authorizations can be added or modified.
+pub fn merge_program_configs(
+    mut base: ProgramConfig,
+    overrides: ProgramConfigUpdate,
+) -> ManagerResult<ProgramConfig> {
+    if let Some(owner) = overrides.owner {
+        base.owner = owner;
+    }
+
+    for (id, library_update) in overrides.libraries.iter() {
+        base.libraries
+            .get_mut(id)
+            .ok_or(ManagerError::LibraryIdIsMissing(*id))?
+            .config
+            .apply_update(library_update)?;
+    }
+
+    for authorization in overrides.authorizations {
+        match authorization {
+            AuthorizationInfoUpdate::Add(authorization_info) => {
+                ensure!(
+                    !base
+                        .authorizations
+                        .iter()
+                        .any(|auth| auth.label == authorization_info.label),
+                    ManagerError::AuthorizationLabelExists(authorization_info.label)
+                );
+
+                base.authorizations.push(authorization_info);
+            }
+            AuthorizationInfoUpdate::Modify {
+                label,
+                not_before,
+                expiration,
+                max_concurrent_executions,
+                priority,
+            } => {
+                // The config only has a duration that starts when the authorization is created,
+                // an expiration is a point in time so there is nothing we can map it to
+                ensure!(
+                    expiration.is_none(),
+                    ManagerError::generic_err(format!(
+                        "Expiration of authorization {} can only be modified on an existing program",
+                        label
+                    ))
+                );
+
+                let auth = base
+                    .authorizations
+                    .iter_mut()
+                    .find(|auth| auth.label == label)
+                    .ok_or(ManagerError::AuthorizationLabelNotFound(label))?;
+
+                if let Some(not_before) = not_before {
+                    auth.not_before = not_before;
+                }
+
+                if max_concurrent_executions.is_some() {
+                    auth.max_concurrent_executions = max_concurrent_executions;
+                }
+
+                if priority.is_some() {
+                    auth.priority = priority;
+                }
+            }
+            AuthorizationInfoUpdate::Disable(label) | AuthorizationInfoUpdate::Enable(label) => {
+                return Err(ManagerError::generic_err(format!(
+                    "Authorization {} can only be enabled or disabled on an existing program",
+                    label
+                )));
+            }
+        }
+    }
+
+    base.verify_new_config()?;
+
+    Ok(base)
+}
+
+/// A program config that is shared between deployments.
+/// Account ids that are referenced in links but are missing from the accounts list are placeholders,
+/// the accounts for them are provided per deployment when the template is merged.
+/// String fields can also contain `{{variable}}` placeholders that are replaced when the template is rendered.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(crate = "cosmwasm_schema::schemars")]
+pub struct ProgramConfigTemplate {
+    pub config: ProgramConfig,
+}
+
+impl ProgramConfigTemplate {
+    pub fn new(config: ProgramConfig) -> Self {
+        Self { config }
+    }
+
+    /// Get the account ids that must be resolved before the template can be used
+    pub fn placeholders(&self) -> BTreeSet<Id> {
+        self.config
+            .links
+            .values()
+            .flat_map(|link| {
+                link.input_accounts_id
+                    .iter()
+                    .chain(link.output_accounts_id.iter())
+            })
+            .filter(|id| !self.config.accounts.contains_key(id))
+            .copied()
+            .collect()
+    }
+
+    /// Resolve all placeholders with the given accounts and merge the overrides on top of the result
+    pub fn merge(
+        self,
+        accounts: BTreeMap<Id, AccountInfo>,
+        overrides: ProgramConfigUpdate,
+    ) -> ManagerResult<ProgramConfig> {
+        let mut placeholders = self.placeholders();
+        let mut config = self.config;
+
+        for (id, account) in accounts {
+            ensure!(
+                placeholders.remove(&id),
+                ManagerError::generic_err(format!(
+                    "Account id {} is not a placeholder in the template",
+                    id
+                ))
+            );
+
+            config.accounts.insert(id, account);
+        }
+
+        if let Some(id) = placeholders.first() {
+            return Err(ManagerError::AccountIdWasNotFound(*id));
+        }
+
+        merge_program_configs(config, overrides)
+    }
+
+    /// Get the names of the `{{variable}}` placeholders in the string fields of the template
+    pub fn variables(&self) -> ManagerResult<BTreeSet<String>> {
+        let (json, library_jsons) = self.to_json()?;
+
+        jsons_variables(&json, &library_jsons)
+    }
+
+    /// Replace all `{{variable}}` placeholders in the string fields of the template with their values
+    pub fn render(&self, vars: HashMap<String, String>) -> ManagerResult<ProgramConfig> {
+        let (json, library_jsons) = self.to_json()?;
+
+        let variables = jsons_variables(&json, &library_jsons)?;
+        let missing = variables
+            .iter()
+            .filter(|variable| !vars.contains_key(*variable))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        ensure!(
+            missing.is_empty(),
+            ManagerError::MissingTemplateVariables(missing)
+        );
+
+        let mut patterns = Vec::with_capacity(variables.len());
+        let mut replace_with = Vec::with_capacity(variables.len());
+        for variable in variables {
+            // The value is placed inside a json string, so it must be escaped
+            let value = serde_json::to_string(&vars[&variable])
+                .map_err(|e| ManagerError::generic_err(e.to_string()))?;
+
+            patterns.push(format!("{{{{{}}}}}", variable));
+            replace_with.push(value[1..value.len() - 1].to_string());
+        }
+
+        let ac =
+            AhoCorasick::new(patterns).map_err(|e| ManagerError::generic_err(e.to_string()))?;
+
+        let mut config: ProgramConfig = serde_json::from_str(&ac.replace_all(&json, &replace_with))
+            .map_err(|e| ManagerError::generic_err(e.to_string()))?;
+
+        // Library configs are skipped when serializing the program config, so we put them back after rendering them
+        for (id, library_json) in library_jsons {
+            let library = config
+                .libraries
+                .get_mut(&id)
+                .ok_or(ManagerError::LibraryIdIsMissing(id))?;
+            library.config = serde_json::from_str(&ac.replace_all(&library_json, &replace_with))
+                .map_err(|e| ManagerError::generic_err(e.to_string()))?;
+        }
+
+        Ok(config)
+    }
+
+    // Serialize the template, library configs are serialized separately because `LibraryInfo` skips them
+    fn to_json(&self) -> ManagerResult<(String, BTreeMap<Id, String>)> {
+        let json = serde_json::to_string(&self.config)
+            .map_err(|e| ManagerError::generic_err(e.to_string()))?;
+
+        let library_jsons = self
+            .config
+            .libraries
+            .iter()
+            .map(|(id, library)| {
+                serde_json::to_string(&library.config)
+                    .map(|library_json| (*id, library_json))
+                    .map_err(|e| ManagerError::generic_err(e.to_string()))
+            })
+            .collect::<ManagerResult<_>>()?;
+
+        Ok((json, library_jsons))
+    }
+}
+
+/// A deployed program config without any deployment specific data, used to deploy the same program in another environment.
+/// Addresses of the program accounts and libraries are replaced by their ids, and the id, owner and authorization data are cleared.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(crate = "cosmwasm_schema::schemars")]
+pub struct ExportTemplate {
+    pub config: ProgramConfig,
+}
+
+impl ExportTemplate {
+    pub fn from_config(mut config: ProgramConfig) -> Self {
+        // Addresses of the program accounts and libraries, to replace them by their ids
+        let mut ids = HashMap::new();
+        for (id, account) in config.accounts.iter_mut() {
+            if let Some(addr) = account.addr.take() {
+                ids.insert(addr, LibraryAccountType::AccountId(*id));
+            }
+        }
+        for (id, library) in config.libraries.iter_mut() {
+            if let Some(addr) = library.addr.take() {
+                ids.insert(addr, LibraryAccountType::LibraryId(*id));
+            }
+        }
+
+        let sanitize = |contract_address: &mut LibraryAccountType| {
+            if let LibraryAccountType::Addr(addr) = contract_address {
+                if let Some(id) = ids.get(addr) {
+                    *contract_address = id.clone();
+                }
+            }
+        };
+        for authorization in config.authorizations.iter_mut() {
+            match &mut authorization.subroutine {
+                Subroutine::Atomic(subroutine) => subroutine
+                    .functions
+                    .iter_mut()
+                    .for_each(|function| sanitize(&mut function.contract_address)),
+                Subroutine::NonAtomic(subroutine) => subroutine
+                    .functions
+                    .iter_mut()
+                    .for_each(|function| sanitize(&mut function.contract_address)),
+            }
+        }
+
+        config.id = 0;
+        config.owner = String::new();
+        config.authorization_data = AuthorizationData::default();
+
+        Self { config }
+    }
+}
+
+/// An exported program with the data needed to deploy it in the current environment
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImportTemplate {
+    pub template: ExportTemplate,
+    pub owner: String,
+    /// Library configs are not stored in the registry, so they must be provided for every library of the template
+    pub library_configs: BTreeMap<Id, LibraryConfig>,
+}
+
+impl ImportTemplate {
+    /// Build the program config to deploy, making sure every library has a config and a code id on its domain
+    pub async fn into_program_config(self) -> ManagerResult<ProgramConfig> {
+        let mut config = self.template.config;
+        let mut library_configs = self.library_configs;
+        config.owner = self.owner;
+
+        let gc = GLOBAL_CONFIG.lock().await;
+        for (id, library) in config.libraries.iter_mut() {
+            let library_config = library_configs
+                .remove(id)
+                .ok_or(ManagerError::LibraryIdIsMissing(*id))?;
+            let chain_name = library.domain.get_chain_name();
+            let contract_name = library_config.to_string();
+
+            ensure!(
+                gc.get_code_ids(chain_name)?.contains_key(&contract_name),
+                ManagerError::generic_err(format!(
+                    "Code id not found for library {} ({}) on {}",
+                    library.name, contract_name, chain_name
+                ))
+            );
+
+            library.config = library_config;
+        }
+
+        if let Some(id) = library_configs.keys().next() {
+            return Err(ManagerError::generic_err(format!(
+                "Library id {} is not in the template",
+                id
+            )));
+        }
+
+        Ok(config)
+    }
+}
+
+/// Get the variables of the program config json and the library config jsons of a template
+fn jsons_variables(
+    json: &str,
+    library_jsons: &BTreeMap<Id, String>,
+) -> ManagerResult<BTreeSet<String>> {
+    let mut variables = template_variables(json)?;
+    for library_json in library_jsons.values() {
+        variables.extend(template_variables(library_json)?);
+    }
+
+    Ok(variables)
+}
+
+/// Find all `{{variable}}` placeholders in the json, variable names may only contain alphanumeric characters and `_`
+fn template_variables(json: &str) -> ManagerResult<BTreeSet<String>> {
+    let mut variables = BTreeSet::new();
+    let mut rest = json;
+
+    while let Some(start) = rest.find("{{") {
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            ManagerError::InvalidTemplatePlaceholder(format!(
+                "{{{{{}",
+                after.chars().take(20).collect::<String>()
+            ))
+        })?;
+        let variable = &after[..end];
+
+        ensure!(
+            !variable.is_empty()
+                && variable
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            ManagerError::InvalidTemplatePlaceholder(format!("{{{{{}}}}}", variable))
+        );
+
+        variables.insert(variable.to_string());
+        rest = &after[end + 2..];
+    }
+
+    Ok(variables)
+}
@@ -1,8 +1,14 @@
-use valence_authorization_utils::authorization::AuthorizationInfo;
+use std::collections::BTreeMap;
+
+use valence_authorization_utils::{
+    authorization::{AuthorizationInfo, Subroutine},
+    builders::AuthorizationBuilder,
+};
 use valence_library_utils::{GetId, LibraryAccountType};
 
 use crate::{
     account::AccountInfo,
+    error::ManagerResult,
     library::LibraryInfo,
     program_config::{Link, ProgramConfig},
 };
@@ -13,6 +19,10 @@ pub struct ProgramConfigBuilder {
     library_id: u64,
     link_id: u64,
     program_config: ProgramConfig,
+    /// Key: label of the account | Value: id of the account
+    account_labels: BTreeMap<String, LibraryAccountType>,
+    /// Key: label of the library | Value: id of the library
+    library_labels: BTreeMap<String, LibraryAccountType>,
 }
 
 impl ProgramConfigBuilder {
@@ -75,6 +85,76 @@ impl ProgramConfigBuilder {
         self.program_config.authorizations.push(authorization);
     }
 
+    /// Add an account that can be referenced by its label
+    pub fn with_account(&mut self, label: &str, info: AccountInfo) -> &mut Self {
+        if self.account_labels.contains_key(label) {
+            panic!("Account with label {} already exists", label);
+        }
+
+        let id = self.add_account(info);
+        self.account_labels.insert(label.to_string(), id);
+        self
+    }
+
+    /// Add a library that can be referenced by its label, linked to the accounts with the input and output labels
+    pub fn with_library(
+        &mut self,
+        label: &str,
+        info: LibraryInfo,
+        inputs: &[&str],
+        outputs: &[&str],
+    ) -> &mut Self {
+        if self.library_labels.contains_key(label) {
+            panic!("Library with label {} already exists", label);
+        }
+
+        let inputs = inputs
+            .iter()
+            .map(|label| self.account(label))
+            .collect::<Vec<_>>();
+        let outputs = outputs
+            .iter()
+            .map(|label| self.account(label))
+            .collect::<Vec<_>>();
+
+        let id = self.add_library(info);
+        self.add_link(&id, inputs.iter().collect(), outputs.iter().collect());
+        self.library_labels.insert(label.to_string(), id);
+        self
+    }
+
+    /// Add a permissionless authorization with the label that executes the subroutine
+    pub fn with_subroutine(&mut self, label: &str, subroutine: Subroutine) -> &mut Self {
+        self.add_authorization(
+            AuthorizationBuilder::new()
+                .with_label(label)
+                .with_subroutine(subroutine)
+                .build(),
+        );
+        self
+    }
+
+    /// Get the id of the account with the label, to be used in library configs
+    pub fn account(&self, label: &str) -> LibraryAccountType {
+        self.account_labels
+            .get(label)
+            .unwrap_or_else(|| panic!("Account with label {} doesn't exist", label))
+            .clone()
+    }
+
+    /// Get the id of the library with the label, to be used in subroutines
+    pub fn library(&self, label: &str) -> LibraryAccountType {
+        self.library_labels
+            .get(label)
+            .unwrap_or_else(|| panic!("Library with label {} doesn't exist", label))
+            .clone()
+    }
+
+    /// Verify the config that is being built is a valid new config
+    pub fn validate(&self) -> ManagerResult<()> {
+        self.program_config.clone().verify_new_config()
+    }
+
     pub fn build(self) -> ProgramConfig {
         self.program_config
     }
@@ -4,7 +4,7 @@ use anyhow::Context;
 use cosmwasm_schema::schemars::JsonSchema;
 use cosmwasm_std::{to_json_binary, Coin, CosmosMsg, WasmMsg};
 
-use log::{debug, info};
+use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use valence_authorization_utils::{
     authorization::{AuthorizationInfo, AuthorizationModeInfo, Priority},
@@ -47,13 +47,92 @@ pub struct ProgramConfigMigrate {
     pub transfer_funds: Vec<FundsTransfer>,
 }
 
+/// How the migration instructions should be executed
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[schemars(crate = "cosmwasm_schema::schemars")]
+pub enum MigrationStrategy {
+    /// Funds are transferred while the old program is still running
+    #[default]
+    Sequential,
+    /// All authorizations of the old program are disabled before the funds are transferred,
+    /// so the old program can't execute anything while its funds are moved.
+    /// The migration transfers are inserted by the owner, so they are still executed while
+    /// the authorizations are disabled. The old program is retired, so they are never re-enabled.
+    ZeroDowntime,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MigrateResponse {
+    pub strategy: MigrationStrategy,
     pub instructions: Vec<CosmosMsg>,
     pub pause_processor_messages: Vec<CosmosMsg>,
     pub new_config: ProgramConfig,
 }
 
+/// Result of the pre-flight checks of a migration
+/// Errors prevent the migration from starting, warnings are only reported
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub struct ValidationReport {
+    pub warnings: Vec<String>,
+    pub errors: Vec<String>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Pre-flight checks before we start a migration, nothing is executed on chain.
+/// 1. The code ids of the new program libraries must be stored on their chains
+/// 2. The old program processors should have no pending batches, because they are paused
+///    at the end of the migration and those batches would be interrupted.
+///
+/// Migrations instantiate a new program instead of migrating contracts,
+/// so there are no contract migrate messages to simulate.
+pub async fn validate_migration(
+    migrate: &ProgramConfigMigrate,
+    connectors: &Connectors,
+) -> ManagerResult<ValidationReport> {
+    let mut report = ValidationReport::default();
+
+    for (id, library) in migrate.new_program.libraries.iter() {
+        let mut connector = connectors.get_or_create_connector(&library.domain).await?;
+
+        if let Err(err) = connector.verify_library_code_id(&library.config).await {
+            report.errors.push(format!(
+                "Library id: {} ({}) code id is not valid on {}: {:?}",
+                id, library.config, library.domain, err
+            ));
+        }
+    }
+
+    let neutron_domain = Domain::CosmosCosmwasm(NEUTRON_CHAIN.to_string());
+    let old_config = connectors
+        .get_or_create_connector(&neutron_domain)
+        .await?
+        .get_program_config(migrate.old_id)
+        .await?;
+
+    for (domain, processor_addr) in old_config.authorization_data.processor_addrs.iter() {
+        let mut connector = connectors
+            .get_or_create_connector(&Domain::from_string(domain.to_string())?)
+            .await?;
+
+        let pending = connector
+            .query_processor_queue_len(processor_addr.clone())
+            .await?;
+        if pending > 0 {
+            report.warnings.push(format!(
+                "Processor on {} has {} pending batches that will be interrupted when it is paused",
+                domain, pending
+            ));
+        }
+    }
+
+    Ok(report)
+}
+
 impl ProgramConfigMigrate {
     /// Migrate old program to new program
     /// We first verify the migration data is correct
@@ -62,10 +141,26 @@ impl ProgramConfigMigrate {
     /// Then we build the messages to stop all processors.
     /// NOTE: stopping all processor must happen after transfer of funds was completed, else
     /// the transfer message will be stuck in the processor.
-    pub async fn migrate(&mut self, connectors: &Connectors) -> ManagerResult<MigrateResponse> {
+    ///
+    /// With the `ZeroDowntime` strategy, the instructions start by disabling all authorizations
+    /// of the old program.
+    pub async fn migrate(
+        &mut self,
+        connectors: &Connectors,
+        strategy: MigrationStrategy,
+    ) -> ManagerResult<MigrateResponse> {
         info!("Start program migration");
         let neutron_domain = Domain::CosmosCosmwasm(NEUTRON_CHAIN.to_string());
 
+        info!("Validate migration");
+        let report = validate_migration(self, connectors).await?;
+        for warning in report.warnings.iter() {
+            warn!("{}", warning);
+        }
+        if !report.is_valid() {
+            return Err(ManagerError::MigrationValidationFailed(report.errors));
+        }
+
         // Get the old program config from registry
         let mut neutron_connector = connectors.get_or_create_connector(&neutron_domain).await?;
 
@@ -83,6 +178,45 @@ impl ProgramConfigMigrate {
         // Create the new program
         self.new_program.init(connectors).await?;
 
+        // Validate the migration and generate all the instructions
+        let response = self.build_migration(&mut old_config, strategy)?;
+
+        // Save the updated config to the registry
+        let mut neutron_connector = connectors.get_or_create_connector(&neutron_domain).await?;
+
+        neutron_connector
+            .update_program_config(old_config.clone())
+            .await?;
+
+        Ok(response)
+    }
+
+    /// Generate the migration instructions after the new program was created.
+    /// `old_config` is updated with the authorizations we create for the funds transfer.
+    pub fn build_migration(
+        &self,
+        old_config: &mut ProgramConfig,
+        strategy: MigrationStrategy,
+    ) -> ManagerResult<MigrateResponse> {
+        let neutron_domain = Domain::CosmosCosmwasm(NEUTRON_CHAIN.to_string());
+
+        // On zero downtime migrations we pause the old program by disabling all of its authorizations
+        // These are the labels before we add the transfer authorizations
+        let paused_labels: Vec<String> = match strategy {
+            MigrationStrategy::Sequential => vec![],
+            MigrationStrategy::ZeroDowntime => old_config
+                .authorizations
+                .iter()
+                .map(|auth| auth.label.clone())
+                .collect(),
+        };
+
+        // The new program was created at this point, make sure it can receive the funds before we
+        // generate any instruction, nothing was executed on the old program yet.
+        info!("Validate new program");
+        self.validate_new_program()
+            .map_err(|err| ManagerError::MigrationValidationFailed(vec![err.to_string()]))?;
+
         let mut instructions: VecDeque<CosmosMsg> = VecDeque::new();
         let mut new_authorizations: Vec<AuthorizationInfo> = vec![];
 
@@ -215,6 +349,16 @@ impl ProgramConfigMigrate {
             .into(),
         );
 
+        // Pausing the old program must happen before anything else
+        for label in paused_labels.iter().rev() {
+            instructions.push_front(authorization_msg(
+                &old_config.authorization_data.authorization_addr,
+                valence_authorization_utils::msg::PermissionedMsg::DisableAuthorization {
+                    label: label.clone(),
+                },
+            )?);
+        }
+
         // Add all processor halt messages
         let mut pause_processor_messages: Vec<CosmosMsg> = vec![];
 
@@ -243,20 +387,47 @@ impl ProgramConfigMigrate {
             )
         }
 
-        // Save the updated config to the registry
-        let mut neutron_connector = connectors.get_or_create_connector(&neutron_domain).await?;
-
-        neutron_connector
-            .update_program_config(old_config.clone())
-            .await?;
-
         Ok(MigrateResponse {
+            strategy,
             instructions: instructions.into(),
             new_config: self.new_program.clone(),
             pause_processor_messages,
         })
     }
 
+    /// Make sure the new program was fully instantiated and every account we transfer funds to has an address
+    fn validate_new_program(&self) -> ManagerResult<()> {
+        if self.new_program.id == 0 {
+            return Err(ManagerError::InvalidProgramId);
+        }
+
+        if self
+            .new_program
+            .authorization_data
+            .authorization_addr
+            .is_empty()
+        {
+            return Err(ManagerError::generic_err(
+                "New program has no authorization contract",
+            ));
+        }
+
+        for funds_transfer in self.transfer_funds.iter() {
+            let account = self
+                .new_program
+                .get_account(funds_transfer.to.get_account_id())?;
+
+            if account.addr.is_none() {
+                return Err(ManagerError::generic_err(format!(
+                    "Account id: {} doesn't have address in new config",
+                    funds_transfer.to.get_account_id()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Verify that the migration data is correct
     /// Make sure old program id is not 0
     /// For each funds transfer make sure the amount is not zero, the account we send funds from
@@ -305,3 +476,16 @@ impl ProgramConfigMigrate {
         Ok(())
     }
 }
+
+fn authorization_msg(
+    authorization_addr: &str,
+    msg: valence_authorization_utils::msg::PermissionedMsg,
+) -> ManagerResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: authorization_addr.to_string(),
+        msg: to_json_binary(&valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(msg))
+            .context("Failed binary parsing PermissionedAction")?,
+        funds: vec![],
+    }
+    .into())
+}
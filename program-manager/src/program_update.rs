@@ -4,7 +4,7 @@ use anyhow::Context;
 use cosmwasm_schema::schemars::JsonSchema;
 use cosmwasm_std::{to_json_binary, CosmosMsg, WasmMsg};
 use cw_ownable::Expiration;
-use log::{debug, info};
+use log::{debug, info, warn};
 use serde::{Deserialize, Serialize};
 use valence_authorization_utils::{
     authorization::{AuthorizationInfo, AuthorizationModeInfo, Priority},
@@ -19,6 +19,7 @@ use crate::{
     domain::Domain,
     error::{ManagerError, ManagerResult},
     library::LibraryConfigUpdate,
+    program_config::{diff_program_configs, ProgramConfig},
     NEUTRON_CHAIN,
 };
 
@@ -82,189 +83,83 @@ impl ProgramConfigUpdate {
         let mut config = neutron_connector.get_program_config(self.id).await?;
         debug!("Old config: {:#?}", config);
 
+        let old_config = config.clone();
+        let response = self.apply_to_config(&mut config)?;
+
+        // Show the operator exactly what is about to change in the program config
+        let diff = diff_program_configs(&old_config, &config);
+        info!("Program config diff: {}", diff.to_pretty_json()?);
+
+        // Save the updated config to the registry
+        neutron_connector.update_program_config(config).await?;
+
+        Ok(response)
+    }
+
+    /// Apply all the updates on the given config and generate the instructions to execute.
+    /// The updates are applied on a copy of the config, if any of them fails the copy is discarded,
+    /// so the config is left exactly as it was given to us.
+    pub fn apply_to_config(&self, config: &mut ProgramConfig) -> ManagerResult<UpdateResponse> {
+        let mut new_config = config.clone();
+        let mut succeeded: Vec<String> = vec![];
         let mut instructions: VecDeque<CosmosMsg> = VecDeque::new();
         let mut new_authorizations: Vec<AuthorizationInfo> = vec![];
 
+        let rolled_back = |succeeded: Vec<String>, component: String, err: ManagerError| {
+            warn!(
+                "Update of {} failed, discarding {} applied updates",
+                component,
+                succeeded.len()
+            );
+            ManagerError::UpdateRolledBack {
+                succeeded,
+                failed: vec![format!("{}: {}", component, err)],
+            }
+        };
+
         // If we have an owner set, we add the update owner instruction
         if let Some(new_owner) = self.owner.clone() {
             info!("Updating owner");
-            config.owner = new_owner.to_string();
-
-            // Create instruction to change owner
-            instructions.push_back(
-                WasmMsg::Execute {
-                    contract_addr: config.authorization_data.authorization_addr.clone(),
-                    msg: to_json_binary(&cw_ownable::Action::TransferOwnership {
-                        new_owner,
-                        expiry: None,
-                    })
-                    .context("Failed binary parsing TransferOwnership")?,
-                    funds: vec![],
-                }
-                .into(),
-            );
+            if let Err(err) = update_owner(&mut new_config, new_owner, &mut instructions) {
+                return Err(rolled_back(succeeded, "owner".to_string(), err));
+            }
+
+            succeeded.push("owner".to_string());
         }
 
         // Generate library update instructions
         info!("Generate authorization to update libraries");
         for (id, library_update) in self.libraries.iter() {
-            // Verify that the library id exists in the config and get it
-            let library = config
-                .libraries
-                .get(id)
-                .context(ManagerError::LibraryIdIsMissing(*id).to_string())?;
-
-            // Add authorization to update the library
-            let label = format!("update_library_{}", id);
-
-            // Create authorization if we don't already have one
-            if !config.authorizations.iter().any(|auth| auth.label == label) {
-                let library_domain = if library.domain == neutron_domain {
-                    valence_authorization_utils::domain::Domain::Main
-                } else {
-                    valence_authorization_utils::domain::Domain::External(
-                        library.domain.to_string(),
-                    )
-                };
-
-                let subroutine = AtomicSubroutineBuilder::new()
-                    .with_function(
-                        AtomicFunctionBuilder::new()
-                            .with_domain(library_domain)
-                            .with_contract_address(LibraryAccountType::Addr(
-                                library.addr.clone().unwrap(),
-                            ))
-                            .with_message_details(MessageDetails {
-                                message_type: MessageType::CosmwasmExecuteMsg,
-                                message: Message {
-                                    name: "update_config".to_string(),
-                                    params_restrictions: None,
-                                },
-                            })
-                            .build(),
-                    )
-                    .build();
-                let authorization_builder = AuthorizationBuilder::new()
-                    .with_label(&label)
-                    .with_mode(AuthorizationModeInfo::Permissioned(
-                        valence_authorization_utils::authorization::PermissionTypeInfo::WithoutCallLimit(vec![config.owner.clone()]),
-                    ))
-                    .with_priority(Priority::High)
-                    .with_subroutine(subroutine);
-
-                let authorization_info = authorization_builder.build();
-                new_authorizations.push(authorization_info.clone());
-
-                debug!("library id {} authorization {:?}", id, authorization_info);
-
-                config.authorizations.push(authorization_info);
+            let component = format!("library_{}", id);
+
+            if let Err(err) = update_library(
+                &mut new_config,
+                *id,
+                library_update,
+                &mut instructions,
+                &mut new_authorizations,
+            ) {
+                return Err(rolled_back(succeeded, component, err));
             }
 
-            // execute insert message on the authorization to push this message to processor
-            let update_config_msg = library_update
-                .clone()
-                .get_update_msg()
-                .context("Failed binary parsing get_update_msg")?;
-
-            debug!("library id {} update message {:#?}", id, update_config_msg);
-
-            instructions.push_back(
-                WasmMsg::Execute {
-                    contract_addr: config.authorization_data.authorization_addr.clone(),
-                    msg: to_json_binary(
-                        &valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
-                            valence_authorization_utils::msg::PermissionedMsg::InsertMsgs {
-                                label,
-                                queue_position: 0,
-                                priority: Priority::High,
-                                messages: vec![ProcessorMessage::CosmwasmExecuteMsg {
-                                    msg: update_config_msg,
-                                }],
-                            },
-                        ),
-                    )
-                    .context("Failed binary parsing InsertMsgs")?,
-                    funds: vec![],
-                }
-                .into(),
-            );
+            succeeded.push(component);
         }
 
         // Generate authorization update instructions
         info!("Generate authorization update instructions");
-        for authorization in self.authorizations.clone().into_iter() {
-            match authorization {
-                AuthorizationInfoUpdate::Add(authorization_info) => {
-                    // Verify the new authorization doesn't exists yet
-                    verify_authorization_not_exists(
-                        &config.authorizations,
-                        authorization_info.label.clone(),
-                    )?;
-
-                    // Create instruction for adding authorization
-                    new_authorizations.push(authorization_info.clone());
-
-                    // Add new authorizations to our config saved in registry
-                    config.authorizations.push(authorization_info);
-                }
-                AuthorizationInfoUpdate::Modify {
-                    label,
-                    not_before,
-                    expiration,
-                    max_concurrent_executions,
-                    priority,
-                } => {
-                    // Verify the authorization exists
-                    verify_authorization_exists(&config.authorizations, label.clone())?;
-
-                    // Create instruction for modifying authorization
-                    instructions.push_back(WasmMsg::Execute {
-                        contract_addr: config.authorization_data.authorization_addr.clone(),
-                        msg: to_json_binary(&valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
-                            valence_authorization_utils::msg::PermissionedMsg::ModifyAuthorization { label: label.clone(), not_before, expiration, max_concurrent_executions, priority: priority.clone() }
-                        )).context("Failed binary parsing AuthorizationInfoUpdate::Modify")?,
-                        funds: vec![]
-                    }.into());
-
-                    // Modify saved config with the new modified authorizations
-                    let auth = config
-                        .authorizations
-                        .iter_mut()
-                        .find(|a| a.label == label)
-                        .context(format!("Failed to find authorization {}", label))?;
-
-                    if let Some(not_before) = not_before {
-                        auth.not_before = not_before;
-                    }
-
-                    auth.priority = priority;
-                    auth.max_concurrent_executions = max_concurrent_executions;
-                }
-                AuthorizationInfoUpdate::Disable(label) => {
-                    verify_authorization_exists(&config.authorizations, label.clone())?;
-
-                    // Create instruction for disabling authorization
-                    instructions.push_back(WasmMsg::Execute {
-                        contract_addr: config.authorization_data.authorization_addr.clone(),
-                        msg: to_json_binary(&valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
-                            valence_authorization_utils::msg::PermissionedMsg::DisableAuthorization { label }
-                        )).unwrap(),
-                        funds: vec![]
-                    }.into());
-                }
-                AuthorizationInfoUpdate::Enable(label) => {
-                    verify_authorization_exists(&config.authorizations, label.clone())?;
-
-                    // Create instruction for enabling authorization
-                    instructions.push_back(WasmMsg::Execute {
-                        contract_addr: config.authorization_data.authorization_addr.clone(),
-                        msg: to_json_binary(&valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
-                            valence_authorization_utils::msg::PermissionedMsg::EnableAuthorization { label }
-                        )).unwrap(),
-                        funds: vec![]
-                    }.into());
-                }
+        for authorization in self.authorizations.iter() {
+            let component = format!("authorization_{}", authorization.label());
+
+            if let Err(err) = update_authorization(
+                &mut new_config,
+                authorization.clone(),
+                &mut instructions,
+                &mut new_authorizations,
+            ) {
+                return Err(rolled_back(succeeded, component, err));
             }
+
+            succeeded.push(component);
         }
 
         // Add all new authorizations in a single message to be executed on the authorization contract
@@ -285,8 +180,7 @@ impl ProgramConfigUpdate {
             .into(),
         );
 
-        // Save the updated config to the registry
-        neutron_connector.update_program_config(config).await?;
+        *config = new_config;
 
         Ok(UpdateResponse {
             instructions: instructions.into(),
@@ -294,6 +188,220 @@ impl ProgramConfigUpdate {
     }
 }
 
+impl AuthorizationInfoUpdate {
+    /// The label of the authorization this update is applied to
+    pub fn label(&self) -> &str {
+        match self {
+            AuthorizationInfoUpdate::Add(authorization_info) => &authorization_info.label,
+            AuthorizationInfoUpdate::Modify { label, .. }
+            | AuthorizationInfoUpdate::Disable(label)
+            | AuthorizationInfoUpdate::Enable(label) => label,
+        }
+    }
+}
+
+fn update_owner(
+    config: &mut ProgramConfig,
+    new_owner: String,
+    instructions: &mut VecDeque<CosmosMsg>,
+) -> ManagerResult<()> {
+    config.owner = new_owner.to_string();
+
+    // Create instruction to change owner
+    instructions.push_back(
+        WasmMsg::Execute {
+            contract_addr: config.authorization_data.authorization_addr.clone(),
+            msg: to_json_binary(&cw_ownable::Action::TransferOwnership {
+                new_owner,
+                expiry: None,
+            })
+            .context("Failed binary parsing TransferOwnership")?,
+            funds: vec![],
+        }
+        .into(),
+    );
+
+    Ok(())
+}
+
+fn update_library(
+    config: &mut ProgramConfig,
+    id: Id,
+    library_update: &LibraryConfigUpdate,
+    instructions: &mut VecDeque<CosmosMsg>,
+    new_authorizations: &mut Vec<AuthorizationInfo>,
+) -> ManagerResult<()> {
+    let neutron_domain = Domain::CosmosCosmwasm(NEUTRON_CHAIN.to_string());
+
+    // Verify that the library id exists in the config and get it
+    let library = config
+        .libraries
+        .get(&id)
+        .context(ManagerError::LibraryIdIsMissing(id).to_string())?;
+
+    // Add authorization to update the library
+    let label = format!("update_library_{}", id);
+
+    // Create authorization if we don't already have one
+    if !config.authorizations.iter().any(|auth| auth.label == label) {
+        let library_domain = if library.domain == neutron_domain {
+            valence_authorization_utils::domain::Domain::Main
+        } else {
+            valence_authorization_utils::domain::Domain::External(library.domain.to_string())
+        };
+
+        let library_addr = library
+            .addr
+            .clone()
+            .context(format!("Library id {} has no address", id))?;
+
+        let subroutine = AtomicSubroutineBuilder::new()
+            .with_function(
+                AtomicFunctionBuilder::new()
+                    .with_domain(library_domain)
+                    .with_contract_address(LibraryAccountType::Addr(library_addr))
+                    .with_message_details(MessageDetails {
+                        message_type: MessageType::CosmwasmExecuteMsg,
+                        message: Message {
+                            name: "update_config".to_string(),
+                            params_restrictions: None,
+                        },
+                    })
+                    .build(),
+            )
+            .build();
+        let authorization_builder = AuthorizationBuilder::new()
+            .with_label(&label)
+            .with_mode(AuthorizationModeInfo::Permissioned(
+                valence_authorization_utils::authorization::PermissionTypeInfo::WithoutCallLimit(
+                    vec![config.owner.clone()],
+                ),
+            ))
+            .with_priority(Priority::High)
+            .with_subroutine(subroutine);
+
+        let authorization_info = authorization_builder.build();
+        new_authorizations.push(authorization_info.clone());
+
+        debug!("library id {} authorization {:?}", id, authorization_info);
+
+        config.authorizations.push(authorization_info);
+    }
+
+    // execute insert message on the authorization to push this message to processor
+    let update_config_msg = library_update
+        .clone()
+        .get_update_msg()
+        .context("Failed binary parsing get_update_msg")?;
+
+    debug!("library id {} update message {:#?}", id, update_config_msg);
+
+    instructions.push_back(
+        WasmMsg::Execute {
+            contract_addr: config.authorization_data.authorization_addr.clone(),
+            msg: to_json_binary(
+                &valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
+                    valence_authorization_utils::msg::PermissionedMsg::InsertMsgs {
+                        label,
+                        queue_position: 0,
+                        priority: Priority::High,
+                        messages: vec![ProcessorMessage::CosmwasmExecuteMsg {
+                            msg: update_config_msg,
+                        }],
+                    },
+                ),
+            )
+            .context("Failed binary parsing InsertMsgs")?,
+            funds: vec![],
+        }
+        .into(),
+    );
+
+    Ok(())
+}
+
+fn update_authorization(
+    config: &mut ProgramConfig,
+    authorization: AuthorizationInfoUpdate,
+    instructions: &mut VecDeque<CosmosMsg>,
+    new_authorizations: &mut Vec<AuthorizationInfo>,
+) -> ManagerResult<()> {
+    match authorization {
+        AuthorizationInfoUpdate::Add(authorization_info) => {
+            // Verify the new authorization doesn't exists yet
+            verify_authorization_not_exists(
+                &config.authorizations,
+                authorization_info.label.clone(),
+            )?;
+
+            // Create instruction for adding authorization
+            new_authorizations.push(authorization_info.clone());
+
+            // Add new authorizations to our config saved in registry
+            config.authorizations.push(authorization_info);
+        }
+        AuthorizationInfoUpdate::Modify {
+            label,
+            not_before,
+            expiration,
+            max_concurrent_executions,
+            priority,
+        } => {
+            // Verify the authorization exists
+            verify_authorization_exists(&config.authorizations, label.clone())?;
+
+            // Create instruction for modifying authorization
+            instructions.push_back(WasmMsg::Execute {
+                contract_addr: config.authorization_data.authorization_addr.clone(),
+                msg: to_json_binary(&valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
+                    valence_authorization_utils::msg::PermissionedMsg::ModifyAuthorization { label: label.clone(), not_before, expiration, max_concurrent_executions, priority: priority.clone() }
+                )).context("Failed binary parsing AuthorizationInfoUpdate::Modify")?,
+                funds: vec![]
+            }.into());
+
+            // Modify saved config with the new modified authorizations
+            let auth = config
+                .authorizations
+                .iter_mut()
+                .find(|a| a.label == label)
+                .context(format!("Failed to find authorization {}", label))?;
+
+            if let Some(not_before) = not_before {
+                auth.not_before = not_before;
+            }
+
+            auth.priority = priority;
+            auth.max_concurrent_executions = max_concurrent_executions;
+        }
+        AuthorizationInfoUpdate::Disable(label) => {
+            verify_authorization_exists(&config.authorizations, label.clone())?;
+
+            // Create instruction for disabling authorization
+            instructions.push_back(WasmMsg::Execute {
+                contract_addr: config.authorization_data.authorization_addr.clone(),
+                msg: to_json_binary(&valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
+                    valence_authorization_utils::msg::PermissionedMsg::DisableAuthorization { label }
+                )).context("Failed binary parsing AuthorizationInfoUpdate::Disable")?,
+                funds: vec![]
+            }.into());
+        }
+        AuthorizationInfoUpdate::Enable(label) => {
+            verify_authorization_exists(&config.authorizations, label.clone())?;
+
+            // Create instruction for enabling authorization
+            instructions.push_back(WasmMsg::Execute {
+                contract_addr: config.authorization_data.authorization_addr.clone(),
+                msg: to_json_binary(&valence_authorization_utils::msg::ExecuteMsg::PermissionedAction(
+                    valence_authorization_utils::msg::PermissionedMsg::EnableAuthorization { label }
+                )).context("Failed binary parsing AuthorizationInfoUpdate::Enable")?,
+                funds: vec![]
+            }.into());
+        }
+    }
+
+    Ok(())
+}
+
 fn verify_authorization_not_exists(
     authorizations: &[AuthorizationInfo],
     label: String,
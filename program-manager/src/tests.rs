@@ -249,6 +249,7 @@ mod test {
                         contract_address: LibraryAccountType::LibraryId(1),
                     }],
                     retry_logic: None,
+                    execution_time_limit: None,
                 }),
                 priority: None,
             },
@@ -283,4 +284,1007 @@ mod test {
         //     Err(_) => println!("Program initialization timed out after 60 seconds"),
         // }
     }
+
+    #[test]
+    fn test_update_rollback_on_partial_failure() {
+        use crate::{
+            error::ManagerError, library::LibraryConfigUpdate, program_update::ProgramConfigUpdate,
+        };
+
+        let neutron_domain = Domain::CosmosCosmwasm("neutron".to_string());
+
+        let mut config = ProgramConfig {
+            id: 1,
+            owner: "owner".to_string(),
+            ..Default::default()
+        };
+        config.authorization_data.authorization_addr = "authorization".to_string();
+        config.libraries.insert(
+            1,
+            LibraryInfo {
+                name: "forwarder".to_string(),
+                domain: neutron_domain.clone(),
+                config: LibraryConfig::None,
+                addr: Some("forwarder_addr".to_string()),
+            },
+        );
+
+        let forwarder_update = LibraryConfigUpdate::ValenceForwarderLibrary(
+            valence_forwarder_library::msg::LibraryConfigUpdate {
+                input_addr: Some(LibraryAccountType::Addr("input".to_string())),
+                ..Default::default()
+            },
+        );
+
+        // Library 1 is updated successfully, library 2 doesn't exist in the config and fails
+        let update = ProgramConfigUpdate {
+            id: 1,
+            owner: Some("new_owner".to_string()),
+            libraries: BTreeMap::from([(1, forwarder_update.clone()), (2, forwarder_update)]),
+            authorizations: vec![],
+        };
+
+        let old_config = config.clone();
+        let err = update.apply_to_config(&mut config).unwrap_err();
+
+        match err {
+            ManagerError::UpdateRolledBack { succeeded, failed } => {
+                assert_eq!(
+                    succeeded,
+                    vec!["owner".to_string(), "library_1".to_string()]
+                );
+                assert_eq!(failed.len(), 1);
+                assert!(failed[0].starts_with("library_2"));
+            }
+            err => panic!("Expected UpdateRolledBack error, got: {err:?}"),
+        }
+
+        // The owner and the authorization added for library 1 must be rolled back
+        assert_eq!(config, old_config);
+
+        // Without the failing library, the update is applied
+        let update = ProgramConfigUpdate {
+            id: 1,
+            owner: Some("new_owner".to_string()),
+            libraries: BTreeMap::from([(
+                1,
+                LibraryConfigUpdate::ValenceForwarderLibrary(
+                    valence_forwarder_library::msg::LibraryConfigUpdate::default(),
+                ),
+            )]),
+            authorizations: vec![],
+        };
+
+        let res = update.apply_to_config(&mut config).unwrap();
+        // Create authorizations, transfer ownership and insert the update message
+        assert_eq!(res.instructions.len(), 3);
+        assert_eq!(config.owner, "new_owner");
+        assert!(config
+            .authorizations
+            .iter()
+            .any(|auth| auth.label == "update_library_1"));
+    }
+
+    #[test]
+    fn test_zero_downtime_migration() {
+        use crate::{
+            error::ManagerError,
+            program_migration::{FundsTransfer, MigrationStrategy, ProgramConfigMigrate},
+        };
+        use cosmwasm_std::{coin, CosmosMsg, WasmMsg};
+        use valence_authorization_utils::msg::{ExecuteMsg, PermissionedMsg};
+
+        let neutron_domain = Domain::CosmosCosmwasm("neutron".to_string());
+
+        let authorization = |label: &str| AuthorizationInfo {
+            label: label.to_string(),
+            mode: AuthorizationModeInfo::Permissionless,
+            not_before: Expiration::Never {},
+            duration: AuthorizationDuration::Forever,
+            max_concurrent_executions: None,
+            subroutine: Subroutine::Atomic(AtomicSubroutine {
+                functions: vec![],
+                retry_logic: None,
+                execution_time_limit: None,
+            }),
+            priority: None,
+        };
+
+        let mut old_config = ProgramConfig {
+            id: 1,
+            owner: "owner".to_string(),
+            authorizations: vec![authorization("swap"), authorization("withdraw")],
+            ..Default::default()
+        };
+        old_config.authorization_data.authorization_addr = "old_authorization".to_string();
+        old_config.accounts.insert(
+            0,
+            AccountInfo {
+                name: "old_account".to_string(),
+                ty: AccountType::Addr {
+                    addr: "old_account_addr".to_string(),
+                },
+                domain: neutron_domain.clone(),
+                addr: Some("old_account_addr".to_string()),
+            },
+        );
+
+        let mut new_program = ProgramConfig {
+            owner: "owner".to_string(),
+            ..Default::default()
+        };
+        new_program.accounts.insert(
+            0,
+            AccountInfo {
+                name: "new_account".to_string(),
+                ty: AccountType::Base { admin: None },
+                domain: neutron_domain.clone(),
+                addr: None,
+            },
+        );
+
+        let mut migrate = ProgramConfigMigrate {
+            old_id: 1,
+            new_program,
+            transfer_funds: vec![FundsTransfer {
+                from: "old_account_addr".to_string(),
+                to: LibraryAccountType::AccountId(0),
+                domain: neutron_domain,
+                funds: coin(100, "untrn"),
+            }],
+        };
+
+        // The new program was not instantiated, so no instructions are generated
+        for strategy in [
+            MigrationStrategy::ZeroDowntime,
+            MigrationStrategy::Sequential,
+        ] {
+            let err = migrate
+                .build_migration(&mut old_config.clone(), strategy)
+                .unwrap_err();
+            assert!(
+                matches!(err, ManagerError::MigrationValidationFailed(_)),
+                "Expected MigrationValidationFailed error, got: {err:?}"
+            );
+        }
+
+        // Instantiated new program
+        migrate.new_program.id = 2;
+        migrate.new_program.authorization_data.authorization_addr = "new_authorization".to_string();
+        migrate.new_program.accounts.get_mut(&0).unwrap().addr =
+            Some("new_account_addr".to_string());
+
+        let res = migrate
+            .build_migration(&mut old_config, MigrationStrategy::ZeroDowntime)
+            .unwrap();
+
+        let parse = |msg: &CosmosMsg| match msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => match from_json(msg).unwrap() {
+                ExecuteMsg::PermissionedAction(permissioned_msg) => permissioned_msg,
+                _ => panic!("Expected permissioned action"),
+            },
+            _ => panic!("Expected wasm execute message"),
+        };
+
+        // Pause first, then create authorizations and insert the transfer
+        assert_eq!(res.instructions.len(), 4);
+        assert_eq!(
+            parse(&res.instructions[0]),
+            PermissionedMsg::DisableAuthorization {
+                label: "swap".to_string()
+            }
+        );
+        assert_eq!(
+            parse(&res.instructions[1]),
+            PermissionedMsg::DisableAuthorization {
+                label: "withdraw".to_string()
+            }
+        );
+        assert!(matches!(
+            parse(&res.instructions[2]),
+            PermissionedMsg::CreateAuthorizations { .. }
+        ));
+        assert!(matches!(
+            parse(&res.instructions[3]),
+            PermissionedMsg::InsertMsgs { .. }
+        ));
+
+        // Sequential migrations do not pause the authorizations
+        let res = migrate
+            .build_migration(&mut old_config, MigrationStrategy::Sequential)
+            .unwrap();
+        assert_eq!(res.instructions.len(), 2);
+    }
+
+    #[test]
+    fn test_program_config_diff() {
+        use crate::program_config::diff_program_configs;
+
+        let neutron_domain = Domain::CosmosCosmwasm("neutron".to_string());
+
+        let mut old_config = ProgramConfig {
+            id: 1,
+            owner: "owner".to_string(),
+            ..Default::default()
+        };
+        old_config.accounts.insert(
+            1,
+            AccountInfo {
+                name: "input".to_string(),
+                ty: AccountType::default(),
+                domain: neutron_domain.clone(),
+                addr: Some("input_addr".to_string()),
+            },
+        );
+        old_config.libraries.insert(
+            1,
+            LibraryInfo {
+                name: "forwarder".to_string(),
+                domain: neutron_domain.clone(),
+                config: LibraryConfig::None,
+                addr: Some("forwarder_addr".to_string()),
+            },
+        );
+
+        // Same config has no diff
+        assert!(diff_program_configs(&old_config, &old_config).is_empty());
+
+        let mut new_config = old_config.clone();
+        new_config.libraries.get_mut(&1).unwrap().addr = Some("new_forwarder_addr".to_string());
+
+        let diff = diff_program_configs(&old_config, &new_config);
+
+        assert!(diff.added_accounts.is_empty());
+        assert!(diff.removed_accounts.is_empty());
+        assert!(diff.changed_accounts.is_empty());
+        assert!(diff.added_libraries.is_empty());
+        assert!(diff.removed_libraries.is_empty());
+        assert!(diff.changed_subroutines.is_empty());
+
+        assert_eq!(diff.changed_libraries.len(), 1);
+        let change = diff.changed_libraries.get(&1).unwrap();
+        assert_eq!(change.before.addr, Some("forwarder_addr".to_string()));
+        assert_eq!(change.after.addr, Some("new_forwarder_addr".to_string()));
+
+        let json = diff.to_pretty_json().unwrap();
+        assert!(json.contains("new_forwarder_addr"));
+    }
+
+    #[test]
+    fn test_program_config_template_merge() {
+        use crate::{
+            library::LibraryConfigUpdate,
+            program_config::{merge_program_configs, ProgramConfigTemplate},
+            program_update::{AuthorizationInfoUpdate, ProgramConfigUpdate},
+        };
+        use valence_authorization_utils::{
+            authorization::Priority,
+            builders::{AtomicFunctionBuilder, AtomicSubroutineBuilder, AuthorizationBuilder},
+        };
+
+        let neutron_domain = Domain::CosmosCosmwasm("neutron".to_string());
+        let forwarding_configs = |amount: u128| {
+            vec![valence_forwarder_library::msg::UncheckedForwardingConfig {
+                denom: UncheckedDenom::Native("untrn".to_string()),
+                max_amount: Uint128::new(amount),
+            }]
+        };
+
+        let mut config = ProgramConfig {
+            owner: "owner".to_string(),
+            ..Default::default()
+        };
+        config.accounts.insert(
+            1,
+            AccountInfo::new("input".to_string(), &neutron_domain, AccountType::default()),
+        );
+        config.libraries.insert(
+            1,
+            LibraryInfo::new(
+                "forwarder".to_string(),
+                &neutron_domain,
+                LibraryConfig::ValenceForwarderLibrary(
+                    valence_forwarder_library::msg::LibraryConfig {
+                        input_addr: LibraryAccountType::AccountId(1),
+                        output_addr: LibraryAccountType::AccountId(2),
+                        forwarding_configs: forwarding_configs(100),
+                        forwarding_constraints:
+                            valence_forwarder_library::msg::ForwardingConstraints::new(None),
+                    },
+                ),
+            ),
+        );
+        config.links.insert(
+            1,
+            Link {
+                input_accounts_id: vec![1],
+                output_accounts_id: vec![2],
+                library_id: 1,
+            },
+        );
+        config.authorizations.push(
+            AuthorizationBuilder::new()
+                .with_label("forward")
+                .with_subroutine(
+                    AtomicSubroutineBuilder::new()
+                        .with_function(
+                            AtomicFunctionBuilder::new()
+                                .with_contract_address(LibraryAccountType::LibraryId(1))
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build(),
+        );
+
+        // Account 2 is a placeholder, it is linked but not in the accounts list
+        let template = ProgramConfigTemplate::new(config);
+        assert_eq!(template.placeholders(), [2].into());
+
+        let output_account = BTreeMap::from([(
+            2,
+            AccountInfo::new(
+                "output".to_string(),
+                &neutron_domain,
+                AccountType::default(),
+            ),
+        )]);
+
+        // Placeholders must be resolved
+        template
+            .clone()
+            .merge(BTreeMap::new(), ProgramConfigUpdate::default())
+            .unwrap_err();
+
+        // Only placeholders can be resolved
+        template
+            .clone()
+            .merge(
+                BTreeMap::from([(
+                    1,
+                    AccountInfo::new("input".to_string(), &neutron_domain, AccountType::default()),
+                )]),
+                ProgramConfigUpdate::default(),
+            )
+            .unwrap_err();
+
+        // First level of overrides, for the chain
+        let chain_config = template
+            .clone()
+            .merge(
+                output_account,
+                ProgramConfigUpdate {
+                    owner: Some("chain_owner".to_string()),
+                    libraries: BTreeMap::from([(
+                        1,
+                        LibraryConfigUpdate::ValenceForwarderLibrary(
+                            valence_forwarder_library::msg::LibraryConfigUpdate {
+                                forwarding_configs: Some(forwarding_configs(200)),
+                                ..Default::default()
+                            },
+                        ),
+                    )]),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert_eq!(chain_config.owner, "chain_owner");
+        assert_eq!(chain_config.accounts.len(), 2);
+
+        // Second level of overrides, for a specific deployment on the chain
+        let deployment_config = merge_program_configs(
+            chain_config.clone(),
+            ProgramConfigUpdate {
+                libraries: BTreeMap::from([(
+                    1,
+                    LibraryConfigUpdate::ValenceForwarderLibrary(
+                        valence_forwarder_library::msg::LibraryConfigUpdate {
+                            forwarding_configs: Some(forwarding_configs(300)),
+                            ..Default::default()
+                        },
+                    ),
+                )]),
+                authorizations: vec![AuthorizationInfoUpdate::Modify {
+                    label: "forward".to_string(),
+                    not_before: None,
+                    expiration: None,
+                    max_concurrent_executions: None,
+                    priority: Some(Priority::High),
+                }],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        // Owner from the first level is kept, library config is overridden again
+        assert_eq!(deployment_config.owner, "chain_owner");
+        match &deployment_config.libraries.get(&1).unwrap().config {
+            LibraryConfig::ValenceForwarderLibrary(config) => {
+                assert_eq!(config.forwarding_configs, forwarding_configs(300));
+                assert_eq!(config.input_addr, LibraryAccountType::AccountId(1));
+            }
+            config => panic!("Expected forwarder config, got: {config:?}"),
+        }
+        assert_eq!(
+            deployment_config.authorizations[0].priority,
+            Some(Priority::High)
+        );
+
+        // Expiration can't be set on a new config, it only has a duration
+        let err = merge_program_configs(
+            chain_config.clone(),
+            ProgramConfigUpdate {
+                authorizations: vec![AuthorizationInfoUpdate::Modify {
+                    label: "forward".to_string(),
+                    not_before: None,
+                    expiration: Some(Expiration::AtHeight(100)),
+                    max_concurrent_executions: None,
+                    priority: None,
+                }],
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains(
+            "Expiration of authorization forward can only be modified on an existing program"
+        ));
+
+        // Update for a different library type fails
+        let err = merge_program_configs(
+            chain_config,
+            ProgramConfigUpdate {
+                libraries: BTreeMap::from([(
+                    1,
+                    LibraryConfigUpdate::ValenceSplitterLibrary(
+                        valence_splitter_library::msg::LibraryConfigUpdate::default(),
+                    ),
+                )]),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Tried to compare 2 different configs"));
+    }
+
+    #[tokio::test]
+    async fn test_endpoint_failover() {
+        use crate::config::{ChainInfo, EndpointSelector};
+
+        let chain_info = ChainInfo {
+            name: "failover_test".to_string(),
+            rpc: "http://rpc".to_string(),
+            endpoints: vec!["http://failing".to_string(), "http://working".to_string()],
+            endpoint_selector: EndpointSelector::Primary,
+            max_retries: 1,
+            prefix: "neutron".to_string(),
+            gas_price: "0.025".to_string(),
+            gas_denom: "untrn".to_string(),
+            coin_type: 118,
+        };
+
+        let mut requested = vec![];
+        let (endpoint, res) = chain_info
+            .with_failover(|endpoint| {
+                requested.push(endpoint.clone());
+                async move {
+                    if endpoint == "http://failing" {
+                        Err(anyhow::anyhow!("Endpoint is down"))
+                    } else {
+                        Ok(endpoint.len())
+                    }
+                }
+            })
+            .await
+            .unwrap();
+
+        // The first endpoint failed, so the second one is used
+        assert_eq!(endpoint, "http://working");
+        assert_eq!(res, "http://working".len());
+        assert_eq!(requested, vec!["http://failing", "http://working"]);
+
+        // Fails after we are out of retries
+        let err = chain_info
+            .with_failover(|_| async { Err::<(), _>(anyhow::anyhow!("Endpoint is down")) })
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "All endpoints failed for: failover_test, last error: Endpoint is down"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_endpoint_is_tried_last() {
+        use crate::config::{ChainInfo, EndpointSelector};
+
+        let chain_info = ChainInfo {
+            name: "failed_endpoint_test".to_string(),
+            rpc: "http://rpc".to_string(),
+            endpoints: vec![
+                "http://first-endpoint".to_string(),
+                "http://second-endpoint".to_string(),
+            ],
+            endpoint_selector: EndpointSelector::Primary,
+            max_retries: 0,
+            prefix: "neutron".to_string(),
+            gas_price: "0.025".to_string(),
+            gas_denom: "untrn".to_string(),
+            coin_type: 118,
+        };
+
+        // A request failed on the first endpoint after the client was created
+        chain_info.mark_endpoint_failed("http://first-endpoint");
+
+        // Reconnecting should use the second endpoint without trying the failed one first
+        let mut requested = vec![];
+        let (endpoint, _) = chain_info
+            .with_failover(|endpoint| {
+                requested.push(endpoint.clone());
+                async { Ok(()) }
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(endpoint, "http://second-endpoint");
+        assert_eq!(requested, vec!["http://second-endpoint"]);
+    }
+
+    #[test]
+    fn test_chain_info_accepts_legacy_grpc_key() {
+        use crate::config::{ChainInfo, EndpointSelector};
+
+        let chain_info: ChainInfo = serde_json::from_value(serde_json::json!({
+            "name": "neutron",
+            "rpc": "http://rpc",
+            "grpc": "http://grpc",
+            "prefix": "neutron",
+            "gas_price": "0.025",
+            "gas_denom": "untrn",
+            "coin_type": 118
+        }))
+        .unwrap();
+
+        assert_eq!(chain_info.endpoints, vec!["http://grpc"]);
+        assert_eq!(chain_info.endpoint_selector, EndpointSelector::Primary);
+        assert_eq!(chain_info.max_retries, 3);
+
+        let chain_info: ChainInfo = serde_json::from_value(serde_json::json!({
+            "name": "neutron",
+            "rpc": "http://rpc",
+            "endpoints": ["http://grpc-1", "http://grpc-2"],
+            "endpoint_selector": "latency",
+            "prefix": "neutron",
+            "gas_price": "0.025",
+            "gas_denom": "untrn",
+            "coin_type": 118
+        }))
+        .unwrap();
+
+        assert_eq!(chain_info.endpoints, vec!["http://grpc-1", "http://grpc-2"]);
+        assert_eq!(chain_info.endpoint_selector, EndpointSelector::Latency);
+    }
+
+    #[test]
+    fn test_export_program_config_schema() {
+        let schema = crate::program_config::export_program_config_schema();
+
+        assert_eq!(
+            schema.schema.metadata.unwrap().title,
+            Some("ProgramConfig".to_string())
+        );
+        // Nested types are part of the schema
+        for definition in ["AccountInfo", "LibraryInfo", "Link", "AuthorizationData"] {
+            assert!(schema.definitions.contains_key(definition));
+        }
+    }
+
+    #[test]
+    fn test_program_config_builder_labels() {
+        use crate::program_config_builder::ProgramConfigBuilder;
+        use valence_authorization_utils::builders::{
+            AtomicFunctionBuilder, AtomicSubroutineBuilder,
+        };
+
+        let neutron_domain = Domain::CosmosCosmwasm("neutron".to_string());
+        let forwarder = |input: LibraryAccountType, output: LibraryAccountType| {
+            LibraryInfo::new(
+                "forwarder".to_string(),
+                &neutron_domain,
+                LibraryConfig::ValenceForwarderLibrary(
+                    valence_forwarder_library::msg::LibraryConfig {
+                        input_addr: input,
+                        output_addr: output,
+                        forwarding_configs: vec![
+                            valence_forwarder_library::msg::UncheckedForwardingConfig {
+                                denom: UncheckedDenom::Native("untrn".to_string()),
+                                max_amount: Uint128::new(100),
+                            },
+                        ],
+                        forwarding_constraints:
+                            valence_forwarder_library::msg::ForwardingConstraints::new(None),
+                    },
+                ),
+            )
+        };
+
+        let mut builder = ProgramConfigBuilder::new("owner".to_string());
+        builder
+            .with_account(
+                "input",
+                AccountInfo::new("input".to_string(), &neutron_domain, AccountType::default()),
+            )
+            .with_account(
+                "output",
+                AccountInfo::new(
+                    "output".to_string(),
+                    &neutron_domain,
+                    AccountType::default(),
+                ),
+            );
+
+        let library_info = forwarder(builder.account("input"), builder.account("output"));
+        builder.with_library("forwarder", library_info, &["input"], &["output"]);
+
+        // Authorizations are required
+        builder.validate().unwrap_err();
+
+        let subroutine = AtomicSubroutineBuilder::new()
+            .with_function(
+                AtomicFunctionBuilder::new()
+                    .with_contract_address(builder.library("forwarder"))
+                    .build(),
+            )
+            .build();
+        builder.with_subroutine("forward", subroutine);
+        builder.validate().unwrap();
+
+        let config = builder.build();
+        assert_eq!(config.accounts.len(), 2);
+        assert_eq!(config.libraries.len(), 1);
+        assert_eq!(
+            config.links.get(&0).unwrap(),
+            &Link {
+                input_accounts_id: vec![0],
+                output_accounts_id: vec![1],
+                library_id: 0,
+            }
+        );
+        assert_eq!(config.authorizations[0].label, "forward");
+
+        // An account that is not used by any library fails validation
+        let mut builder = ProgramConfigBuilder::new("owner".to_string());
+        builder
+            .with_account(
+                "input",
+                AccountInfo::new("input".to_string(), &neutron_domain, AccountType::default()),
+            )
+            .with_subroutine("forward", AtomicSubroutineBuilder::new().build());
+        builder.validate().unwrap_err();
+    }
+
+    #[test]
+    fn test_program_config_template_render() {
+        use crate::program_config::ProgramConfigTemplate;
+        use std::collections::HashMap;
+
+        let neutron_domain = Domain::CosmosCosmwasm("neutron".to_string());
+
+        let mut config = ProgramConfig {
+            owner: "{{owner}}".to_string(),
+            ..Default::default()
+        };
+        config.accounts.insert(
+            1,
+            AccountInfo::new(
+                "{{name}}_input".to_string(),
+                &neutron_domain,
+                AccountType::default(),
+            ),
+        );
+        config.libraries.insert(
+            1,
+            LibraryInfo::new(
+                "{{name}}_forwarder".to_string(),
+                &neutron_domain,
+                LibraryConfig::ValenceForwarderLibrary(
+                    valence_forwarder_library::msg::LibraryConfig {
+                        input_addr: LibraryAccountType::AccountId(1),
+                        output_addr: LibraryAccountType::AccountId(1),
+                        forwarding_configs: vec![
+                            valence_forwarder_library::msg::UncheckedForwardingConfig {
+                                denom: UncheckedDenom::Native("{{denom}}".to_string()),
+                                max_amount: Uint128::new(100),
+                            },
+                        ],
+                        forwarding_constraints:
+                            valence_forwarder_library::msg::ForwardingConstraints::new(None),
+                    },
+                ),
+            ),
+        );
+        let template = ProgramConfigTemplate::new(config);
+
+        assert_eq!(
+            template.variables().unwrap(),
+            ["denom", "name", "owner"].map(String::from).into()
+        );
+
+        let vars = HashMap::from([
+            ("owner".to_string(), "neutron1owner".to_string()),
+            ("name".to_string(), "ntrn".to_string()),
+            ("denom".to_string(), "untrn".to_string()),
+        ]);
+        let config = template.render(vars.clone()).unwrap();
+
+        assert_eq!(config.owner, "neutron1owner");
+        assert_eq!(config.accounts.get(&1).unwrap().name, "ntrn_input");
+        assert_eq!(config.libraries.get(&1).unwrap().name, "ntrn_forwarder");
+        let LibraryConfig::ValenceForwarderLibrary(library_config) =
+            &config.libraries.get(&1).unwrap().config
+        else {
+            panic!("Expected forwarder library config");
+        };
+        assert_eq!(
+            library_config.forwarding_configs[0].denom,
+            UncheckedDenom::Native("untrn".to_string())
+        );
+
+        // All variables must be provided
+        let mut missing_vars = vars.clone();
+        missing_vars.remove("denom");
+        let err = template.render(missing_vars).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Template variables are missing: {\"denom\"}"
+        );
+
+        // Variable names are validated
+        let mut invalid_template = template.clone();
+        invalid_template.config.owner = "{{owner name}}".to_string();
+        invalid_template.render(vars).unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_export_and_import_template() {
+        use crate::{
+            error::ManagerError,
+            program_config::{ExportTemplate, ImportTemplate},
+        };
+
+        let neutron_domain = Domain::CosmosCosmwasm("neutron".to_string());
+        let library_config =
+            LibraryConfig::ValenceForwarderLibrary(valence_forwarder_library::msg::LibraryConfig {
+                input_addr: LibraryAccountType::AccountId(1),
+                output_addr: LibraryAccountType::AccountId(2),
+                forwarding_configs: vec![
+                    valence_forwarder_library::msg::UncheckedForwardingConfig {
+                        denom: UncheckedDenom::Native("untrn".to_string()),
+                        max_amount: Uint128::new(100),
+                    },
+                ],
+                forwarding_constraints: valence_forwarder_library::msg::ForwardingConstraints::new(
+                    None,
+                ),
+            });
+
+        // A deployed program, as it is stored in the registry
+        let mut config = ProgramConfig {
+            id: 5,
+            owner: "neutron1owner".to_string(),
+            ..Default::default()
+        };
+        config.accounts.insert(
+            1,
+            AccountInfo {
+                name: "input".to_string(),
+                ty: AccountType::Base { admin: None },
+                domain: neutron_domain.clone(),
+                addr: Some("neutron1account".to_string()),
+            },
+        );
+        config.libraries.insert(
+            1,
+            LibraryInfo {
+                name: "forwarder".to_string(),
+                domain: neutron_domain.clone(),
+                config: LibraryConfig::None,
+                addr: Some("neutron1library".to_string()),
+            },
+        );
+        config.authorizations.push(AuthorizationInfo {
+            label: "forward".to_string(),
+            mode: AuthorizationModeInfo::Permissionless,
+            not_before: Expiration::Never {},
+            duration: AuthorizationDuration::Forever,
+            max_concurrent_executions: None,
+            subroutine: Subroutine::Atomic(AtomicSubroutine {
+                functions: vec![AtomicFunction {
+                    domain: valence_authorization_utils::domain::Domain::Main,
+                    message_details: MessageDetails {
+                        message_type: MessageType::CosmwasmExecuteMsg,
+                        message: Message {
+                            name: "process_function".to_string(),
+                            params_restrictions: None,
+                        },
+                    },
+                    contract_address: LibraryAccountType::Addr("neutron1library".to_string()),
+                }],
+                retry_logic: None,
+                execution_time_limit: None,
+            }),
+            priority: None,
+        });
+        config
+            .authorization_data
+            .set_authorization_addr("neutron1authorization".to_string());
+
+        // Addresses are replaced by ids and deployment data is cleared
+        let template = ExportTemplate::from_config(config);
+        assert_eq!(template.config.id, 0);
+        assert!(template.config.owner.is_empty());
+        assert_eq!(template.config.accounts[&1].addr, None);
+        assert_eq!(template.config.libraries[&1].addr, None);
+        assert_eq!(template.config.authorization_data, Default::default());
+        let Subroutine::Atomic(subroutine) = &template.config.authorizations[0].subroutine else {
+            panic!("Expected an atomic subroutine");
+        };
+        assert_eq!(
+            subroutine.functions[0].contract_address,
+            LibraryAccountType::LibraryId(1)
+        );
+
+        // Every library needs a config to be imported
+        let err = ImportTemplate {
+            template: template.clone(),
+            owner: "neutron1newowner".to_string(),
+            library_configs: BTreeMap::new(),
+        }
+        .into_program_config()
+        .await
+        .unwrap_err();
+        assert!(matches!(err, ManagerError::LibraryIdIsMissing(1)));
+
+        GLOBAL_CONFIG.lock().await.update_code_id(
+            "neutron".to_string(),
+            library_config.to_string(),
+            10,
+        );
+
+        let imported = ImportTemplate {
+            template,
+            owner: "neutron1newowner".to_string(),
+            library_configs: BTreeMap::from([(1, library_config.clone())]),
+        }
+        .into_program_config()
+        .await
+        .unwrap();
+        assert_eq!(imported.owner, "neutron1newowner");
+        assert_eq!(imported.libraries[&1].config, library_config);
+    }
+
+    #[tokio::test]
+    async fn test_gas_price_oracle() {
+        use crate::{
+            connectors::Connectors,
+            gas_price::{parse_min_gas_price, MockGasPriceOracle},
+        };
+        use cosmwasm_std::Decimal;
+        use std::str::FromStr;
+
+        assert_eq!(
+            parse_min_gas_price("0.0053untrn,0.1uatom", "uatom").unwrap(),
+            Some(Decimal::from_str("0.1").unwrap())
+        );
+        // Only whole denoms are matched
+        assert_eq!(parse_min_gas_price("0.1ibc/uatom", "uatom").unwrap(), None);
+        assert_eq!(parse_min_gas_price("", "untrn").unwrap(), None);
+        // Nodes without a minimum gas price report a zero price
+        assert_eq!(parse_min_gas_price("0untrn", "untrn").unwrap(), None);
+        assert_eq!(
+            parse_min_gas_price("0.000000000000000000untrn", "untrn").unwrap(),
+            None
+        );
+
+        let connectors =
+            Connectors::default().with_gas_price_oracle(Box::new(MockGasPriceOracle {
+                prices: [("neutron".to_string(), Decimal::from_str("0.0053").unwrap())].into(),
+            }));
+
+        assert_eq!(
+            connectors.get_gas_price("neutron").await.unwrap(),
+            Decimal::from_str("0.0053").unwrap()
+        );
+        connectors.get_gas_price("osmosis").await.unwrap_err();
+    }
+
+    #[tokio::test]
+    async fn test_cached_gas_price_oracle() {
+        use crate::{
+            error::ManagerResult,
+            gas_price::{CachedGasPriceOracle, GasPriceOracle},
+        };
+        use async_trait::async_trait;
+        use cosmwasm_std::Decimal;
+        use std::{
+            sync::{
+                atomic::{AtomicU64, Ordering},
+                Arc,
+            },
+            time::Duration,
+        };
+
+        /// Counts how many times the gas price was queried
+        #[derive(Debug)]
+        struct CountingGasPriceOracle {
+            calls: Arc<AtomicU64>,
+        }
+
+        #[async_trait]
+        impl GasPriceOracle for CountingGasPriceOracle {
+            async fn get_gas_price(&self, _chain: &str) -> ManagerResult<Decimal> {
+                let calls = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+                Ok(Decimal::permille(calls))
+            }
+        }
+
+        let calls = Arc::new(AtomicU64::new(0));
+        let oracle = CachedGasPriceOracle::new(Box::new(CountingGasPriceOracle {
+            calls: calls.clone(),
+        }));
+
+        // The price of a chain is only queried once within the cache duration
+        assert_eq!(
+            oracle.get_gas_price("neutron").await.unwrap(),
+            Decimal::permille(1)
+        );
+        assert_eq!(
+            oracle.get_gas_price("neutron").await.unwrap(),
+            Decimal::permille(1)
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Each chain is cached separately
+        assert_eq!(
+            oracle.get_gas_price("osmosis").await.unwrap(),
+            Decimal::permille(2)
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Once the cache expires the price is queried again
+        let calls = Arc::new(AtomicU64::new(0));
+        let oracle = CachedGasPriceOracle::new(Box::new(CountingGasPriceOracle {
+            calls: calls.clone(),
+        }))
+        .with_cache_duration(Duration::from_millis(50));
+
+        oracle.get_gas_price("neutron").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(
+            oracle.get_gas_price("neutron").await.unwrap(),
+            Decimal::permille(2)
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_connection_monitor_stops_when_connectors_are_dropped() {
+        use crate::connectors::Connectors;
+        use std::{sync::Arc, time::Duration};
+
+        let connectors = Arc::new(Connectors::default());
+        let monitor = connectors.spawn_connection_monitor(Duration::from_millis(10));
+
+        // Let the monitor run a few rounds without any connector
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!monitor.is_finished());
+
+        drop(connectors);
+        tokio::time::timeout(Duration::from_secs(1), monitor)
+            .await
+            .unwrap()
+            .unwrap();
+    }
 }
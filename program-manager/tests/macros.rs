@@ -0,0 +1,5 @@
+#[test]
+fn manager_impl_library_configs() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/library_config_docs.rs");
+}
@@ -0,0 +1,36 @@
+use aho_corasick::AhoCorasick;
+use cosmwasm_schema::{schema_for, schemars::JsonSchema};
+use cosmwasm_std::{to_json_binary, Binary, Empty};
+use serde::{Deserialize, Serialize};
+use serde_json::to_vec;
+use strum::VariantNames;
+use valence_library_utils::{
+    msg::{InstantiateMsg, LibraryConfigValidation},
+    Id,
+};
+use valence_macros::manager_impl_library_configs;
+use valence_program_manager::library::{LibraryError, LibraryResult};
+
+#[manager_impl_library_configs]
+#[derive(
+    Debug, Clone, strum::Display, Serialize, Deserialize, VariantNames, PartialEq, Default,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum LibraryConfig {
+    #[default]
+    None,
+    /// Forwards funds from an input account to an output account
+    ValenceForwarderLibrary(valence_forwarder_library::msg::LibraryConfig),
+}
+
+impl LibraryConfig {
+    fn find_account_ids(_ac: AhoCorasick, _json: String) -> LibraryResult<Vec<Id>> {
+        Ok(vec![])
+    }
+}
+
+fn main() {
+    // The docs of the variant are kept on the generated update variant
+    let schema = serde_json::to_string(&schema_for!(LibraryConfigUpdate)).unwrap();
+    assert!(schema.contains("Forwards funds from an input account to an output account"));
+}